@@ -0,0 +1,1329 @@
+//! Conversions of the OpenCRUD input values, passed in the request, into the typed inputs.
+//!
+//! Inputs are checked against the model, as the filter names are the field names
+//! suffixed with the operator and the field names may contain `_` themselves.
+use chrono::NaiveDate;
+use graphql_value::{ConstValue as Value, Name};
+use indexmap::IndexMap;
+use sdml_parser::types::{
+  DataModel, FieldDecl, ModelAttribute, ModelDecl, PrimitiveType, Token, Type,
+};
+use uuid::Uuid;
+
+use super::*;
+use crate::{errors::Error, model::FIELD_ID, tables::value};
+
+/// Logical filters, combining the nested filters.
+const FILTER_AND: &str = "AND";
+const FILTER_OR: &str = "OR";
+const FILTER_NOT: &str = "NOT";
+
+/// Suffixes of the scalar field filters, with the operator and whether it is negated.
+/// Note: Longer suffixes come first, so that `_not_in` isn't taken for `_in`.
+const FIELD_FILTERS: [(&str, FieldOp, bool); 17] = [
+  ("_starts_with_i", FieldOp::StartsWithIgnoreCase, false),
+  ("_ends_with_i", FieldOp::EndsWithIgnoreCase, false),
+  ("_contains_i", FieldOp::ContainsIgnoreCase, false),
+  ("_equals_i", FieldOp::EqualsIgnoreCase, false),
+  ("_not_starts_with", FieldOp::StartsWith, true),
+  ("_not_ends_with", FieldOp::EndsWith, true),
+  ("_not_contains", FieldOp::Contains, true),
+  ("_starts_with", FieldOp::StartsWith, false),
+  ("_ends_with", FieldOp::EndsWith, false),
+  ("_contains", FieldOp::Contains, false),
+  ("_not_in", FieldOp::In, true),
+  ("_not", FieldOp::Equals, true),
+  ("_lte", FieldOp::Lte, false),
+  ("_gte", FieldOp::Gte, false),
+  ("_in", FieldOp::In, false),
+  ("_lt", FieldOp::Lt, false),
+  ("_gt", FieldOp::Gt, false),
+];
+
+/// Suffix of the filter checking whether an optional scalar field is `null`.
+const FIELD_FILTER_IS_NULL: &str = "_is_null";
+
+/// Suffix of the filter on the value at a path inside a json field.
+const JSON_FILTER_PATH: &str = "_path";
+/// Path of the json path filter.
+const JSON_PATH: &str = "path";
+
+/// Operators of the json path filter, with whether they are negated.
+const JSON_PATH_FILTERS: [(&str, FieldOp, bool); 9] = [
+  ("equals", FieldOp::Equals, false),
+  ("not", FieldOp::Equals, true),
+  ("string_contains", FieldOp::Contains, false),
+  ("string_starts_with", FieldOp::StartsWith, false),
+  ("string_ends_with", FieldOp::EndsWith, false),
+  ("lt", FieldOp::Lt, false),
+  ("lte", FieldOp::Lte, false),
+  ("gt", FieldOp::Gt, false),
+  ("gte", FieldOp::Gte, false),
+];
+
+/// Suffixes of the scalar list field filters.
+const LIST_FILTER_HAS: &str = "_has";
+const LIST_FILTER_HAS_EVERY: &str = "_has_every";
+const LIST_FILTER_HAS_SOME: &str = "_has_some";
+const LIST_FILTER_IS_EMPTY: &str = "_is_empty";
+
+/// Suffixes of the scalar list field length filters, with the operator comparing
+/// the length.
+const LIST_FILTERS_LENGTH: [(&str, FieldOp); 5] = [
+  ("_length", FieldOp::Equals),
+  ("_length_lt", FieldOp::Lt),
+  ("_length_lte", FieldOp::Lte),
+  ("_length_gt", FieldOp::Gt),
+  ("_length_gte", FieldOp::Gte),
+];
+
+/// Format of the `Date` values, as per RFC3339 full-date.
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Suffixes of the relation field filters.
+const RELATION_FILTER_IS_NULL: &str = "_is_null";
+const RELATION_FILTER_EVERY: &str = "_every";
+const RELATION_FILTER_SOME: &str = "_some";
+const RELATION_FILTER_NONE: &str = "_none";
+const RELATION_FILTER_IS_EMPTY: &str = "_is_empty";
+
+/// Relation filters, with whether they are meant for the many-side relations.
+const RELATION_FILTERS: [(&str, bool); 5] = [
+  (RELATION_FILTER_IS_NULL, false),
+  (RELATION_FILTER_EVERY, true),
+  (RELATION_FILTER_SOME, true),
+  (RELATION_FILTER_NONE, true),
+  (RELATION_FILTER_IS_EMPTY, true),
+];
+
+/// Suffixes of the order by input values.
+const ORDER_ASC: &str = "_ASC";
+const ORDER_DSC: &str = "_DSC";
+
+/// Nested relation inputs of the create, update & upsert inputs.
+const INPUT_CREATE: &str = "create";
+const INPUT_CONNECT: &str = "connect";
+const INPUT_UPDATE: &str = "update";
+const INPUT_UPSERT: &str = "upsert";
+const INPUT_SET: &str = "set";
+const INPUT_DISCONNECT: &str = "disconnect";
+const INPUT_DELETE: &str = "delete";
+const INPUT_WHERE: &str = "where";
+const INPUT_DATA: &str = "data";
+const INPUT_POSITION: &str = "position";
+
+/// Fields of the connect position input.
+const POSITION_BEFORE: &str = "before";
+const POSITION_AFTER: &str = "after";
+const POSITION_START: &str = "start";
+
+impl ObjectWhereUniqueInput {
+  /// Unique filter of the model, from the `*WhereUniqueInput` value.
+  pub fn from_value(
+    data_model: &DataModel,
+    model_name: &str,
+    value: Value,
+  ) -> Result<Self, Error> {
+    where_unique(InputModel::new(data_model, model_name)?, value)
+  }
+}
+
+impl ObjectWhereInput {
+  /// Filter of the model, from the `*WhereInput` value.
+  pub fn from_value(
+    data_model: &DataModel,
+    model_name: &str,
+    value: Value,
+  ) -> Result<Self, Error> {
+    r#where(InputModel::new(data_model, model_name)?, value)
+  }
+}
+
+impl ObjectOrderByInput {
+  /// Order of the model objects, from the `*OrderByInput` value.
+  pub fn from_value(
+    data_model: &DataModel,
+    model_name: &str,
+    value: Value,
+  ) -> Result<Self, Error> {
+    order_by(InputModel::new(data_model, model_name)?, value)
+  }
+}
+
+impl ObjectCreateInput {
+  /// Create input of the model, from the `*CreateInput` value.
+  pub fn from_value(
+    data_model: &DataModel,
+    model_name: &str,
+    value: Value,
+  ) -> Result<Self, Error> {
+    create(InputModel::new(data_model, model_name)?, value)
+  }
+}
+
+impl ObjectUpdateInput {
+  /// Update input of the model, from the `*UpdateInput` value.
+  pub fn from_value(
+    data_model: &DataModel,
+    model_name: &str,
+    value: Value,
+  ) -> Result<Self, Error> {
+    update(InputModel::new(data_model, model_name)?, value)
+  }
+}
+
+impl ObjectUpsertInput {
+  /// Upsert input of the model, from the `*UpsertInput` value.
+  pub fn from_value(
+    data_model: &DataModel,
+    model_name: &str,
+    value: Value,
+  ) -> Result<Self, Error> {
+    upsert(InputModel::new(data_model, model_name)?, value)
+  }
+}
+
+/// Model (or composite type) of the input, along with the data model to reach
+/// the related models & the embedded composite types.
+#[derive(Clone, Copy)]
+struct InputModel<'a> {
+  data_model: &'a DataModel,
+  name: &'a Token,
+  fields: &'a [FieldDecl],
+  /// `None` for the composite types, as they have neither the IDs nor the relations.
+  model: Option<&'a ModelDecl>,
+}
+
+impl<'a> InputModel<'a> {
+  fn new(data_model: &'a DataModel, model_name: &str) -> Result<Self, Error> {
+    let model = data_model.models.get(model_name).ok_or_else(|| {
+      Error::InvalidInput(format!(r#"Model "{model_name}" is not found."#))
+    })?;
+    Ok(InputModel {
+      data_model,
+      name: &model.name,
+      fields: &model.fields,
+      model: Some(model),
+    })
+  }
+
+  /// Composite type of the given name, whose values are embedded in the objects.
+  fn composite_type(data_model: &'a DataModel, type_name: &str) -> Option<Self> {
+    let r#type = data_model.types.get(type_name)?;
+    Some(InputModel {
+      data_model,
+      name: &r#type.name,
+      fields: &r#type.fields,
+      model: None,
+    })
+  }
+
+  fn name(&self) -> String {
+    self.name.ident_name().unwrap()
+  }
+
+  fn field_by_name(&self, name: &str) -> Option<&'a FieldDecl> {
+    self
+      .fields
+      .iter()
+      .find(|field| field.name.ident_name().as_deref() == Some(name))
+  }
+
+  /// Scalar field of the given GraphQL field name.
+  fn field(&self, name: &str) -> Option<&'a FieldDecl> {
+    if name == FIELD_ID && self.model.is_some() {
+      return self.fields.iter().find(|field| field.has_id_attrib());
+    }
+    self.field_by_name(name).filter(|field| {
+      !matches!(
+        field.field_type.r#type(),
+        Type::Relation(_) | Type::Composite { .. }
+      )
+    })
+  }
+
+  /// Relation field of the given name, along with the related model.
+  fn relation(&self, name: &str) -> Option<(&'a FieldDecl, InputModel<'a>)> {
+    let field = self.field_by_name(name)?;
+    let Type::Relation(edge) = field.field_type.r#type() else {
+      return None;
+    };
+    let model_name = edge.referenced_model_name().ident_name()?;
+    Some((field, InputModel::new(self.data_model, &model_name).ok()?))
+  }
+
+  /// Composite field of the given name, along with its composite type.
+  fn composite(&self, name: &str) -> Option<(&'a FieldDecl, InputModel<'a>)> {
+    let field = self.field_by_name(name)?;
+    let Type::Composite { composite_ty_name } = field.field_type.r#type() else {
+      return None;
+    };
+    let type_name = composite_ty_name.ident_name()?;
+    Some((
+      field,
+      InputModel::composite_type(self.data_model, &type_name)?,
+    ))
+  }
+
+  /// Compound unique key (`@@id` or `@@unique`) of the given compound name.
+  fn compound_key(&self, name: &str) -> Option<&'a ModelAttribute> {
+    let model = self.model?;
+    model
+      .compound_id()
+      .into_iter()
+      .chain(model.compound_uniques())
+      .find(|compound_key| compound_key.compound_name() == name)
+  }
+
+  fn unknown_field(&self, name: &str) -> Error {
+    Error::InvalidInput(format!(r#"Unknown field "{name}" on {}."#, self.name()))
+  }
+}
+
+fn where_unique(
+  model: InputModel<'_>,
+  value: Value,
+) -> Result<ObjectWhereUniqueInput, Error> {
+  let mut fields = IndexMap::new();
+  for (name, value) in input_object(value, "Unique filter")? {
+    if matches!(value, Value::Null) {
+      continue;
+    }
+    // Note: Objects of the model with compound ID are identified by the `id` field.
+    if name == FIELD_ID
+      && model
+        .model
+        .is_some_and(|model| model.compound_id().is_some())
+    {
+      fields.insert(FIELD_ID.to_string(), value);
+      continue;
+    }
+    if let Some(compound_key) = model.compound_key(&name) {
+      fields.insert(
+        name.to_string(),
+        compound_key_value(model, compound_key, value)?,
+      );
+      continue;
+    }
+    let field = model
+      .field(&name)
+      .filter(|field| field.has_id_attrib() || field.has_unique_attrib())
+      .ok_or_else(|| {
+        Error::InvalidInput(format!(
+          r#"Field "{name}" isn't a unique field of {}."#,
+          model.name()
+        ))
+      })?;
+    fields.insert(field_name(field), value);
+  }
+  Ok(ObjectWhereUniqueInput(fields))
+}
+
+/// Value of the compound key, list of its field values in the key order.
+fn compound_key_value(
+  model: InputModel<'_>,
+  compound_key: &ModelAttribute,
+  value: Value,
+) -> Result<Value, Error> {
+  let compound_name = compound_key.compound_name();
+  let mut input = input_object(value, "Compound unique filter")?;
+  let values = compound_key
+    .field_names()
+    .iter()
+    .map(|field_name| match take(&mut input, field_name) {
+      Value::Null => Err(Error::InvalidInput(format!(
+        r#"Field "{field_name}" of the compound key "{compound_name}" of {} should have a value."#,
+        model.name()
+      ))),
+      value => Ok(value),
+    })
+    .collect::<Result<Vec<_>, Error>>()?;
+  if let Some(name) = input.keys().next() {
+    return Err(model.unknown_field(name));
+  }
+  Ok(Value::List(values))
+}
+
+fn r#where(model: InputModel<'_>, value: Value) -> Result<ObjectWhereInput, Error> {
+  let mut filters = Vec::new();
+  for (name, value) in input_object(value, "Filter")? {
+    if let Some(filter) = filter(model, &name, value)? {
+      filters.push(filter);
+    }
+  }
+  Ok(ObjectWhereInput(filters))
+}
+
+/// Filter of the given name, `None` if the filter is to be ignored.
+fn filter(
+  model: InputModel<'_>,
+  name: &str,
+  value: Value,
+) -> Result<Option<ObjectFilter>, Error> {
+  let nested = |value: Value| {
+    items(value)
+      .into_iter()
+      .map(|value| r#where(model, value))
+      .collect::<Result<Vec<_>, Error>>()
+  };
+  match name {
+    FILTER_AND | FILTER_OR | FILTER_NOT if matches!(value, Value::Null) => {
+      return Ok(None)
+    }
+    FILTER_AND => return Ok(Some(ObjectFilter::And(nested(value)?))),
+    FILTER_OR => return Ok(Some(ObjectFilter::Or(nested(value)?))),
+    FILTER_NOT => return Ok(Some(ObjectFilter::Not(nested(value)?))),
+    _ => {}
+  }
+
+  if let Some((field, target, suffix)) =
+    relation_filter(model, name, InputModel::relation)
+  {
+    let op = relation_op(target, name, suffix, value)?;
+    return Ok(op.map(|op| {
+      ObjectFilter::Relation(RelationFilter {
+        relation: field_name(field),
+        op,
+      })
+    }));
+  }
+  if let Some((field, target, suffix)) =
+    relation_filter(model, name, InputModel::composite)
+  {
+    let op = relation_op(target, name, suffix, value)?;
+    return Ok(op.map(|op| {
+      ObjectFilter::Composite(CompositeFilter {
+        field: field_name(field),
+        op,
+      })
+    }));
+  }
+
+  if let Some(field) = json_field(model, name) {
+    return json_path_filter(model, field, value);
+  }
+  if let Some((field, suffix)) = list_filter(model, name) {
+    return list_op(model, field, name, suffix, value).map(|op| {
+      op.map(|op| {
+        ObjectFilter::List(ListFilter {
+          field: field_name(field),
+          op,
+        })
+      })
+    });
+  }
+
+  if let Some(field) = is_null_filter(model, name) {
+    // Note: It is the same as comparing the field with `null`.
+    return match value {
+      Value::Null => Ok(None),
+      Value::Boolean(is_null) => Ok(Some(ObjectFilter::Field(FieldFilter {
+        field: field_name(field),
+        op: FieldOp::Equals,
+        negated: !is_null,
+        value: Value::Null,
+      }))),
+      _ => Err(Error::InvalidInput(format!(
+        r#"Filter "{name}" should be a boolean."#
+      ))),
+    };
+  }
+
+  let Some((field, op, negated)) = field_filter(model, name) else {
+    return Err(Error::InvalidInput(format!(
+      r#"Unknown filter "{name}" on {}."#,
+      model.name()
+    )));
+  };
+  // Note: `null` is compared for the equality, ignored by the other operators.
+  if matches!(value, Value::Null) && op != FieldOp::Equals {
+    return Ok(None);
+  }
+  let filter = FieldFilter {
+    field: field_name(field),
+    op,
+    negated,
+    value: scalar_value(model, field, value)?,
+  };
+  if matches!(
+    field.field_type.r#type(),
+    Type::Primitive {
+      r#type: PrimitiveType::Decimal,
+      ..
+    }
+  ) {
+    Ok(Some(ObjectFilter::Decimal(filter)))
+  } else {
+    Ok(Some(ObjectFilter::Field(filter)))
+  }
+}
+
+/// Optional scalar field of the `*_is_null` filter.
+fn is_null_filter<'a>(model: InputModel<'a>, name: &str) -> Option<&'a FieldDecl> {
+  if model.field(name).is_some() {
+    return None;
+  }
+  model
+    .field(name.strip_suffix(FIELD_FILTER_IS_NULL)?)
+    .filter(|field| field.field_type.is_optional())
+}
+
+/// Json field of the `*_path` filter.
+fn json_field<'a>(model: InputModel<'a>, name: &str) -> Option<&'a FieldDecl> {
+  if model.field(name).is_some() {
+    return None;
+  }
+  model
+    .field(name.strip_suffix(JSON_FILTER_PATH)?)
+    .filter(|field| {
+      matches!(
+        field.field_type.r#type(),
+        Type::Primitive {
+          r#type: PrimitiveType::Json,
+          ..
+        }
+      )
+    })
+}
+
+/// Filters on the value at the path inside the json field, `None` if the filter
+/// is to be ignored. Ex. `{path: ["theme"], equals: "dark"}`.
+fn json_path_filter(
+  model: InputModel<'_>,
+  field: &FieldDecl,
+  value: Value,
+) -> Result<Option<ObjectFilter>, Error> {
+  if matches!(value, Value::Null) {
+    return Ok(None);
+  }
+  let mut input = input_object(value, "Json path filter")?;
+  let path = match take(&mut input, JSON_PATH) {
+    Value::List(keys) => keys
+      .into_iter()
+      .map(|key| match key {
+        Value::String(key) => Some(key),
+        Value::Number(index) => Some(index.to_string()),
+        _ => None,
+      })
+      .collect::<Option<Vec<_>>>(),
+    _ => None,
+  }
+  .ok_or_else(|| {
+    Error::InvalidInput(format!(
+      r#"Json path filter on the field "{}" of {} should have the path."#,
+      field_name(field),
+      model.name()
+    ))
+  })?;
+  let mut filters = Vec::new();
+  for (name, value) in input {
+    let Some((_, op, negated)) = JSON_PATH_FILTERS
+      .iter()
+      .find(|(filter, ..)| *filter == name.as_str())
+    else {
+      return Err(Error::InvalidInput(format!(
+        r#"Unknown json path filter "{name}"."#
+      )));
+    };
+    // Note: Like the field filters, `null` is only compared for the equality.
+    if matches!(value, Value::Null) && *op != FieldOp::Equals {
+      continue;
+    }
+    filters.push(ObjectFilter::JsonPath(JsonPathFilter {
+      path: path.clone(),
+      filter: FieldFilter {
+        field: field_name(field),
+        op: *op,
+        negated: *negated,
+        value,
+      },
+    }));
+  }
+  Ok(match filters.len() {
+    0 => None,
+    1 => filters.pop(),
+    _ => Some(ObjectFilter::And(vec![ObjectWhereInput(filters)])),
+  })
+}
+
+/// Scalar list field of the filter, along with the filter suffix.
+fn list_filter<'a>(
+  model: InputModel<'a>,
+  name: &str,
+) -> Option<(&'a FieldDecl, &'static str)> {
+  if model.field(name).is_some() {
+    return None;
+  }
+  [
+    LIST_FILTER_HAS,
+    LIST_FILTER_HAS_EVERY,
+    LIST_FILTER_HAS_SOME,
+    LIST_FILTER_IS_EMPTY,
+  ]
+  .into_iter()
+  .chain(LIST_FILTERS_LENGTH.iter().map(|(suffix, _)| *suffix))
+  .find_map(|suffix| {
+    let field = model.field(name.strip_suffix(suffix)?)?;
+    field.field_type.is_array().then_some((field, suffix))
+  })
+}
+
+/// Operator of the scalar list filter, `None` if the filter is to be ignored.
+fn list_op(
+  model: InputModel<'_>,
+  field: &FieldDecl,
+  name: &str,
+  suffix: &str,
+  value: Value,
+) -> Result<Option<ListOp>, Error> {
+  if matches!(value, Value::Null) {
+    return Ok(None);
+  }
+  let scalar_values = |value: Value| {
+    items(value)
+      .into_iter()
+      .map(|item| scalar_value(model, field, item))
+      .collect::<Result<Vec<_>, Error>>()
+  };
+  match (suffix, value) {
+    (LIST_FILTER_HAS, value) => Ok(Some(ListOp::Has(scalar_value(model, field, value)?))),
+    (LIST_FILTER_HAS_EVERY, value) => Ok(Some(ListOp::HasEvery(scalar_values(value)?))),
+    (LIST_FILTER_HAS_SOME, value) => Ok(Some(ListOp::HasSome(scalar_values(value)?))),
+    (LIST_FILTER_IS_EMPTY, Value::Boolean(is_empty)) => {
+      Ok(Some(ListOp::IsEmpty(is_empty)))
+    }
+    (LIST_FILTER_IS_EMPTY, _) => Err(Error::InvalidInput(format!(
+      r#"Filter "{name}" should be a boolean."#
+    ))),
+    (suffix, value) => {
+      let (_, op) = LIST_FILTERS_LENGTH
+        .iter()
+        .find(|(length_suffix, _)| *length_suffix == suffix)
+        .expect("Suffix should be one of the list filters.");
+      match &value {
+        Value::Number(length) if length.is_u64() => {
+          Ok(Some(ListOp::Length(*op, length.as_u64().unwrap() as usize)))
+        }
+        _ => Err(Error::InvalidInput(format!(
+          r#"Filter "{name}" should be a non-negative integer."#
+        ))),
+      }
+    }
+  }
+}
+
+/// Relation (or composite) field of the filter, found using `target_field`, along
+/// with the related model (or the composite type) & the filter suffix.
+/// Note: Composite fields take the same filters as the relation fields.
+fn relation_filter<'a>(
+  model: InputModel<'a>,
+  name: &str,
+  target_field: fn(&InputModel<'a>, &str) -> Option<(&'a FieldDecl, InputModel<'a>)>,
+) -> Option<(&'a FieldDecl, InputModel<'a>, &'static str)> {
+  if let Some((field, target)) = target_field(&model, name) {
+    return Some((field, target, ""));
+  }
+  RELATION_FILTERS.iter().find_map(|(suffix, is_array)| {
+    let (field, target) = target_field(&model, name.strip_suffix(suffix)?)?;
+    (field.field_type.is_array() == *is_array).then_some((field, target, *suffix))
+  })
+}
+
+/// Operator of the relation filter, `None` if the filter is to be ignored.
+fn relation_op(
+  target: InputModel<'_>,
+  name: &str,
+  suffix: &str,
+  value: Value,
+) -> Result<Option<RelationOp>, Error> {
+  match (suffix, value) {
+    ("", Value::Null) => Ok(Some(RelationOp::Is(None))),
+    ("", value) => Ok(Some(RelationOp::Is(Some(r#where(target, value)?)))),
+    (_, Value::Null) => Ok(None),
+    (RELATION_FILTER_IS_NULL, Value::Boolean(is_null)) => {
+      Ok(Some(RelationOp::IsNull(is_null)))
+    }
+    (RELATION_FILTER_IS_EMPTY, Value::Boolean(is_empty)) => {
+      Ok(Some(RelationOp::IsEmpty(is_empty)))
+    }
+    (RELATION_FILTER_IS_NULL | RELATION_FILTER_IS_EMPTY, _) => Err(Error::InvalidInput(
+      format!(r#"Filter "{name}" should be a boolean."#),
+    )),
+    (suffix, value) => {
+      let filter = r#where(target, value)?;
+      Ok(Some(match suffix {
+        RELATION_FILTER_EVERY => RelationOp::Every(filter),
+        RELATION_FILTER_SOME => RelationOp::Some(filter),
+        _ => RelationOp::None(filter),
+      }))
+    }
+  }
+}
+
+fn field_filter<'a>(
+  model: InputModel<'a>,
+  name: &str,
+) -> Option<(&'a FieldDecl, FieldOp, bool)> {
+  if let Some(field) = model.field(name) {
+    return Some((field, FieldOp::Equals, false));
+  }
+  FIELD_FILTERS.iter().find_map(|(suffix, op, negated)| {
+    let field = model.field(name.strip_suffix(suffix)?)?;
+    Some((field, *op, *negated))
+  })
+}
+
+fn order_by(model: InputModel<'_>, value: Value) -> Result<ObjectOrderByInput, Error> {
+  let order_by = match &value {
+    Value::Null => return Ok(ObjectOrderByInput(None)),
+    Value::Enum(order_by) => order_by.as_str(),
+    Value::String(order_by) => order_by.as_str(),
+    _ => "",
+  };
+  let (name, order) = match (
+    order_by.strip_suffix(ORDER_ASC),
+    order_by.strip_suffix(ORDER_DSC),
+  ) {
+    (Some(name), _) => (name, Order::Asc),
+    (_, Some(name)) => (name, Order::Dsc),
+    _ => ("", Order::Asc),
+  };
+  let field = model.field(name).ok_or_else(|| {
+    Error::InvalidInput(format!(r#"Unknown order "{value}" on {}."#, model.name()))
+  })?;
+  Ok(ObjectOrderByInput(Some(FieldOrder {
+    field: field_name(field),
+    order,
+  })))
+}
+
+fn create(model: InputModel<'_>, value: Value) -> Result<ObjectCreateInput, Error> {
+  let mut input = ObjectCreateInput::default();
+  for (name, value) in input_object(value, "Create input")? {
+    if let Some(field) = model.field(&name) {
+      // Note: Fields set to `null` get their default value.
+      if !matches!(value, Value::Null) {
+        input
+          .fields
+          .insert(field_name(field), scalar_value(model, field, value)?);
+      }
+    } else if let Some((field, target)) = model.composite(&name) {
+      if !matches!(value, Value::Null) {
+        input
+          .fields
+          .insert(field_name(field), embedded(target, value)?);
+      }
+    } else if let Some((field, target)) = model.relation(&name) {
+      if !matches!(value, Value::Null) {
+        input
+          .relations
+          .insert(field_name(field), relation_create(target, value)?);
+      }
+    } else {
+      return Err(model.unknown_field(&name));
+    }
+  }
+  Ok(input)
+}
+
+/// Value(s) of the composite type to embed in the object, from the `*CreateInput`
+/// value(s) of the composite type.
+fn embedded(r#type: InputModel<'_>, value: Value) -> Result<Value, Error> {
+  match value {
+    Value::Null => Ok(Value::Null),
+    Value::List(items) => Ok(Value::List(
+      items
+        .into_iter()
+        .map(|value| embedded(r#type, value))
+        .collect::<Result<_, _>>()?,
+    )),
+    value => {
+      let mut fields = IndexMap::new();
+      for (name, value) in input_object(value, "Composite create input")? {
+        let value = if let Some((_, target)) = r#type.composite(&name) {
+          embedded(target, value)?
+        } else if let Some(field) = r#type.field(&name) {
+          scalar_value(r#type, field, value)?
+        } else {
+          return Err(r#type.unknown_field(&name));
+        };
+        fields.insert(name, value);
+      }
+      Ok(Value::Object(fields))
+    }
+  }
+}
+
+fn relation_create(
+  target: InputModel<'_>,
+  value: Value,
+) -> Result<RelationCreateInput, Error> {
+  let mut input = RelationCreateInput::default();
+  for (name, value) in input_object(value, "Relation create input")? {
+    match name.as_str() {
+      INPUT_CREATE => {
+        for value in items(value) {
+          input.create.push(create(target, value)?);
+        }
+      }
+      INPUT_CONNECT => {
+        for value in items(value) {
+          input.connect.push(where_unique(target, value)?);
+        }
+      }
+      _ => return Err(unknown_input(&name)),
+    }
+  }
+  Ok(input)
+}
+
+fn update(model: InputModel<'_>, value: Value) -> Result<ObjectUpdateInput, Error> {
+  let mut input = ObjectUpdateInput::default();
+  for (name, value) in input_object(value, "Update input")? {
+    if let Some(field) = model.field(&name) {
+      input
+        .fields
+        .insert(field_name(field), scalar_value(model, field, value)?);
+    } else if let Some((field, target)) = model.composite(&name) {
+      // Note: Value of the single composite field is merged, while the list
+      // of values is replaced as a whole.
+      match value {
+        value @ Value::Object(_) if !field.field_type.is_array() => {
+          input
+            .composites
+            .insert(field_name(field), update(target, value)?);
+        }
+        value => {
+          input
+            .fields
+            .insert(field_name(field), embedded(target, value)?);
+        }
+      }
+    } else if let Some((field, target)) = model.relation(&name) {
+      if !matches!(value, Value::Null) {
+        let is_array = field.field_type.is_array();
+        input
+          .relations
+          .insert(field_name(field), relation_update(target, is_array, value)?);
+      }
+    } else {
+      return Err(model.unknown_field(&name));
+    }
+  }
+  Ok(input)
+}
+
+fn relation_update(
+  target: InputModel<'_>,
+  is_array: bool,
+  value: Value,
+) -> Result<RelationUpdateInput, Error> {
+  let mut input = RelationUpdateInput::default();
+  let connected = |value: Value| -> Result<Option<ConnectedObjects>, Error> {
+    match value {
+      Value::Boolean(true) if !is_array => Ok(Some(ConnectedObjects::Connected)),
+      value if is_array => Ok(Some(ConnectedObjects::Unique(
+        items(value)
+          .into_iter()
+          .map(|value| where_unique(target, value))
+          .collect::<Result<_, _>>()?,
+      ))),
+      _ => Ok(None),
+    }
+  };
+  for (name, value) in input_object(value, "Relation update input")? {
+    if matches!(value, Value::Null) {
+      continue;
+    }
+    match name.as_str() {
+      INPUT_SET => {
+        input.set = Some(
+          items(value)
+            .into_iter()
+            .map(|value| where_unique(target, value))
+            .collect::<Result<_, _>>()?,
+        );
+      }
+      INPUT_DISCONNECT => input.disconnect = connected(value)?,
+      INPUT_DELETE => input.delete = connected(value)?,
+      INPUT_CONNECT => {
+        for value in items(value) {
+          input.connect.push(if is_array {
+            let mut connect = input_object(value, "Connect input")?;
+            ConnectInput {
+              r#where: where_unique(target, take(&mut connect, INPUT_WHERE))?,
+              position: position(take(&mut connect, INPUT_POSITION)),
+            }
+          } else {
+            ConnectInput {
+              r#where: where_unique(target, value)?,
+              position: ConnectPosition::End,
+            }
+          });
+        }
+      }
+      INPUT_CREATE => {
+        for value in items(value) {
+          input.create.push(create(target, value)?);
+        }
+      }
+      INPUT_UPDATE => {
+        for value in items(value) {
+          let mut nested = input_object(value, "Nested update input")?;
+          input.update.push(NestedUpdateInput {
+            r#where: where_unique(target, take(&mut nested, INPUT_WHERE))?,
+            data: update(target, take(&mut nested, INPUT_DATA))?,
+          });
+        }
+      }
+      INPUT_UPSERT => {
+        for value in items(value) {
+          let mut nested = input_object(value, "Nested upsert input")?;
+          input.upsert.push(NestedUpsertInput {
+            r#where: where_unique(target, take(&mut nested, INPUT_WHERE))?,
+            data: upsert(target, take(&mut nested, INPUT_DATA))?,
+          });
+        }
+      }
+      _ => return Err(unknown_input(&name)),
+    }
+  }
+  Ok(input)
+}
+
+fn upsert(model: InputModel<'_>, value: Value) -> Result<ObjectUpsertInput, Error> {
+  let mut input = input_object(value, "Upsert input")?;
+  Ok(ObjectUpsertInput {
+    create: create(model, take(&mut input, INPUT_CREATE))?,
+    update: update(model, take(&mut input, INPUT_UPDATE))?,
+  })
+}
+
+fn position(value: Value) -> ConnectPosition {
+  let Value::Object(position) = value else {
+    return ConnectPosition::End;
+  };
+  let id = |name| match position.get(name) {
+    Some(Value::String(id)) => Some(ID::new(id)),
+    _ => None,
+  };
+  if let Some(id) = id(POSITION_BEFORE) {
+    ConnectPosition::Before(id)
+  } else if let Some(id) = id(POSITION_AFTER) {
+    ConnectPosition::After(id)
+  } else if matches!(position.get(POSITION_START), Some(Value::Boolean(true))) {
+    ConnectPosition::Start
+  } else {
+    ConnectPosition::End
+  }
+}
+
+/// Value of the scalar field as held in the store, Ex. the values of the `Bytes`
+/// fields are held as binary, the `Int64` values as numbers & the `Decimal`
+/// values as strings.
+fn scalar_value(
+  model: InputModel<'_>,
+  field: &FieldDecl,
+  value: Value,
+) -> Result<Value, Error> {
+  let r#type = match field.field_type.r#type() {
+    Type::Primitive { r#type, .. } => *r#type,
+    _ => return Ok(value),
+  };
+  primitive_value(r#type, &value).ok_or_else(|| {
+    Error::InvalidInput(format!(
+      r#"Invalid value {value} of the field "{}" on {}."#,
+      field_name(field),
+      model.name()
+    ))
+  })
+}
+
+/// Normalized value (or the list of values) of the primitive type, `None` if the
+/// value doesn't fit the type.
+fn primitive_value(r#type: PrimitiveType, value: &Value) -> Option<Value> {
+  match (r#type, value) {
+    (_, Value::Null) => Some(Value::Null),
+    (PrimitiveType::Bytes, Value::Binary(_)) => Some(value.clone()),
+    (PrimitiveType::Bytes, Value::List(items))
+      if !items.is_empty()
+        && items.iter().all(|item| matches!(item, Value::Number(_))) =>
+    {
+      items
+        .iter()
+        .map(|item| match item {
+          Value::Number(byte) => u8::try_from(byte.as_u64()?).ok(),
+          _ => None,
+        })
+        .collect::<Option<Vec<u8>>>()
+        .map(|bytes| Value::Binary(bytes.into()))
+    }
+    (_, Value::List(items)) => items
+      .iter()
+      .map(|item| primitive_value(r#type, item))
+      .collect::<Option<_>>()
+      .map(Value::List),
+    (PrimitiveType::Int64, Value::Number(long)) if long.is_i64() => Some(value.clone()),
+    (PrimitiveType::Int64, Value::String(long)) => {
+      long.trim().parse::<i64>().ok().map(Value::from)
+    }
+    (PrimitiveType::Decimal, _) => value::decimal_value(value),
+    (PrimitiveType::Date, Value::String(date)) => {
+      NaiveDate::parse_from_str(date, DATE_FORMAT)
+        .ok()
+        .map(|date| Value::String(date.format(DATE_FORMAT).to_string()))
+    }
+    (PrimitiveType::Uuid, Value::String(uuid)) => Uuid::parse_str(uuid)
+      .ok()
+      .map(|uuid| Value::String(uuid.hyphenated().to_string())),
+    (
+      PrimitiveType::Int64
+      | PrimitiveType::Bytes
+      | PrimitiveType::Date
+      | PrimitiveType::Uuid,
+      _,
+    ) => None,
+    (_, value) => Some(value.clone()),
+  }
+}
+
+/// Fields of the input object, none if the input is `null`.
+fn input_object(value: Value, type_name: &str) -> Result<IndexMap<Name, Value>, Error> {
+  match value {
+    Value::Null => Ok(IndexMap::new()),
+    Value::Object(fields) => Ok(fields),
+    _ => Err(Error::InvalidInput(format!(
+      "{type_name} should be an input object."
+    ))),
+  }
+}
+
+/// Items of the list value, or the value itself if it isn't a list.
+/// Note: GraphQL coerces a single value into a list of one item.
+fn items(value: Value) -> Vec<Value> {
+  match value {
+    Value::Null => vec![],
+    Value::List(items) => items,
+    value => vec![value],
+  }
+}
+
+/// Takes out the field of the input object, `null` if not given.
+fn take(input: &mut IndexMap<Name, Value>, name: &str) -> Value {
+  input.shift_remove(name).unwrap_or(Value::Null)
+}
+
+fn field_name(field: &FieldDecl) -> String {
+  field.name.ident_name().unwrap()
+}
+
+fn unknown_input(name: &str) -> Error {
+  Error::InvalidInput(format!(r#"Unknown nested input "{name}"."#))
+}
+
+#[cfg(test)]
+mod tests {
+  use graphql_value::value;
+
+  use super::*;
+
+  fn data_model() -> DataModel {
+    let sdml = include_str!("../test_data/test_crud_api_def.sdml");
+    sdml_parser::parse(sdml).expect("Test SDML should be valid.")
+  }
+
+  fn field(field: &str, op: FieldOp, negated: bool, value: Value) -> ObjectFilter {
+    ObjectFilter::Field(FieldFilter {
+      field: field.to_string(),
+      op,
+      negated,
+      value,
+    })
+  }
+
+  #[test]
+  fn test_where_input() {
+    let data_model = data_model();
+    let r#where = ObjectWhereInput::from_value(
+      &data_model,
+      "User",
+      value!({
+        "id": "1",
+        "name_not_in": ["Alice"],
+        "email_ends_with": null,
+        "OR": [{"role": "ADMIN"}],
+        "posts_some": {"title_contains": "GraphQL"},
+        "mentor_is_null": true,
+      }),
+    )
+    .unwrap();
+    assert_eq!(
+      r#where,
+      ObjectWhereInput(vec![
+        field("userId", FieldOp::Equals, false, value!("1")),
+        field("name", FieldOp::In, true, value!(["Alice"])),
+        ObjectFilter::Or(vec![ObjectWhereInput(vec![field(
+          "role",
+          FieldOp::Equals,
+          false,
+          value!("ADMIN")
+        )])]),
+        ObjectFilter::Relation(RelationFilter {
+          relation: "posts".to_string(),
+          op: RelationOp::Some(ObjectWhereInput(vec![field(
+            "title",
+            FieldOp::Contains,
+            false,
+            value!("GraphQL")
+          )])),
+        }),
+        ObjectFilter::Relation(RelationFilter {
+          relation: "mentor".to_string(),
+          op: RelationOp::IsNull(true),
+        }),
+      ])
+    );
+
+    // Filters of the other side of the relation.
+    for r#where in [
+      value!({"posts_is_null": true}),
+      value!({"mentor_some": {}}),
+      value!({"email_is_empty": true}),
+    ] {
+      assert!(matches!(
+        ObjectWhereInput::from_value(&data_model, "User", r#where),
+        Err(Error::InvalidInput(_))
+      ));
+    }
+  }
+
+  #[test]
+  fn test_list_filters() {
+    let r#where = ObjectWhereInput::from_value(
+      &data_model(),
+      "User",
+      value!({
+        "nickNames_has": "al",
+        "nickNames_has_some": "ali",
+        "nickNames_is_empty": false,
+        "nickNames_length_lte": 2,
+        "nickNames_has_every": null,
+      }),
+    )
+    .unwrap();
+    let list = |op| {
+      ObjectFilter::List(ListFilter {
+        field: "nickNames".to_string(),
+        op,
+      })
+    };
+    assert_eq!(
+      r#where,
+      ObjectWhereInput(vec![
+        list(ListOp::Has(value!("al"))),
+        list(ListOp::HasSome(vec![value!("ali")])),
+        list(ListOp::IsEmpty(false)),
+        list(ListOp::Length(FieldOp::Lte, 2)),
+      ])
+    );
+  }
+
+  #[test]
+  fn test_where_unique_input() {
+    let data_model = data_model();
+    assert_eq!(
+      ObjectWhereUniqueInput::from_value(
+        &data_model,
+        "User",
+        value!({"id": "1", "email": null})
+      )
+      .unwrap(),
+      ObjectWhereUniqueInput(IndexMap::from([("userId".to_string(), value!("1"))]))
+    );
+    assert!(matches!(
+      ObjectWhereUniqueInput::from_value(&data_model, "User", value!({"name": "Alice"})),
+      Err(Error::InvalidInput(_))
+    ));
+  }
+
+  #[test]
+  fn test_order_by_input() {
+    let data_model = data_model();
+    assert_eq!(
+      ObjectOrderByInput::from_value(&data_model, "User", value!("nickNames_DSC"))
+        .unwrap(),
+      ObjectOrderByInput(Some(FieldOrder {
+        field: "nickNames".to_string(),
+        order: Order::Dsc,
+      }))
+    );
+    assert!(
+      ObjectOrderByInput::from_value(&data_model, "User", value!("posts_ASC")).is_err()
+    );
+  }
+
+  #[test]
+  fn test_primitive_values() {
+    let data_model = data_model();
+    let create = ObjectCreateInput::from_value(
+      &data_model,
+      "Profile",
+      value!({
+        "settings": {"theme": "dark"},
+        "avatar": [104, 105],
+        "credits": "12345678901234567.890",
+        "birthday": "2000-01-01",
+        "token": "67E55044-10B1-426F-9247-BB680E5FE0C8",
+      }),
+    )
+    .unwrap();
+    assert_eq!(
+      create.fields,
+      IndexMap::from([
+        ("settings".to_string(), value!({"theme": "dark"})),
+        ("avatar".to_string(), Value::Binary(b"hi".to_vec().into())),
+        ("credits".to_string(), value!("12345678901234567.89")),
+        ("birthday".to_string(), value!("2000-01-01")),
+        (
+          "token".to_string(),
+          value!("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        ),
+      ])
+    );
+    for value in [
+      value!({"avatar": [256]}),
+      value!({"credits": "twelve"}),
+      value!({"birthday": "2000-13-01"}),
+      value!({"token": "not-a-uuid"}),
+    ] {
+      assert!(matches!(
+        ObjectUpdateInput::from_value(&data_model, "Profile", value),
+        Err(Error::InvalidInput(_))
+      ));
+    }
+
+    let r#where = ObjectWhereInput::from_value(
+      &data_model,
+      "Profile",
+      value!({
+        "credits_gt": 1e1,
+        "settings_path": {"path": ["theme"], "equals": "dark", "not": null},
+      }),
+    )
+    .unwrap();
+    assert_eq!(
+      r#where,
+      ObjectWhereInput(vec![
+        ObjectFilter::Decimal(FieldFilter {
+          field: "credits".to_string(),
+          op: FieldOp::Gt,
+          negated: false,
+          value: value!("10"),
+        }),
+        ObjectFilter::And(vec![ObjectWhereInput(vec![
+          ObjectFilter::JsonPath(JsonPathFilter {
+            path: vec!["theme".to_string()],
+            filter: FieldFilter {
+              field: "settings".to_string(),
+              op: FieldOp::Equals,
+              negated: false,
+              value: value!("dark"),
+            },
+          }),
+          ObjectFilter::JsonPath(JsonPathFilter {
+            path: vec!["theme".to_string()],
+            filter: FieldFilter {
+              field: "settings".to_string(),
+              op: FieldOp::Equals,
+              negated: true,
+              value: Value::Null,
+            },
+          }),
+        ])]),
+      ])
+    );
+    assert!(matches!(
+      ObjectWhereInput::from_value(
+        &data_model,
+        "Profile",
+        value!({"settings_path": {"equals": "dark"}})
+      ),
+      Err(Error::InvalidInput(_))
+    ));
+  }
+
+  #[test]
+  fn test_create_and_update_input() {
+    let data_model = data_model();
+    let create = ObjectCreateInput::from_value(
+      &data_model,
+      "User",
+      value!({
+        "email": "alice@x.com",
+        "name": null,
+        "posts": {"create": {"title": "First"}, "connect": [{"id": "2"}]},
+      }),
+    )
+    .unwrap();
+    assert_eq!(
+      create.fields,
+      IndexMap::from([("email".to_string(), value!("alice@x.com"))])
+    );
+    assert_eq!(
+      create.relations["posts"],
+      RelationCreateInput {
+        create: vec![ObjectCreateInput {
+          fields: IndexMap::from([("title".to_string(), value!("First"))]),
+          relations: IndexMap::new(),
+        }],
+        connect: vec![ObjectWhereUniqueInput(IndexMap::from([(
+          "postId".to_string(),
+          value!("2")
+        )]))],
+      }
+    );
+
+    let update = ObjectUpdateInput::from_value(
+      &data_model,
+      "Post",
+      value!({
+        "title": "Second",
+        "category": {
+          "connect": [{"where": {"name": "News"}, "position": {"after": "3"}}],
+          "disconnect": [{"id": "4"}],
+        },
+      }),
+    )
+    .unwrap();
+    assert_eq!(
+      update.relations["category"],
+      RelationUpdateInput {
+        connect: vec![ConnectInput {
+          r#where: ObjectWhereUniqueInput(IndexMap::from([(
+            "name".to_string(),
+            value!("News")
+          )])),
+          position: ConnectPosition::After(ID::new("3")),
+        }],
+        disconnect: Some(ConnectedObjects::Unique(vec![ObjectWhereUniqueInput(
+          IndexMap::from([("categoryId".to_string(), value!("4"))])
+        )])),
+        ..Default::default()
+      }
+    );
+    let update = ObjectUpdateInput::from_value(
+      &data_model,
+      "User",
+      value!({"mentor": {"disconnect": true}}),
+    )
+    .unwrap();
+    assert_eq!(
+      update.relations["mentor"].disconnect,
+      Some(ConnectedObjects::Connected)
+    );
+    assert!(matches!(
+      ObjectUpdateInput::from_value(&data_model, "User", value!({"unknown": 1})),
+      Err(Error::InvalidInput(_))
+    ));
+  }
+}
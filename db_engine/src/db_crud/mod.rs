@@ -1,13 +1,14 @@
-use std::sync::Arc;
-
+mod convert;
 mod mutation;
 mod object;
 mod query;
+mod store;
 mod types;
 
 pub use mutation::DBMutation;
 pub use object::DBObject;
 pub use query::DBQuery;
+pub use store::DBStore;
 pub use types::*;
 
-
+pub use crate::model::FIELD_ID;
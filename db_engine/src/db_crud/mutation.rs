@@ -5,7 +5,7 @@ use super::*;
 
 /// DB Mutation Interface.
 #[async_trait]
-pub trait DBMutation {
+pub trait DBMutation: Send + Sync {
   /// Create and persist new object in DB.
   async fn create_object(
     &mut self,
@@ -41,21 +41,14 @@ pub trait DBMutation {
   async fn delete_many_objects(
     &mut self,
     r#where: ObjectWhereInput,
-    skip: u32,
-    after: ID,
-    first: u32,
-    before: ID,
-    last: u32,
+    pagination: Pagination,
   ) -> Result<ObjectConnection, Error>;
   /// Updates more than one objects found using the filter.
   /// and returns the updated objects.
   async fn update_many_objects(
     &mut self,
     r#where: ObjectWhereInput,
-    skip: u32,
-    after: ID,
-    first: u32,
-    before: ID,
-    last: u32,
+    data: ObjectUpdateInput,
+    pagination: Pagination,
   ) -> Result<ObjectConnection, Error>;
 }
@@ -5,19 +5,17 @@ use super::*;
 
 /// DB Query interface.
 #[async_trait]
-pub trait DBQuery {
+pub trait DBQuery: Send + Sync {
+  /// Returns the object found by the unique filter, if any.
   async fn get_object(
     &self,
     r#where: ObjectWhereUniqueInput,
-  ) -> Result<Box<dyn DBObject>, Error>;
+  ) -> Result<Option<Box<dyn DBObject>>, Error>;
+  /// Returns the objects found using the filter, in the given order.
   async fn get_objects(
     &self,
     r#where: ObjectWhereInput,
     order_by: ObjectOrderByInput,
-    skip: u32,
-    after: ID,
-    first: u32,
-    before: ID,
-    last: u32,
+    pagination: Pagination,
   ) -> Result<ObjectConnection, Error>;
 }
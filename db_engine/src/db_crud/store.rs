@@ -0,0 +1,14 @@
+use super::*;
+
+/// Data store holding the objects of all the models in a data model.
+///
+/// The query engine resolves each root field against the model specific
+/// query & mutation interfaces handed out by the store.
+pub trait DBStore: Send + Sync {
+  /// Query interface for the model of the given name.
+  /// Returns `None` if the model is unknown to the store.
+  fn query(&self, model_name: &str) -> Option<Box<dyn DBQuery>>;
+  /// Mutation interface for the model of the given name.
+  /// Returns `None` if the model is unknown to the store.
+  fn mutation(&self, model_name: &str) -> Option<Box<dyn DBMutation>>;
+}
@@ -1,25 +1,329 @@
-use std::sync::Arc;
+use std::{fmt, sync::Arc};
+
+use graphql_value::ConstValue as Value;
+use indexmap::IndexMap;
+
+use super::DBObject;
 
 /// ID of an object.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ID(Arc<str>);
 
+impl ID {
+  pub fn new(id: impl AsRef<str>) -> Self {
+    ID(Arc::from(id.as_ref()))
+  }
+
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl fmt::Display for ID {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+impl From<ID> for Value {
+  fn from(id: ID) -> Self {
+    Value::String(id.0.to_string())
+  }
+}
+
 /// Unique filter to search and find at most a object.
-pub struct ObjectWhereUniqueInput {}
+/// Holds the values of the unique fields by the model field name, all of them
+/// should match the same object.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectWhereUniqueInput(pub IndexMap<String, Value>);
 
 /// Filter to search and find more than one objects.
-pub struct ObjectWhereInput {}
+/// Object should match all of the filters, an empty filter matches every object.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectWhereInput(pub Vec<ObjectFilter>);
+
+/// A filter of the `*WhereInput`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectFilter {
+  /// Object matches all of the filters.
+  And(Vec<ObjectWhereInput>),
+  /// Object matches at least one of the filters.
+  Or(Vec<ObjectWhereInput>),
+  /// Object doesn't match all of the filters.
+  Not(Vec<ObjectWhereInput>),
+  /// Filter on a scalar field, ex. `title_contains`.
+  Field(FieldFilter),
+  /// Filter on a decimal field, ex. `price_gt`. Decimals are held as strings, so
+  /// they are compared numerically rather than as the strings.
+  Decimal(FieldFilter),
+  /// Filter on a relation field, ex. `posts_some`.
+  Relation(RelationFilter),
+  /// Filter on a composite field, ex. `address` or `pastAddresses_some`.
+  Composite(CompositeFilter),
+  /// Filter on the value at a path inside a json field, ex. `settings_path`.
+  JsonPath(JsonPathFilter),
+  /// Filter on the items of a scalar list field, ex. `nickNames_has`.
+  List(ListFilter),
+}
+
+/// Filter on the value of a scalar field.
+/// List field matches if any of its items match, except when it is compared
+/// for equality with a list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldFilter {
+  /// Model field name.
+  pub field: String,
+  pub op: FieldOp,
+  /// Filter is negated, ex. `_not_in`.
+  pub negated: bool,
+  /// Value to compare with, a list for `In`.
+  pub value: Value,
+}
+
+/// Filter on the value found at the path inside a json field, the value is `null`
+/// if nothing is found at the path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPathFilter {
+  /// Keys of the nested objects (or the indexes of the nested lists).
+  pub path: Vec<String>,
+  /// Filter on the json field, matched against the value at the path.
+  pub filter: FieldFilter,
+}
+
+/// Filter on the items of a scalar list field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListFilter {
+  /// Model field name.
+  pub field: String,
+  pub op: ListOp,
+}
+
+/// Operators of the scalar list field filters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListOp {
+  /// List has the item.
+  Has(Value),
+  /// List has all of the items.
+  HasEvery(Vec<Value>),
+  /// List has at least one of the items.
+  HasSome(Vec<Value>),
+  /// List is empty (`true`) or not (`false`), a `null` list being empty.
+  IsEmpty(bool),
+  /// Length of the list compared with the operator, one of `Equals`, `Lt`,
+  /// `Lte`, `Gt` or `Gte`.
+  Length(FieldOp, usize),
+}
+
+/// Operators of the scalar field filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldOp {
+  Equals,
+  Contains,
+  StartsWith,
+  EndsWith,
+  /// Operators comparing the strings, ignoring the case.
+  EqualsIgnoreCase,
+  ContainsIgnoreCase,
+  StartsWithIgnoreCase,
+  EndsWithIgnoreCase,
+  Lt,
+  Lte,
+  Gt,
+  Gte,
+  In,
+}
+
+/// Filter on the objects connected through a relation field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelationFilter {
+  /// Relation field name.
+  pub relation: String,
+  pub op: RelationOp,
+}
+
+/// Filter on the values embedded in a composite field, the nested filters are
+/// matched against the fields of the embedded values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeFilter {
+  /// Composite field name.
+  pub field: String,
+  /// Same operators as the relation filters, an embedded value standing for
+  /// a connected object.
+  pub op: RelationOp,
+}
 
-/// Objects order in result.
-pub enum ObjectOrderByInput {}
+/// Operators of the relation field filters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RelationOp {
+  /// Connected object matches the filter, or no object is connected if `None`.
+  Is(Option<ObjectWhereInput>),
+  /// Object is connected (`false`) or not (`true`), for one-side relations.
+  IsNull(bool),
+  /// All of the connected objects match the filter.
+  Every(ObjectWhereInput),
+  /// At least one of the connected objects matches the filter.
+  Some(ObjectWhereInput),
+  /// None of the connected objects match the filter.
+  None(ObjectWhereInput),
+  /// No object is connected (`true`) or some are (`false`), for many-side relations.
+  IsEmpty(bool),
+}
+
+/// Objects order in result, insertion order if no field is given.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectOrderByInput(pub Option<FieldOrder>);
+
+/// Order of the objects by the value of a scalar field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldOrder {
+  /// Model field name.
+  pub field: String,
+  pub order: Order,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+  Asc,
+  Dsc,
+}
 
 /// All inputs needed to create a single object.
-pub struct ObjectCreateInput {}
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectCreateInput {
+  /// Values of the scalar fields by the model field name.
+  /// Fields not given get their default value.
+  pub fields: IndexMap<String, Value>,
+  /// Nested writes by the relation field name.
+  pub relations: IndexMap<String, RelationCreateInput>,
+}
+
+/// Nested writes to a relation, while creating the object.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RelationCreateInput {
+  /// New objects to create and connect.
+  pub create: Vec<ObjectCreateInput>,
+  /// Existing objects to connect.
+  pub connect: Vec<ObjectWhereUniqueInput>,
+}
 
 /// Type to capture the update data to update a single object.
-pub struct ObjectUpdateInput {}
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectUpdateInput {
+  /// New values of the scalar fields by the model field name.
+  pub fields: IndexMap<String, Value>,
+  /// Partial updates of the single composite fields by the model field name,
+  /// merged into their current values.
+  pub composites: IndexMap<String, ObjectUpdateInput>,
+  /// Nested writes by the relation field name.
+  pub relations: IndexMap<String, RelationUpdateInput>,
+}
+
+/// Nested writes to a relation, while updating the object.
+/// Applied in the order: set, disconnect, delete, connect, create, update & upsert.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RelationUpdateInput {
+  /// Replaces all the connected objects, for many-side relations.
+  pub set: Option<Vec<ObjectWhereUniqueInput>>,
+  pub disconnect: Option<ConnectedObjects>,
+  /// Connected objects to delete.
+  pub delete: Option<ConnectedObjects>,
+  /// Existing objects to connect.
+  pub connect: Vec<ConnectInput>,
+  /// New objects to create and connect.
+  pub create: Vec<ObjectCreateInput>,
+  /// Connected objects to update.
+  pub update: Vec<NestedUpdateInput>,
+  /// Connected objects to update, or new objects to create and connect.
+  pub upsert: Vec<NestedUpsertInput>,
+}
+
+/// Connected objects to disconnect or delete.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectedObjects {
+  /// Object connected to a one-side relation.
+  Connected,
+  /// Objects found by the unique filters, among the connected ones.
+  Unique(Vec<ObjectWhereUniqueInput>),
+}
+
+/// Existing object to connect, at the position.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectInput {
+  pub r#where: ObjectWhereUniqueInput,
+  pub position: ConnectPosition,
+}
+
+/// Position of the connected object in a many-side relation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ConnectPosition {
+  Start,
+  #[default]
+  End,
+  Before(ID),
+  After(ID),
+}
+
+/// Update of a connected object, found by the unique filter.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NestedUpdateInput {
+  pub r#where: ObjectWhereUniqueInput,
+  pub data: ObjectUpdateInput,
+}
+
+/// Upsert of a connected object, found by the unique filter.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NestedUpsertInput {
+  pub r#where: ObjectWhereUniqueInput,
+  pub data: ObjectUpsertInput,
+}
 
 /// Type to capture the upsert data to either create or update a single object.
-pub struct ObjectUpsertInput {}
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectUpsertInput {
+  /// Used when the object isn't found.
+  pub create: ObjectCreateInput,
+  /// Used when the object is found.
+  pub update: ObjectUpdateInput,
+}
+
+/// Pagination arguments of a query returning array of objects.
+#[derive(Debug, Clone, Default)]
+pub struct Pagination {
+  pub skip: Option<u32>,
+  pub after: Option<ID>,
+  pub first: Option<u32>,
+  pub before: Option<ID>,
+  pub last: Option<u32>,
+}
 
 /// Container to capture array objects, along with pagniation data.
-pub struct ObjectConnection {}
+#[derive(Default)]
+pub struct ObjectConnection {
+  /// Pagination information of the current page.
+  pub page_info: PageInfo,
+  /// Objects in the current page.
+  pub edges: Vec<ObjectEdge>,
+  /// Number of objects matching the filter, ignoring pagination.
+  pub count: usize,
+}
+
+/// An object in a connection, along with its cursor.
+pub struct ObjectEdge {
+  pub node: Box<dyn DBObject>,
+  pub cursor: ID,
+}
+
+/// Pagination information of a connection.
+#[derive(Debug, Clone, Default)]
+pub struct PageInfo {
+  /// When paginating forwards, are there more items ?
+  pub has_next_page: bool,
+  /// When paginating backwards, are there more items ?
+  pub has_previous_page: bool,
+  /// When paginating backwards, cursor to continue.
+  pub start_cursor: Option<ID>,
+  /// When paginating forwards, cursor to continue.
+  pub end_cursor: Option<ID>,
+  /// Number of items in current page.
+  pub page_size: Option<u32>,
+}
@@ -1,2 +1,48 @@
+use thiserror::Error;
+
 /// Errors.
-pub enum Error {}
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error {
+  /// Object to update, delete or connect is not found.
+  #[error("{model} object not found.")]
+  NotFound { model: String },
+  /// Value of a unique field is already taken by another object.
+  #[error(r#"Value "{value}" of the unique field "{model}.{field}" is already taken."#)]
+  UniqueViolation {
+    model: String,
+    field: String,
+    value: String,
+  },
+  /// Change breaks a relation, e.g. deleting an object still referenced by a
+  /// required relation.
+  #[error("{0}")]
+  RelationViolation(String),
+  /// Input doesn't fit the data model.
+  #[error("{0}")]
+  InvalidInput(String),
+  /// Transaction conflicted with the concurrent ones, even after the retries.
+  #[error("Transaction failed, retries exhausted: {0}")]
+  TxConflict(String),
+  /// Value is larger than the data store can hold.
+  #[error("{what} is larger than {limit} bytes.")]
+  ValueTooLarge { what: String, limit: usize },
+  /// Data store backend failed to read or write.
+  #[error("Data store failed: {0}")]
+  Io(String),
+}
+
+impl Error {
+  /// Stable code of the error, for the clients to switch on.
+  pub fn code(&self) -> &'static str {
+    match self {
+      Error::NotFound { .. } => "NOT_FOUND",
+      Error::UniqueViolation { .. } => "UNIQUE_VIOLATION",
+      Error::RelationViolation(_) => "RELATION_VIOLATION",
+      Error::InvalidInput(_) => "INVALID_INPUT",
+      Error::TxConflict(_) => "TX_CONFLICT",
+      Error::ValueTooLarge { .. } => "VALUE_TOO_LARGE",
+      Error::Io(_) => "IO_ERROR",
+    }
+  }
+}
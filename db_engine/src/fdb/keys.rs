@@ -0,0 +1,188 @@
+//! Keys of the data model in the FoundationDB, see the module docs for the layout.
+use foundationdb::tuple::{Element, Subspace};
+use graphql_value::ConstValue as Value;
+
+use crate::{
+  db_crud::ID,
+  model::Model,
+  tables::{value, Counter, Fields},
+  Error,
+};
+
+/// Version of the key layout, bumped on every incompatible change to it.
+pub(super) const LAYOUT_VERSION: i64 = 2;
+
+/// Largest key & value accepted by the FoundationDB, in bytes.
+const MAX_KEY_SIZE: usize = 10_000;
+const MAX_VALUE_SIZE: usize = 100_000;
+
+const META: &str = "meta";
+const META_LAYOUT_VERSION: &str = "layout_version";
+const META_NEXT_ID: &str = "next_id";
+const META_SEQUENCE: &str = "seq";
+const OBJECTS: &str = "obj";
+const UNIQUE: &str = "uniq";
+const INDEXES: &str = "idx";
+
+/// Key & value pair to write.
+pub(super) type Entry = (Vec<u8>, Vec<u8>);
+
+/// Keys under the root subspace of the data model.
+pub(super) struct Keys {
+  meta: Subspace,
+  objects: Subspace,
+  unique: Subspace,
+  indexes: Subspace,
+}
+
+impl Keys {
+  pub fn new(root: Subspace) -> Self {
+    Keys {
+      meta: root.subspace(&META),
+      objects: root.subspace(&OBJECTS),
+      unique: root.subspace(&UNIQUE),
+      indexes: root.subspace(&INDEXES),
+    }
+  }
+
+  pub fn layout_version(&self) -> Vec<u8> {
+    self.meta.pack(&META_LAYOUT_VERSION)
+  }
+
+  pub fn counter(&self, counter: &Counter) -> Vec<u8> {
+    match counter {
+      Counter::Id => self.meta.pack(&META_NEXT_ID),
+      Counter::Sequence(model_name, field_name) => {
+        self.meta.pack(&(META_SEQUENCE, model_name, field_name))
+      }
+    }
+  }
+
+  /// Subspace holding all the objects of the model.
+  pub fn objects(&self, model_name: &str) -> Subspace {
+    self.objects.subspace(&model_name)
+  }
+
+  pub fn object(&self, model_name: &str, id: &ID) -> Vec<u8> {
+    self.objects.pack(&(model_name, id.as_str()))
+  }
+
+  pub fn unique(&self, model_name: &str, field_name: &str, value: &Value) -> Vec<u8> {
+    self.unique.pack(&(model_name, field_name, element(value)))
+  }
+
+  /// Subspace holding the index entries of the field (or compound key) value,
+  /// an entry per object holding it.
+  pub fn index_values(
+    &self,
+    model_name: &str,
+    field_name: &str,
+    value: &Value,
+  ) -> Subspace {
+    self
+      .indexes
+      .subspace(&(model_name, field_name, element(value)))
+  }
+
+  fn index(&self, model_name: &str, field_name: &str, value: &Value, id: &ID) -> Vec<u8> {
+    self
+      .index_values(model_name, field_name, value)
+      .pack(&id.as_str())
+  }
+
+  /// Object entry, fails if the object is too large to be stored.
+  pub fn object_entry(
+    &self,
+    model: &Model,
+    id: &ID,
+    fields: &Fields,
+  ) -> Result<Entry, Error> {
+    let object =
+      serde_json::to_vec(fields).map_err(|error| Error::Io(error.to_string()))?;
+    if object.len() > MAX_VALUE_SIZE {
+      return Err(Error::ValueTooLarge {
+        what: format!(r#"{} object "{id}""#, model.name),
+        limit: MAX_VALUE_SIZE,
+      });
+    }
+    Ok((self.object(&model.name, id), object))
+  }
+
+  /// Unique & field index entries of the object, an entry per item of the list values
+  /// and an entry per compound key.
+  /// Note: `null` values aren't indexed, neither the compound keys with a `null` field.
+  pub fn index_entries(
+    &self,
+    model: &Model,
+    id: &ID,
+    fields: &Fields,
+  ) -> Result<Vec<Entry>, Error> {
+    let mut entries = Vec::new();
+    for field in model.indexed_fields() {
+      let field_value = fields.get(&field.name).unwrap_or(&Value::Null);
+      for item in value::items(field_value) {
+        let entry = if field.is_unique {
+          (
+            self.unique(&model.name, &field.name, item),
+            foundationdb::tuple::pack(&id.as_str()),
+          )
+        } else {
+          (self.index(&model.name, &field.name, item, id), Vec::new())
+        };
+        if entry.0.len() > MAX_KEY_SIZE {
+          return Err(Error::ValueTooLarge {
+            what: format!(
+              r#"Value of the indexed field "{}.{}""#,
+              model.name, field.name
+            ),
+            limit: MAX_KEY_SIZE,
+          });
+        }
+        entries.push(entry);
+      }
+    }
+    for compound_key in model.compound_keys.values() {
+      let Some(key_value) = compound_key.value(fields) else {
+        continue;
+      };
+      let entry = if compound_key.is_unique {
+        (
+          self.unique(&model.name, &compound_key.name, &key_value),
+          foundationdb::tuple::pack(&id.as_str()),
+        )
+      } else {
+        (
+          self.index(&model.name, &compound_key.name, &key_value, id),
+          Vec::new(),
+        )
+      };
+      if entry.0.len() > MAX_KEY_SIZE {
+        return Err(Error::ValueTooLarge {
+          what: format!(
+            r#"Value of the compound key "{}.{}""#,
+            model.name, compound_key.name
+          ),
+          limit: MAX_KEY_SIZE,
+        });
+      }
+      entries.push(entry);
+    }
+    Ok(entries)
+  }
+}
+
+/// Tuple element of the indexed value, so that the index keys sort in the value order.
+fn element(value: &Value) -> Element<'_> {
+  match value {
+    Value::Null => Element::Nil,
+    Value::Boolean(value) => Element::Bool(*value),
+    Value::Number(number) => match number.as_i64() {
+      Some(number) => Element::Int(number),
+      None => Element::Double(number.as_f64().unwrap_or_default()),
+    },
+    Value::String(value) => Element::String(value.as_str().into()),
+    Value::Enum(value) => Element::String(value.as_str().into()),
+    Value::List(items) => Element::Tuple(items.iter().map(element).collect()),
+    value => Element::String(value.to_string().into()),
+  }
+}
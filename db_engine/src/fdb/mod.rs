@@ -0,0 +1,825 @@
+//! FoundationDB data store, enabled by the `fdb` feature.
+//!
+//! Each GraphQL mutation runs in a single FoundationDB transaction, retried on
+//! conflicts, so that it is applied in full or not at all.
+//!
+//! Objects are loaded as they are looked up: by their keys, by the `"uniq"` entry
+//! of a unique value, or by the range of `"idx"` entries of an indexed value. A
+//! filter the indexes can't answer reads the objects of the model a page at a time,
+//! each page in a transaction of its own.
+//! Reads & writes run again once the objects they looked up are loaded.
+//! Note: Filtering on a relation whose scalar field isn't `@indexed` reads all the
+//! objects of the related model.
+//!
+//! ## Key layout (version 2)
+//!
+//! All the keys are packed with the tuple layer, under the `root` subspace given
+//! to [`FdbStore::open`].
+//!
+//! | Key                                         | Value                              |
+//! |---------------------------------------------|------------------------------------|
+//! | `(root, "meta", "layout_version")`          | Packed integer, the layout version |
+//! | `(root, "meta", "next_id")`                 | Counter, last auto ID reserved     |
+//! | `(root, "meta", "seq", model, field)`       | Counter, last sequence reserved    |
+//! | `(root, "obj", model, id)`                  | Object fields, as JSON             |
+//! | `(root, "uniq", model, field, value)`       | Packed string, ID of the object    |
+//! | `(root, "idx", model, field, value, id)`    | Empty                              |
+//!
+//! * `model` & `field` are the names in the SDML, `id` is the string of the
+//!   object's ID field value.
+//! * Every unique (`@id`, `@unique`) field gets an entry in `"uniq"`, every other
+//!   indexed field (`@indexed`) gets an entry in `"idx"`.
+//! * Compound keys are indexed alike: `@@id` & `@@unique` in `"uniq"`, `@@index` in
+//!   `"idx"`. Their `field` is the compound name (field names joined by `_`) and
+//!   `value` is the nested tuple of the field values. `id` of the model with `@@id`
+//!   is the string of the list of its field values.
+//! * Index `value` is the tuple element of the field value: strings & enums are
+//!   strings, integers are integers, other numbers are doubles and booleans are
+//!   booleans. List values get an entry per item, `null` values aren't indexed.
+//! * Counters are 64-bit little-endian integers, added to atomically. Each store
+//!   reserves a block of values at a time, so the `auto()` IDs & the `sequence()`
+//!   values increase within a store, but not across the stores, and have gaps.
+//! * Objects are listed in the order of their keys, i.e. in the ID order.
+//! * Links of the implicit many-to-many relation are the objects of its hidden join
+//!   model, named `#` followed by the relation name. Each link holds the IDs of the
+//!   connected objects in the indexed fields `A` & `B`, identified by `@@id([A, B])`.
+//!
+//! Store refuses to open a layout of another version. Any incompatible change to
+//! the layout bumps the version.
+mod keys;
+mod mutation;
+mod object;
+mod query;
+
+use std::{
+  future::Future,
+  sync::{Arc, Mutex},
+};
+
+use foundationdb::{
+  options::{MutationType, StreamingMode},
+  tuple::{pack, unpack, Subspace},
+  Database, FdbBindingError, KeySelector, RangeOption, RetryableTransaction, Transaction,
+};
+use futures_util::future::try_join_all;
+use sdml_parser::types::DataModel;
+
+use crate::{
+  db_crud::{
+    DBMutation, DBQuery, DBStore, ObjectOrderByInput, ObjectWhereInput, Pagination, ID,
+  },
+  model::{self, Model, Models},
+  tables::{Counter, Counters, Fields, Lookup, Table, Tables, Txn, View},
+  Error,
+};
+use keys::{Keys, LAYOUT_VERSION};
+use mutation::FdbMutation;
+use object::Objects;
+use query::FdbQuery;
+
+/// Objects read at a time, when the objects of a model are searched in full.
+const PAGE_SIZE: usize = 1_000;
+
+/// Values of a counter reserved at a time.
+const RESERVED_VALUES: i64 = 100;
+
+/// FoundationDB data store of a data model.
+#[derive(Clone)]
+pub struct FdbStore(Arc<Store>);
+
+struct Store {
+  db: Database,
+  keys: Keys,
+  models: Models,
+  /// Counted values reserved by the store, see [`Store::reserve`].
+  counters: Mutex<Counters>,
+}
+
+impl FdbStore {
+  /// Opens the store of the data model, under the `root` subspace of the database.
+  /// Fails if the data under `root` is of another key layout version.
+  ///
+  /// Note: FoundationDB network should be booted (`foundationdb::boot`) by the caller.
+  pub async fn open(
+    db: Database,
+    root: Subspace,
+    data_model: &DataModel,
+  ) -> Result<Self, Error> {
+    let store = Store {
+      db,
+      keys: Keys::new(root),
+      models: model::models(data_model),
+      counters: Mutex::new(Counters::reserved()),
+    };
+    let key = store.keys.layout_version();
+    let key = &key;
+    store
+      .run(|trx| async move {
+        match trx.get(key, false).await? {
+          None => {
+            trx.set(key, &pack(&LAYOUT_VERSION));
+            Ok(())
+          }
+          Some(version) => {
+            let version = unpack::<i64>(&version).map_err(FdbBindingError::PackError)?;
+            if version == LAYOUT_VERSION {
+              Ok(())
+            } else {
+              Err(custom(Error::Io(format!(
+                "Key layout version {version} isn't supported, expected {LAYOUT_VERSION}."
+              ))))
+            }
+          }
+        }
+      })
+      .await?;
+    Ok(FdbStore(Arc::new(store)))
+  }
+}
+
+impl DBStore for FdbStore {
+  fn query(&self, model_name: &str) -> Option<Box<dyn DBQuery>> {
+    let model = self.0.models.get(model_name)?.clone();
+    Some(Box::new(FdbQuery::new(self.0.clone(), model)))
+  }
+
+  fn mutation(&self, model_name: &str) -> Option<Box<dyn DBMutation>> {
+    let model = self.0.models.get(model_name)?.clone();
+    Some(Box::new(FdbMutation::new(self.0.clone(), model)))
+  }
+}
+
+impl Store {
+  /// Runs the transaction, retrying it on the conflicts.
+  /// Note: A retryable FoundationDB error left after the retries is a conflict.
+  async fn run<T, F, Fut>(&self, run: F) -> Result<T, Error>
+  where
+    F: Fn(RetryableTransaction) -> Fut,
+    Fut: Future<Output = Result<T, FdbBindingError>>,
+  {
+    self
+      .db
+      .run(|trx, _maybe_committed| run(trx))
+      .await
+      .map_err(|error| match error {
+        FdbBindingError::CustomError(error) => match error.downcast::<Error>() {
+          Ok(error) => *error,
+          Err(error) => Error::Io(error.to_string()),
+        },
+        error => match error.get_fdb_error() {
+          Some(fdb_error) if fdb_error.is_retryable() => {
+            Error::TxConflict(fdb_error.to_string())
+          }
+          _ => Error::Io(error.to_string()),
+        },
+      })
+  }
+
+  /// Partially loaded tables of all the models, see [`Table::partial`].
+  fn tables(&self) -> Tables {
+    self
+      .models
+      .keys()
+      .map(|model_name| (model_name.clone(), Table::partial()))
+      .collect()
+  }
+
+  /// Reads the tables in a transaction.
+  /// Note: Read runs on the objects loaded so far, then runs again once the
+  /// objects it looked up are loaded, until it finds them all loaded.
+  async fn read<T: Send>(
+    &self,
+    read: impl Fn(&View<'_>) -> Result<T, Error> + Sync,
+  ) -> Result<T, Error> {
+    let read = &read;
+    self
+      .run(|trx| async move {
+        let mut tables = self.tables();
+        loop {
+          let result = read(&View {
+            models: &self.models,
+            tables: &tables,
+          });
+          let missing = missing(&tables);
+          if missing.is_empty() {
+            return result.map_err(custom);
+          }
+          self.load(&trx, &mut tables, missing).await?;
+        }
+      })
+      .await
+  }
+
+  /// Writes to the tables in a transaction, then stores the changes.
+  /// Note: Writes run like the reads, each run on a fresh copy of the objects
+  /// loaded so far, replaying the values generated by the previous runs. Counted
+  /// values are drawn from the blocks reserved by the store, see [`Store::reserve`].
+  async fn write<T: Send>(
+    &self,
+    write: impl Fn(&mut Txn<'_>) -> Result<T, Error> + Sync,
+  ) -> Result<T, Error> {
+    let write = &write;
+    self
+      .run(|trx| async move {
+        let mut before = self.tables();
+        let mut replay = Vec::new();
+        loop {
+          let mut tables = before.clone();
+          let (result, exhausted) = {
+            let mut counters = self.counters.lock().unwrap();
+            let mut txn =
+              Txn::new(&self.models, &mut tables, &mut counters).replaying(&mut replay);
+            let result = write(&mut txn).and_then(|result| txn.commit().map(|_| result));
+            (result, counters.take_exhausted())
+          };
+          if !exhausted.is_empty() {
+            self.reserve(exhausted).await.map_err(custom)?;
+            continue;
+          }
+          let missing = missing(&tables);
+          if !missing.is_empty() {
+            self.load(&trx, &mut before, missing).await?;
+            continue;
+          }
+          let result = result.map_err(custom)?;
+          self.save(&trx, &before, &tables).map_err(custom)?;
+          return Ok(result);
+        }
+      })
+      .await
+  }
+
+  /// Finds the objects of the model matching the filter, then orders & paginates
+  /// them. Objects are looked up through the indexes when the filter allows,
+  /// otherwise they are read a page at a time, keeping the ones matching.
+  /// Note: Each page is read in a transaction of its own, so that the search isn't
+  /// bound by the time limit of a transaction, thus the pages aren't read at the
+  /// same version of the database.
+  async fn find_many(
+    &self,
+    model: &Model,
+    r#where: &ObjectWhereInput,
+    order_by: &ObjectOrderByInput,
+    pagination: &Pagination,
+  ) -> Result<Objects, Error> {
+    let all = (model.name.clone(), Lookup::All);
+    let all = &all;
+    let objects = self
+      .run(|trx| async move {
+        let mut tables = self.tables();
+        loop {
+          let view = View {
+            models: &self.models,
+            tables: &tables,
+          };
+          let objects = view
+            .find_many(model, None, r#where, order_by, pagination)
+            .and_then(|page| object::page_objects(model, &view, page));
+          let missing = missing(&tables);
+          if missing.is_empty() {
+            return objects.map(Some).map_err(custom);
+          }
+          if missing.contains(all) {
+            return Ok(None);
+          }
+          self.load(&trx, &mut tables, missing).await?;
+        }
+      })
+      .await?;
+    if let Some(objects) = objects {
+      return Ok(objects);
+    }
+
+    let mut matches = Table::partial();
+    // Note: Objects of the other models looked up are kept for the next pages.
+    let mut tables = self.tables();
+    let mut after = None;
+    loop {
+      let (found, next, loaded) = self
+        .find_page(model, r#where, &tables, after.as_deref())
+        .await?;
+      for (id, fields) in found {
+        matches.load(model, Lookup::Object(id), vec![fields])?;
+      }
+      tables = loaded;
+      match next {
+        Some(next) => after = Some(next),
+        None => break,
+      }
+    }
+
+    let ids = matches.iter().map(|(id, _)| id.clone()).collect();
+    let tables = Tables::from([(model.name.clone(), matches)]);
+    let view = View {
+      models: &self.models,
+      tables: &tables,
+    };
+    view
+      .find_many(
+        model,
+        Some(ids),
+        &ObjectWhereInput::default(),
+        order_by,
+        pagination,
+      )
+      .and_then(|page| object::page_objects(model, &view, page))
+  }
+
+  /// Reads a page of the model's objects, after the key `after`, & keeps the ones
+  /// matching the filter. Returns them with the key of the page's last object, if
+  /// more objects follow, and the tables of the other models looked up.
+  async fn find_page(
+    &self,
+    model: &Model,
+    r#where: &ObjectWhereInput,
+    tables: &Tables,
+    after: Option<&[u8]>,
+  ) -> Result<(Vec<(ID, Fields)>, Option<Vec<u8>>, Tables), Error> {
+    let objects = self.keys.objects(&model.name);
+    let objects = &objects;
+    self
+      .run(|trx| async move {
+        let mut tables = tables.clone();
+        let mut range = RangeOption {
+          limit: Some(PAGE_SIZE),
+          mode: StreamingMode::WantAll,
+          ..RangeOption::from(objects)
+        };
+        if let Some(after) = after {
+          range.begin = KeySelector::first_greater_than(after.to_vec());
+        }
+        let values = trx.get_range(&range, 1, false).await?;
+        let mut page = Table::partial();
+        let mut ids = Vec::new();
+        for value in values.iter() {
+          let fields = decode(value.value())?;
+          let Some(id) = model.object_id(&fields) else {
+            return Err(custom(Error::Io(format!(
+              "Stored {} object has no ID.",
+              model.name
+            ))));
+          };
+          page
+            .load(model, Lookup::Object(id.clone()), vec![fields])
+            .map_err(custom)?;
+          ids.push(id);
+        }
+        tables.insert(model.name.clone(), page);
+        let found = loop {
+          let view = View {
+            models: &self.models,
+            tables: &tables,
+          };
+          let found = view.find_many(
+            model,
+            Some(ids.clone()),
+            r#where,
+            &ObjectOrderByInput::default(),
+            &Pagination::default(),
+          );
+          let missing = missing(&tables);
+          if missing.is_empty() {
+            break found.map_err(custom)?;
+          }
+          self.load(&trx, &mut tables, missing).await?;
+        };
+        let page = tables.remove(&model.name).unwrap_or_default();
+        let found = found
+          .ids
+          .into_iter()
+          .filter_map(|id| page.get(&id).cloned().map(|fields| (id, fields)))
+          .collect();
+        let next = match (values.more(), values.last()) {
+          (true, Some(last)) => Some(last.key().to_vec()),
+          _ => None,
+        };
+        Ok((found, next, tables))
+      })
+      .await
+  }
+
+  /// Loads the objects looked up into the partially loaded tables.
+  async fn load(
+    &self,
+    trx: &Transaction,
+    tables: &mut Tables,
+    missing: Vec<(String, Lookup)>,
+  ) -> Result<(), FdbBindingError> {
+    let objects = try_join_all(
+      missing
+        .iter()
+        .map(|(model_name, lookup)| self.lookup(trx, &self.models[model_name], lookup)),
+    )
+    .await?;
+    for ((model_name, lookup), objects) in missing.into_iter().zip(objects) {
+      let model = &self.models[&model_name];
+      if let Some(table) = tables.get_mut(&model_name) {
+        table.load(model, lookup, objects).map_err(custom)?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Objects found by the lookup: objects by their key, unique values by their
+  /// `"uniq"` entry, other indexed values by the range of their `"idx"` entries
+  /// and all the objects by the range of the model's objects.
+  async fn lookup(
+    &self,
+    trx: &Transaction,
+    model: &Model,
+    lookup: &Lookup,
+  ) -> Result<Vec<Fields>, FdbBindingError> {
+    let ids = match lookup {
+      Lookup::Object(id) => vec![id.clone()],
+      Lookup::Value { name, value } => {
+        let is_unique = match model.compound_keys.get(name) {
+          Some(compound_key) => compound_key.is_unique,
+          None => model.fields.get(name).is_some_and(|field| field.is_unique),
+        };
+        if is_unique {
+          let key = self.keys.unique(&model.name, name, value);
+          match trx.get(&key, false).await? {
+            Some(id) => vec![ID::new(
+              unpack::<String>(&id).map_err(FdbBindingError::PackError)?,
+            )],
+            None => vec![],
+          }
+        } else {
+          let values = self.keys.index_values(&model.name, name, value);
+          let mut ids = Vec::new();
+          self
+            .read_range(trx, &values, |key, _| {
+              let id = values
+                .unpack::<String>(key)
+                .map_err(FdbBindingError::PackError)?;
+              ids.push(ID::new(id));
+              Ok(())
+            })
+            .await?;
+          ids
+        }
+      }
+      Lookup::All => {
+        let mut objects = Vec::new();
+        self
+          .read_range(trx, &self.keys.objects(&model.name), |_, object| {
+            objects.push(decode(object)?);
+            Ok(())
+          })
+          .await?;
+        return Ok(objects);
+      }
+    };
+    let objects = try_join_all(
+      ids
+        .iter()
+        .map(|id| trx.get(&self.keys.object(&model.name, id), false)),
+    )
+    .await?;
+    objects
+      .iter()
+      .flatten()
+      .map(|object| decode(object))
+      .collect()
+  }
+
+  /// Reads the key-value pairs of the subspace, a batch at a time.
+  async fn read_range(
+    &self,
+    trx: &Transaction,
+    subspace: &Subspace,
+    mut read: impl FnMut(&[u8], &[u8]) -> Result<(), FdbBindingError>,
+  ) -> Result<(), FdbBindingError> {
+    let mut range = RangeOption::from(subspace);
+    let mut iteration = 1;
+    loop {
+      let values = trx.get_range(&range, iteration, false).await?;
+      for value in values.iter() {
+        read(value.key(), value.value())?;
+      }
+      match range.next_range(&values) {
+        Some(next) => range = next,
+        None => break,
+      }
+      iteration += 1;
+    }
+    Ok(())
+  }
+
+  /// Reserves a block of values for each of the counters run out of them, in a
+  /// transaction of its own. So that the writes draw the counted values without
+  /// reading the counters, which every write creating the objects would conflict on.
+  /// Note: Values are reserved by adding to the counters atomically, the values
+  /// left in a block when the store is dropped are never used.
+  async fn reserve(&self, exhausted: Vec<Counter>) -> Result<(), Error> {
+    let exhausted = &exhausted;
+    let reserved = self
+      .run(|trx| async move {
+        let mut reserved = Vec::new();
+        for counter in exhausted {
+          let key = self.keys.counter(counter);
+          trx.atomic_op(&key, &RESERVED_VALUES.to_le_bytes(), MutationType::Add);
+          let last = match trx.get(&key, false).await? {
+            Some(last) => <[u8; 8]>::try_from(last.as_ref())
+              .map(i64::from_le_bytes)
+              .map_err(|_| {
+                custom(Error::Io("Counter isn't a 64-bit integer.".to_string()))
+              })?,
+            None => RESERVED_VALUES,
+          };
+          reserved.push(last);
+        }
+        Ok(reserved)
+      })
+      .await?;
+    let mut counters = self.counters.lock().unwrap();
+    for (counter, last) in exhausted.iter().zip(reserved) {
+      counters.reserve(counter, last - RESERVED_VALUES + 1, last);
+    }
+    Ok(())
+  }
+
+  /// Stores the changes made to the tables.
+  /// Note: Stale entries are cleared first, so that a unique value moved
+  /// between the objects isn't lost.
+  fn save(
+    &self,
+    trx: &Transaction,
+    before: &Tables,
+    after: &Tables,
+  ) -> Result<(), Error> {
+    let mut entries = Vec::new();
+    for (model_name, table) in after {
+      let model = &self.models[model_name];
+      let previous = &before[model_name];
+      for (id, fields) in previous.iter() {
+        match table.get(id) {
+          None => {
+            trx.clear(&self.keys.object(model_name, id));
+          }
+          Some(current) if current == fields => continue,
+          Some(_) => {}
+        }
+        for (key, _) in self.keys.index_entries(model, id, fields)? {
+          trx.clear(&key);
+        }
+      }
+      for (id, fields) in table.iter() {
+        if previous.get(id) == Some(fields) {
+          continue;
+        }
+        entries.push(self.keys.object_entry(model, id, fields)?);
+        entries.extend(self.keys.index_entries(model, id, fields)?);
+      }
+    }
+    for (key, value) in entries {
+      trx.set(&key, &value);
+    }
+    Ok(())
+  }
+}
+
+/// Lookups recorded as missing by the partially loaded tables, by the model name.
+fn missing(tables: &Tables) -> Vec<(String, Lookup)> {
+  tables
+    .iter()
+    .flat_map(|(model_name, table)| {
+      table
+        .take_missing()
+        .into_iter()
+        .map(|lookup| (model_name.clone(), lookup))
+    })
+    .collect()
+}
+
+/// Fields of the object stored as JSON.
+fn decode(object: &[u8]) -> Result<Fields, FdbBindingError> {
+  serde_json::from_slice(object).map_err(|error| custom(Error::Io(error.to_string())))
+}
+
+/// Passes the data store error through the transaction, without retrying it.
+fn custom(error: Error) -> FdbBindingError {
+  FdbBindingError::CustomError(Box::new(error))
+}
+
+/// Note: The tests need a FoundationDB server, reached through the default cluster
+/// file. Each test runs under a root subspace of its own, cleared before it runs.
+#[cfg(test)]
+mod tests {
+  use std::sync::Once;
+
+  use graphql_value::{value, ConstValue as Value, Name};
+
+  use super::*;
+  use crate::db_crud::{ObjectConnection, ObjectCreateInput, ObjectWhereUniqueInput};
+
+  fn data_model() -> DataModel {
+    let sdml = include_str!("../test_data/test_crud_api_def.sdml");
+    sdml_parser::parse(sdml).expect("Test SDML should be valid.")
+  }
+
+  fn database() -> Database {
+    static BOOT: Once = Once::new();
+    // Safety: Network is booted once, and stopped when the test process exits.
+    BOOT.call_once(|| std::mem::forget(unsafe { foundationdb::boot() }));
+    Database::default().expect("FoundationDB should be reachable.")
+  }
+
+  /// Clears the data under the root of the test.
+  async fn clear(db: &Database, root: &Subspace) {
+    db.run(|trx, _maybe_committed| async move {
+      let (begin, end) = root.range();
+      trx.clear_range(&begin, &end);
+      Ok(())
+    })
+    .await
+    .unwrap();
+  }
+
+  async fn store(test_name: &str) -> FdbStore {
+    let db = database();
+    let root = Subspace::from(("db_engine_test", test_name));
+    clear(&db, &root).await;
+    FdbStore::open(db, root, &data_model()).await.unwrap()
+  }
+
+  fn where_unique(model: &str, value: Value) -> ObjectWhereUniqueInput {
+    ObjectWhereUniqueInput::from_value(&data_model(), model, value).unwrap()
+  }
+
+  async fn create(store: &FdbStore, model: &str, data: Value) -> Result<ID, Error> {
+    let object = store
+      .mutation(model)
+      .unwrap()
+      .create_object(ObjectCreateInput::from_value(&data_model(), model, data)?)
+      .await?;
+    Ok(object.id().await)
+  }
+
+  async fn field_values(connection: &ObjectConnection, name: &str) -> Vec<Value> {
+    let mut values = Vec::new();
+    for edge in &connection.edges {
+      values.push(edge.node.field(Name::new(name)).await);
+    }
+    values
+  }
+
+  async fn find_many(
+    store: &FdbStore,
+    model: &str,
+    r#where: Value,
+    order_by: Value,
+  ) -> ObjectConnection {
+    store
+      .query(model)
+      .unwrap()
+      .get_objects(
+        ObjectWhereInput::from_value(&data_model(), model, r#where).unwrap(),
+        ObjectOrderByInput::from_value(&data_model(), model, order_by).unwrap(),
+        Pagination::default(),
+      )
+      .await
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_open_other_layout_version() {
+    let db = database();
+    let root = Subspace::from(("db_engine_test", "test_open_other_layout_version"));
+    clear(&db, &root).await;
+    let key = Keys::new(root.clone()).layout_version();
+    let key = &key;
+    db.run(|trx, _maybe_committed| async move {
+      trx.set(key, &pack(&(LAYOUT_VERSION + 1)));
+      Ok(())
+    })
+    .await
+    .unwrap();
+    assert!(matches!(
+      FdbStore::open(db, root, &data_model()).await,
+      Err(Error::Io(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_create_and_get_object() {
+    let store = store("test_create_and_get_object").await;
+    let id = create(
+      &store,
+      "User",
+      value!({"email": "alice@x.com", "nickNames": ["al"]}),
+    )
+    .await
+    .unwrap();
+
+    let query = store.query("User").unwrap();
+    let user = query
+      .get_object(where_unique("User", value!({"email": "alice@x.com"})))
+      .await
+      .unwrap()
+      .expect("User should be found by the unique field.");
+    assert_eq!(user.id().await, id);
+    assert_eq!(
+      user.field(Name::new("role")).await,
+      Value::Enum(Name::new("USER"))
+    );
+    assert!(query
+      .get_object(where_unique("User", value!({"id": id.as_str()})))
+      .await
+      .unwrap()
+      .is_some());
+    assert_eq!(
+      field_values(
+        &find_many(
+          &store,
+          "User",
+          value!({"nickNames_contains": "al"}),
+          Value::Null
+        )
+        .await,
+        "email"
+      )
+      .await,
+      vec![value!("alice@x.com")]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_unique_violation() {
+    let store = store("test_unique_violation").await;
+    create(
+      &store,
+      "User",
+      value!({"email": "alice@x.com", "nickNames": []}),
+    )
+    .await
+    .unwrap();
+    let result = create(
+      &store,
+      "User",
+      value!({"email": "alice@x.com", "nickNames": []}),
+    )
+    .await;
+    assert!(matches!(
+      result,
+      Err(Error::UniqueViolation { model, field, .. }) if model == "User" && field == "email"
+    ));
+    assert_eq!(
+      find_many(&store, "User", Value::Null, Value::Null)
+        .await
+        .edges
+        .len(),
+      1
+    );
+  }
+
+  #[tokio::test]
+  async fn test_string_sequence_order() {
+    let store = store("test_string_sequence_order").await;
+    for _ in 0..11 {
+      create(&store, "Session", value!({})).await.unwrap();
+    }
+    let connection =
+      find_many(&store, "Session", Value::Null, value!("number_ASC")).await;
+    let serials = field_values(&connection, "serial").await;
+    assert_eq!(serials.len(), 11);
+    assert!(
+      serials.windows(2).all(|pair| match (&pair[0], &pair[1]) {
+        (Value::Number(first), Value::Number(second)) => first.as_i64() < second.as_i64(),
+        _ => false,
+      }),
+      "Sequence strings should sort in the sequence order."
+    );
+  }
+
+  #[tokio::test]
+  async fn test_find_many_over_pages() {
+    let store = store("test_find_many_over_pages").await;
+    for index in 0..=PAGE_SIZE {
+      create(
+        &store,
+        "User",
+        value!({
+          "email": format!("user{index:04}@x.com"),
+          "name": if index % 2 == 0 { "even" } else { "odd" },
+          "nickNames": [],
+        }),
+      )
+      .await
+      .unwrap();
+    }
+
+    // Note: `name` isn't indexed, so the users are read a page at a time.
+    let connection = find_many(
+      &store,
+      "User",
+      value!({"name": "even"}),
+      value!("email_DSC"),
+    )
+    .await;
+    let emails = field_values(&connection, "email").await;
+    assert_eq!(emails.len(), PAGE_SIZE / 2 + 1);
+    assert_eq!(emails[0], value!(format!("user{PAGE_SIZE:04}@x.com")));
+    assert_eq!(emails[emails.len() - 1], value!("user0000@x.com"));
+  }
+}
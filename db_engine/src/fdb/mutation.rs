@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::{object, Store};
+use crate::{
+  db_crud::{
+    DBMutation, DBObject, ObjectConnection, ObjectCreateInput, ObjectOrderByInput,
+    ObjectUpdateInput, ObjectUpsertInput, ObjectWhereInput, ObjectWhereUniqueInput,
+    Pagination,
+  },
+  model::Model,
+  tables::Fields,
+  Error,
+};
+
+pub(super) struct FdbMutation {
+  store: Arc<Store>,
+  model: Arc<Model>,
+}
+
+impl FdbMutation {
+  pub fn new(store: Arc<Store>, model: Arc<Model>) -> Self {
+    FdbMutation { store, model }
+  }
+}
+
+#[async_trait]
+impl DBMutation for FdbMutation {
+  async fn create_object(
+    &mut self,
+    data: ObjectCreateInput,
+  ) -> Result<Box<dyn DBObject>, Error> {
+    let (fields, id) = self
+      .store
+      .write(|txn| {
+        let id = txn.create(&self.model, &data, Fields::new())?;
+        Ok((txn.view().fields(&self.model, &id)?.clone(), id))
+      })
+      .await?;
+    Ok(object::boxed(&self.store, &self.model, id, fields))
+  }
+
+  async fn update_object(
+    &mut self,
+    r#where: ObjectWhereUniqueInput,
+    data: ObjectUpdateInput,
+  ) -> Result<Box<dyn DBObject>, Error> {
+    let (fields, id) = self
+      .store
+      .write(|txn| {
+        let id = txn.find(&self.model, &r#where)?;
+        txn.update(&self.model, &id, &data)?;
+        Ok((txn.view().fields(&self.model, &id)?.clone(), id))
+      })
+      .await?;
+    Ok(object::boxed(&self.store, &self.model, id, fields))
+  }
+
+  async fn delete_object(
+    &mut self,
+    r#where: ObjectWhereUniqueInput,
+  ) -> Result<Box<dyn DBObject>, Error> {
+    let (fields, id) = self
+      .store
+      .write(|txn| {
+        let id = txn.find(&self.model, &r#where)?;
+        Ok((txn.delete(&self.model, &id)?, id))
+      })
+      .await?;
+    Ok(object::boxed(&self.store, &self.model, id, fields))
+  }
+
+  async fn upsert_object(
+    &mut self,
+    r#where: ObjectWhereUniqueInput,
+    data: ObjectUpsertInput,
+  ) -> Result<Box<dyn DBObject>, Error> {
+    let (fields, id) = self
+      .store
+      .write(|txn| {
+        let id = txn.upsert(&self.model, &r#where, &data)?;
+        Ok((txn.view().fields(&self.model, &id)?.clone(), id))
+      })
+      .await?;
+    Ok(object::boxed(&self.store, &self.model, id, fields))
+  }
+
+  async fn delete_many_objects(
+    &mut self,
+    r#where: ObjectWhereInput,
+    pagination: Pagination,
+  ) -> Result<ObjectConnection, Error> {
+    let objects = self
+      .store
+      .write(|txn| {
+        let page = txn.view().find_many(
+          &self.model,
+          None,
+          &r#where,
+          &ObjectOrderByInput::default(),
+          &pagination,
+        )?;
+        let objects = txn.delete_many(&self.model, page.ids)?;
+        Ok(object::Objects {
+          objects,
+          page_info: page.page_info,
+          count: page.count,
+        })
+      })
+      .await?;
+    Ok(object::connection(&self.store, &self.model, objects))
+  }
+
+  async fn update_many_objects(
+    &mut self,
+    r#where: ObjectWhereInput,
+    data: ObjectUpdateInput,
+    pagination: Pagination,
+  ) -> Result<ObjectConnection, Error> {
+    let objects = self
+      .store
+      .write(|txn| {
+        let page = txn.view().find_many(
+          &self.model,
+          None,
+          &r#where,
+          &ObjectOrderByInput::default(),
+          &pagination,
+        )?;
+        for id in &page.ids {
+          txn.update(&self.model, id, &data)?;
+        }
+        object::page_objects(&self.model, &txn.view(), page)
+      })
+      .await?;
+    Ok(object::connection(&self.store, &self.model, objects))
+  }
+}
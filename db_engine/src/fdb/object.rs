@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use graphql_value::{ConstValue as Value, Name};
+
+use super::Store;
+use crate::{
+  db_crud::{
+    DBObject, ObjectConnection, ObjectEdge, ObjectOrderByInput, ObjectWhereInput,
+    PageInfo, Pagination, ID,
+  },
+  model::{Model, Relation, FIELD_ID},
+  tables::{Fields, Page, View},
+  Error,
+};
+
+/// Objects of a page, with their fields.
+pub(super) struct Objects {
+  pub objects: Vec<(Fields, ID)>,
+  pub page_info: PageInfo,
+  pub count: usize,
+}
+
+/// Object read from the store, holds a snapshot of its fields.
+/// Relations are read from the store, when asked for.
+struct FdbObject {
+  store: Arc<Store>,
+  model: Arc<Model>,
+  id: ID,
+  fields: Fields,
+}
+
+impl FdbObject {
+  fn relation(&self, name: &str) -> Result<&Relation, Error> {
+    self.model.relations.get(name).ok_or_else(|| {
+      Error::InvalidInput(format!(
+        r#"Unknown relation "{name}" on {}."#,
+        self.model.name
+      ))
+    })
+  }
+}
+
+#[async_trait]
+impl DBObject for FdbObject {
+  async fn id(&self) -> ID {
+    self.id.clone()
+  }
+
+  async fn field(&self, name: Name) -> Value {
+    // Note: Model with compound ID has no ID field, its ID is the object's ID.
+    if name == FIELD_ID && self.model.has_compound_id() {
+      return Value::String(self.id.to_string());
+    }
+    self
+      .fields
+      .get(name.as_str())
+      .cloned()
+      .unwrap_or(Value::Null)
+  }
+
+  async fn relation(&self, name: Name) -> Result<Option<Box<dyn DBObject>>, Error> {
+    let relation = self.relation(&name)?;
+    let target = self.store.models[&relation.model].clone();
+    let object = self
+      .store
+      .read(|view| {
+        let Some(id) = view
+          .related(&self.model, relation, &self.fields)
+          .into_iter()
+          .next()
+        else {
+          return Ok(None);
+        };
+        Ok(Some((view.fields(&target, &id)?.clone(), id)))
+      })
+      .await?;
+    Ok(object.map(|(fields, id)| boxed(&self.store, &target, id, fields)))
+  }
+
+  async fn relations(
+    &self,
+    name: Name,
+    r#where: ObjectWhereInput,
+    order_by: ObjectOrderByInput,
+    pagination: Pagination,
+  ) -> Result<ObjectConnection, Error> {
+    let relation = self.relation(&name)?;
+    let target = self.store.models[&relation.model].clone();
+    let objects = self
+      .store
+      .read(|view| {
+        let related = view.related(&self.model, relation, &self.fields);
+        let page =
+          view.find_many(&target, Some(related), &r#where, &order_by, &pagination)?;
+        page_objects(&target, view, page)
+      })
+      .await?;
+    Ok(connection(&self.store, &target, objects))
+  }
+}
+
+pub(super) fn boxed(
+  store: &Arc<Store>,
+  model: &Arc<Model>,
+  id: ID,
+  fields: Fields,
+) -> Box<dyn DBObject> {
+  Box::new(FdbObject {
+    store: store.clone(),
+    model: model.clone(),
+    id,
+    fields,
+  })
+}
+
+/// Objects in the page, with their fields read from the view.
+pub(super) fn page_objects(
+  model: &Model,
+  view: &View<'_>,
+  page: Page,
+) -> Result<Objects, Error> {
+  let objects = page
+    .ids
+    .into_iter()
+    .map(|id| Ok((view.fields(model, &id)?.clone(), id)))
+    .collect::<Result<Vec<_>, Error>>()?;
+  Ok(Objects {
+    objects,
+    page_info: page.page_info,
+    count: page.count,
+  })
+}
+
+/// Connection of the given objects.
+pub(super) fn connection(
+  store: &Arc<Store>,
+  model: &Arc<Model>,
+  objects: Objects,
+) -> ObjectConnection {
+  ObjectConnection {
+    page_info: objects.page_info,
+    edges: objects
+      .objects
+      .into_iter()
+      .map(|(fields, id)| ObjectEdge {
+        node: boxed(store, model, id.clone(), fields),
+        cursor: id,
+      })
+      .collect(),
+    count: objects.count,
+  }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::{object, Store};
+use crate::{
+  db_crud::{
+    DBObject, DBQuery, ObjectConnection, ObjectOrderByInput, ObjectWhereInput,
+    ObjectWhereUniqueInput, Pagination,
+  },
+  model::Model,
+  Error,
+};
+
+pub(super) struct FdbQuery {
+  store: Arc<Store>,
+  model: Arc<Model>,
+}
+
+impl FdbQuery {
+  pub fn new(store: Arc<Store>, model: Arc<Model>) -> Self {
+    FdbQuery { store, model }
+  }
+}
+
+#[async_trait]
+impl DBQuery for FdbQuery {
+  async fn get_object(
+    &self,
+    r#where: ObjectWhereUniqueInput,
+  ) -> Result<Option<Box<dyn DBObject>>, Error> {
+    let object = self
+      .store
+      .read(|view| {
+        let Some(id) = view.find_unique(&self.model, &r#where)? else {
+          return Ok(None);
+        };
+        Ok(Some((view.fields(&self.model, &id)?.clone(), id)))
+      })
+      .await?;
+    Ok(object.map(|(fields, id)| object::boxed(&self.store, &self.model, id, fields)))
+  }
+
+  async fn get_objects(
+    &self,
+    r#where: ObjectWhereInput,
+    order_by: ObjectOrderByInput,
+    pagination: Pagination,
+  ) -> Result<ObjectConnection, Error> {
+    let objects = self
+      .store
+      .find_many(&self.model, &r#where, &order_by, &pagination)
+      .await?;
+    Ok(object::connection(&self.store, &self.model, objects))
+  }
+}
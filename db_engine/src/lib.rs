@@ -1,2 +1,9 @@
-mod db_crud;
+pub mod db_crud;
 mod errors;
+#[cfg(feature = "fdb")]
+pub mod fdb;
+pub mod memory;
+mod model;
+mod tables;
+
+pub use errors::Error;
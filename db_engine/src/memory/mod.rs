@@ -0,0 +1,1368 @@
+//! In-memory data store, holding the objects of the data model in memory.
+//!
+//! Meant for the tests & development, nothing is persisted. Each mutation is
+//! atomic, changes are rolled back if any part of it fails.
+mod mutation;
+mod object;
+mod query;
+
+use std::sync::{Arc, Mutex, RwLock};
+
+use sdml_parser::types::DataModel;
+
+use crate::{
+  db_crud::{DBMutation, DBQuery, DBStore},
+  model::{self, Models},
+  tables::{Counters, Tables, Txn, View},
+  Error,
+};
+use mutation::MemoryMutation;
+use query::MemoryQuery;
+
+/// In-memory data store of a data model.
+#[derive(Clone)]
+pub struct MemoryStore(Arc<Store>);
+
+struct Store {
+  models: Models,
+  tables: RwLock<Tables>,
+  /// Note: Counters aren't rolled back, so the generated values are never reused.
+  counters: Mutex<Counters>,
+}
+
+impl MemoryStore {
+  /// Creates an empty store for the data model.
+  pub fn new(data_model: &DataModel) -> Self {
+    let models = model::models(data_model);
+    let tables = models
+      .keys()
+      .map(|model_name| (model_name.clone(), Default::default()))
+      .collect();
+    MemoryStore(Arc::new(Store {
+      models,
+      tables: RwLock::new(tables),
+      counters: Mutex::new(Counters::default()),
+    }))
+  }
+}
+
+impl DBStore for MemoryStore {
+  fn query(&self, model_name: &str) -> Option<Box<dyn DBQuery>> {
+    let model = self.0.models.get(model_name)?.clone();
+    Some(Box::new(MemoryQuery::new(self.0.clone(), model)))
+  }
+
+  fn mutation(&self, model_name: &str) -> Option<Box<dyn DBMutation>> {
+    let model = self.0.models.get(model_name)?.clone();
+    Some(Box::new(MemoryMutation::new(self.0.clone(), model)))
+  }
+}
+
+impl Store {
+  fn read<T>(
+    &self,
+    read: impl FnOnce(&View<'_>) -> Result<T, Error>,
+  ) -> Result<T, Error> {
+    let tables = self.tables.read().unwrap();
+    read(&View {
+      models: &self.models,
+      tables: &tables,
+    })
+  }
+
+  /// Writes within a transaction, rolls back the changes on error.
+  /// Note: Simply restores a copy of the tables taken before the writes.
+  fn write<T>(
+    &self,
+    write: impl FnOnce(&mut Txn<'_>) -> Result<T, Error>,
+  ) -> Result<T, Error> {
+    let mut tables = self.tables.write().unwrap();
+    let mut counters = self.counters.lock().unwrap();
+    let backup = tables.clone();
+    let mut txn = Txn::new(&self.models, &mut tables, &mut counters);
+    let result = write(&mut txn).and_then(|result| txn.commit().map(|_| result));
+    if result.is_err() {
+      *tables = backup;
+    }
+    result
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use graphql_value::{value, ConstValue as Value, Name};
+
+  use super::*;
+  use crate::db_crud::{
+    ObjectConnection, ObjectCreateInput, ObjectOrderByInput, ObjectUpdateInput,
+    ObjectWhereInput, ObjectWhereUniqueInput, Pagination, ID,
+  };
+
+  fn data_model() -> DataModel {
+    let sdml = include_str!("../test_data/test_crud_api_def.sdml");
+    sdml_parser::parse(sdml).expect("Test SDML should be valid.")
+  }
+
+  fn store() -> MemoryStore {
+    MemoryStore::new(&data_model())
+  }
+
+  fn where_unique(model: &str, value: Value) -> ObjectWhereUniqueInput {
+    ObjectWhereUniqueInput::from_value(&data_model(), model, value).unwrap()
+  }
+
+  fn r#where(model: &str, value: Value) -> ObjectWhereInput {
+    ObjectWhereInput::from_value(&data_model(), model, value).unwrap()
+  }
+
+  fn update_input(model: &str, value: Value) -> ObjectUpdateInput {
+    ObjectUpdateInput::from_value(&data_model(), model, value).unwrap()
+  }
+
+  async fn create(store: &MemoryStore, model: &str, data: Value) -> Result<ID, Error> {
+    let object = store
+      .mutation(model)
+      .unwrap()
+      .create_object(ObjectCreateInput::from_value(&data_model(), model, data)?)
+      .await?;
+    Ok(object.id().await)
+  }
+
+  async fn field_values(connection: &ObjectConnection, name: &str) -> Vec<Value> {
+    let mut values = Vec::new();
+    for edge in &connection.edges {
+      values.push(edge.node.field(Name::new(name)).await);
+    }
+    values
+  }
+
+  async fn find_users(
+    store: &MemoryStore,
+    r#where: Value,
+    order_by: Value,
+  ) -> Vec<Value> {
+    let connection = store
+      .query("User")
+      .unwrap()
+      .get_objects(
+        self::r#where("User", r#where),
+        ObjectOrderByInput::from_value(&data_model(), "User", order_by).unwrap(),
+        Pagination::default(),
+      )
+      .await
+      .unwrap();
+    field_values(&connection, "email").await
+  }
+
+  #[tokio::test]
+  async fn test_create_and_get_object() {
+    let store = store();
+    let id = create(
+      &store,
+      "User",
+      value!({"email": "alice@x.com", "nickNames": ["al"]}),
+    )
+    .await
+    .unwrap();
+
+    let query = store.query("User").unwrap();
+    let user = query
+      .get_object(where_unique("User", value!({"email": "alice@x.com"})))
+      .await
+      .unwrap()
+      .expect("User should be found by the unique field.");
+    assert_eq!(user.id().await, id);
+    assert_eq!(
+      user.field(Name::new("userId")).await,
+      Value::from(id.clone())
+    );
+    assert_eq!(
+      user.field(Name::new("role")).await,
+      Value::Enum(Name::new("USER"))
+    );
+    assert_eq!(user.field(Name::new("name")).await, Value::Null);
+    assert!(query
+      .get_object(where_unique("User", value!({"id": id.as_str()})))
+      .await
+      .unwrap()
+      .is_some());
+    assert!(query
+      .get_object(where_unique("User", value!({"email": "bob@x.com"})))
+      .await
+      .unwrap()
+      .is_none());
+  }
+
+  #[tokio::test]
+  async fn test_create_with_literal_defaults() {
+    let store = store();
+    create(
+      &store,
+      "Membership",
+      value!({"tenantId": "t1", "groupName": "admins", "email": "alice@x.com", "locale": "fr"}),
+    )
+    .await
+    .unwrap();
+
+    let membership = store
+      .query("Membership")
+      .unwrap()
+      .get_object(where_unique(
+        "Membership",
+        value!({"tenantId_groupName": {"tenantId": "t1", "groupName": "admins"}}),
+      ))
+      .await
+      .unwrap()
+      .expect("Membership should be found by the compound ID.");
+    assert_eq!(membership.field(Name::new("priority")).await, value!(-1));
+    assert_eq!(membership.field(Name::new("quota")).await, value!(10.0));
+    assert_eq!(membership.field(Name::new("locale")).await, value!("fr"));
+  }
+
+  #[tokio::test]
+  async fn test_create_with_generated_ids() {
+    let store = store();
+    for _ in 0..3 {
+      create(&store, "Session", value!({})).await.unwrap();
+    }
+    create(&store, "Session", value!({"serial": 10}))
+      .await
+      .unwrap();
+    create(&store, "Session", value!({})).await.unwrap();
+
+    let connection = store
+      .query("Session")
+      .unwrap()
+      .get_objects(
+        ObjectWhereInput::default(),
+        ObjectOrderByInput::default(),
+        Pagination::default(),
+      )
+      .await
+      .unwrap();
+    assert_eq!(
+      field_values(&connection, "serial").await,
+      vec![value!(1), value!(2), value!(3), value!(10), value!(4)]
+    );
+    assert_eq!(
+      field_values(&connection, "number").await,
+      vec![
+        value!("00000000000000000001"),
+        value!("00000000000000000002"),
+        value!("00000000000000000003"),
+        value!("00000000000000000004"),
+        value!("00000000000000000005")
+      ]
+    );
+    let lengths = |values: Vec<Value>| {
+      values
+        .into_iter()
+        .map(|value| match value {
+          Value::String(str) => str.len(),
+          value => panic!("ID should be a string, not {value}."),
+        })
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(
+      lengths(field_values(&connection, "token").await),
+      vec![36; 5]
+    );
+    assert_eq!(
+      lengths(field_values(&connection, "cuid").await),
+      vec![25; 5]
+    );
+    assert_eq!(
+      lengths(field_values(&connection, "ulid").await),
+      vec![26; 5]
+    );
+    assert_eq!(lengths(field_values(&connection, "code").await), vec![8; 5]);
+    for name in ["sessionId", "cuid", "ulid"] {
+      let ids = field_values(&connection, name).await;
+      assert!(
+        ids
+          .windows(2)
+          .all(|pair| pair[0].to_string() < pair[1].to_string()),
+        "{name} should follow the creation order."
+      );
+    }
+  }
+
+  #[tokio::test]
+  async fn test_string_sequence_order() {
+    let store = store();
+    for _ in 0..11 {
+      create(&store, "Session", value!({})).await.unwrap();
+    }
+
+    let connection = store
+      .query("Session")
+      .unwrap()
+      .get_objects(
+        ObjectWhereInput::default(),
+        ObjectOrderByInput::from_value(&data_model(), "Session", value!("number_ASC"))
+          .unwrap(),
+        Pagination::default(),
+      )
+      .await
+      .unwrap();
+    assert_eq!(
+      field_values(&connection, "serial").await,
+      (1..=11).map(Value::from).collect::<Vec<_>>()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_updated_at() {
+    let store = store();
+    let user_id = create(
+      &store,
+      "User",
+      value!({
+        "email": "alice@x.com",
+        "nickNames": [],
+        "posts": {"create": [{"title": "First"}, {"title": "Second"}]},
+      }),
+    )
+    .await
+    .unwrap();
+    let post_ids = relations(&store, "User", &user_id, "posts").await;
+    let updated_at = |post_id: ID| {
+      let store = &store;
+      async move {
+        let post = store
+          .query("Post")
+          .unwrap()
+          .get_object(where_unique("Post", value!({"id": post_id.as_str()})))
+          .await
+          .unwrap()
+          .unwrap();
+        match post.field(Name::new("updatedAt")).await {
+          Value::String(str) => chrono::DateTime::parse_from_rfc3339(&str).unwrap(),
+          value => panic!("updatedAt should be a date-time, not {value}."),
+        }
+      }
+    };
+    let created = (
+      updated_at(post_ids[0].clone()).await,
+      updated_at(post_ids[1].clone()).await,
+    );
+
+    let mut mutation = store.mutation("Post").unwrap();
+    mutation
+      .update_many_objects(
+        r#where("Post", value!({"title": "First"})),
+        update_input("Post", value!({"published": true})),
+        Pagination::default(),
+      )
+      .await
+      .unwrap();
+    let first_updated = updated_at(post_ids[0].clone()).await;
+    assert!(first_updated > created.0);
+    assert_eq!(updated_at(post_ids[1].clone()).await, created.1);
+
+    // Nested update through the author.
+    store
+      .mutation("User")
+      .unwrap()
+      .update_object(
+        where_unique("User", value!({"id": user_id.as_str()})),
+        update_input(
+          "User",
+          value!({"posts": {"update": [{
+            "where": {"id": post_ids[1].as_str()},
+            "data": {"title": "Second!"},
+          }]}}),
+        ),
+      )
+      .await
+      .unwrap();
+    assert_eq!(updated_at(post_ids[0].clone()).await, first_updated);
+    assert!(updated_at(post_ids[1].clone()).await > created.1);
+  }
+
+  #[tokio::test]
+  async fn test_unique_violation() {
+    let store = store();
+    create(
+      &store,
+      "User",
+      value!({"email": "alice@x.com", "nickNames": []}),
+    )
+    .await
+    .unwrap();
+    let result = create(
+      &store,
+      "User",
+      value!({"email": "alice@x.com", "nickNames": []}),
+    )
+    .await;
+    assert!(matches!(
+      result,
+      Err(Error::UniqueViolation { model, field, .. }) if model == "User" && field == "email"
+    ));
+    assert_eq!(find_users(&store, Value::Null, Value::Null).await.len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_compound_keys() {
+    let store = store();
+    let id = create(
+      &store,
+      "Membership",
+      value!({"tenantId": "t1", "groupName": "admins", "email": "alice@x.com"}),
+    )
+    .await
+    .unwrap();
+    create(
+      &store,
+      "Membership",
+      value!({"tenantId": "t2", "groupName": "admins", "email": "alice@x.com"}),
+    )
+    .await
+    .unwrap();
+
+    let result = create(
+      &store,
+      "Membership",
+      value!({"tenantId": "t1", "groupName": "admins", "email": "bob@x.com"}),
+    )
+    .await;
+    assert!(matches!(
+      result,
+      Err(Error::UniqueViolation { field, .. }) if field == "tenantId_groupName"
+    ));
+    let result = create(
+      &store,
+      "Membership",
+      value!({"tenantId": "t1", "groupName": "users", "email": "alice@x.com"}),
+    )
+    .await;
+    assert!(matches!(
+      result,
+      Err(Error::UniqueViolation { field, .. }) if field == "tenantId_email"
+    ));
+
+    let query = store.query("Membership").unwrap();
+    let membership = query
+      .get_object(where_unique(
+        "Membership",
+        value!({"tenantId_email": {"tenantId": "t1", "email": "alice@x.com"}}),
+      ))
+      .await
+      .unwrap()
+      .expect("Membership should be found by the compound unique key.");
+    assert_eq!(membership.id().await, id);
+    assert_eq!(
+      membership.field(Name::new("id")).await,
+      Value::from(id.clone())
+    );
+    for r#where in [
+      value!({"tenantId_groupName": {"tenantId": "t1", "groupName": "admins"}}),
+      value!({"id": id.as_str()}),
+    ] {
+      let membership = query
+        .get_object(where_unique("Membership", r#where))
+        .await
+        .unwrap()
+        .expect("Membership should be found by the compound ID.");
+      assert_eq!(membership.id().await, id);
+    }
+    assert!(query
+      .get_object(where_unique(
+        "Membership",
+        value!({"tenantId_email": {"tenantId": "t2", "email": "bob@x.com"}}),
+      ))
+      .await
+      .unwrap()
+      .is_none());
+    assert!(ObjectWhereUniqueInput::from_value(
+      &data_model(),
+      "Membership",
+      value!({"tenantId_email": {"tenantId": "t1"}}),
+    )
+    .is_err());
+
+    // Compound unique value moves along with the update.
+    store
+      .mutation("Membership")
+      .unwrap()
+      .update_object(
+        where_unique("Membership", value!({"id": id.as_str()})),
+        update_input("Membership", value!({"email": "carol@x.com"})),
+      )
+      .await
+      .unwrap();
+    assert!(query
+      .get_object(where_unique(
+        "Membership",
+        value!({"tenantId_email": {"tenantId": "t1", "email": "carol@x.com"}}),
+      ))
+      .await
+      .unwrap()
+      .is_some());
+    create(
+      &store,
+      "Membership",
+      value!({"tenantId": "t1", "groupName": "users", "email": "alice@x.com"}),
+    )
+    .await
+    .unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_get_objects() {
+    let store = store();
+    for (email, name, nick_names) in [
+      ("carol@x.com", "Carol", vec!["cc"]),
+      ("alice@x.com", "Alice", vec!["al", "ali"]),
+      ("bob@x.com", "Bob", vec![]),
+    ] {
+      create(
+        &store,
+        "User",
+        value!({"email": email, "name": name, "nickNames": nick_names}),
+      )
+      .await
+      .unwrap();
+    }
+
+    assert_eq!(
+      find_users(&store, Value::Null, Value::Null).await,
+      vec![
+        value!("carol@x.com"),
+        value!("alice@x.com"),
+        value!("bob@x.com")
+      ]
+    );
+    assert_eq!(
+      find_users(&store, Value::Null, value!("email_ASC")).await,
+      vec![
+        value!("alice@x.com"),
+        value!("bob@x.com"),
+        value!("carol@x.com")
+      ]
+    );
+    assert_eq!(
+      find_users(&store, Value::Null, value!("name_DSC")).await,
+      vec![
+        value!("carol@x.com"),
+        value!("bob@x.com"),
+        value!("alice@x.com")
+      ]
+    );
+    assert_eq!(
+      find_users(&store, value!({"nickNames": "ali"}), Value::Null).await,
+      vec![value!("alice@x.com")]
+    );
+    assert_eq!(
+      find_users(
+        &store,
+        value!({"OR": [{"name_starts_with": "B"}, {"email_contains": "carol"}]}),
+        Value::Null
+      )
+      .await,
+      vec![value!("carol@x.com"), value!("bob@x.com")]
+    );
+    assert_eq!(
+      find_users(
+        &store,
+        value!({"name_in": ["Alice", "Bob"], "NOT": {"email_ends_with": "bob@x.com"}}),
+        Value::Null
+      )
+      .await,
+      vec![value!("alice@x.com")]
+    );
+    assert!(matches!(
+      ObjectWhereInput::from_value(&data_model(), "User", value!({"unknown_lt": 1})),
+      Err(Error::InvalidInput(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_case_insensitive_and_null_filters() {
+    let store = store();
+    for user in [
+      value!({"email": "Carol@X.com", "name": "Carol", "nickNames": []}),
+      value!({"email": "alice@x.com", "nickNames": []}),
+      value!({"email": "bob@x.com", "name": "Bob", "nickNames": []}),
+    ] {
+      create(&store, "User", user).await.unwrap();
+    }
+
+    for (r#where, emails) in [
+      // Note: `email` is a unique field, found using its lowercase index.
+      (
+        value!({"email_equals_i": "carol@x.COM"}),
+        vec![value!("Carol@X.com")],
+      ),
+      (value!({"email_equals_i": "carol"}), vec![]),
+      (
+        value!({"email_starts_with_i": "CAROL@"}),
+        vec![value!("Carol@X.com")],
+      ),
+      (
+        value!({"email_starts_with_i": "b", "email_ends_with_i": "X.COM"}),
+        vec![value!("bob@x.com")],
+      ),
+      (
+        value!({"name_contains_i": "AR"}),
+        vec![value!("Carol@X.com")],
+      ),
+      (
+        value!({"name_starts_with_i": "b"}),
+        vec![value!("bob@x.com")],
+      ),
+      (
+        value!({"email_ends_with_i": "X.COM"}),
+        vec![
+          value!("Carol@X.com"),
+          value!("alice@x.com"),
+          value!("bob@x.com"),
+        ],
+      ),
+      (value!({"name_is_null": true}), vec![value!("alice@x.com")]),
+      (
+        value!({"name_is_null": false}),
+        vec![value!("Carol@X.com"), value!("bob@x.com")],
+      ),
+    ] {
+      assert_eq!(find_users(&store, r#where, Value::Null).await, emails);
+    }
+    assert!(matches!(
+      ObjectWhereInput::from_value(
+        &data_model(),
+        "User",
+        value!({"email_is_null": true})
+      ),
+      Err(Error::InvalidInput(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_decimal_values() {
+    let store = store();
+    for (email, credits) in [
+      ("alice@x.com", value!("12345678901234567.89")),
+      ("bob@x.com", value!(9.5)),
+      ("carol@x.com", value!("12345678901234567.8")),
+    ] {
+      create(
+        &store,
+        "User",
+        value!({
+          "email": email,
+          "nickNames": [],
+          "profile": {"create": {"credits": credits}},
+        }),
+      )
+      .await
+      .unwrap();
+    }
+
+    for (r#where, order_by, credits) in [
+      // Note: Decimals beyond the precision of 64-bit floats are kept exactly.
+      (
+        value!({"credits_gt": "12345678901234567.8"}),
+        Value::Null,
+        vec![value!("12345678901234567.89")],
+      ),
+      (
+        value!({"credits": "12345678901234567.890"}),
+        Value::Null,
+        vec![value!("12345678901234567.89")],
+      ),
+      // Note: Decimals are ordered numerically, not as the strings.
+      (
+        value!({"credits_lt": "100"}),
+        value!("credits_ASC"),
+        vec![value!("9.5")],
+      ),
+      (
+        Value::Null,
+        value!("credits_DSC"),
+        vec![
+          value!("12345678901234567.89"),
+          value!("12345678901234567.8"),
+          value!("9.5"),
+        ],
+      ),
+    ] {
+      let connection = store
+        .query("Profile")
+        .unwrap()
+        .get_objects(
+          self::r#where("Profile", r#where),
+          ObjectOrderByInput::from_value(&data_model(), "Profile", order_by).unwrap(),
+          Pagination::default(),
+        )
+        .await
+        .unwrap();
+      assert_eq!(field_values(&connection, "credits").await, credits);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_list_filters() {
+    let store = store();
+    for (email, nick_names) in [
+      ("carol@x.com", vec!["cc"]),
+      ("alice@x.com", vec!["al", "ali"]),
+      ("bob@x.com", vec![]),
+      ("dave@x.com", vec!["dd", "al"]),
+    ] {
+      create(
+        &store,
+        "User",
+        value!({"email": email, "nickNames": nick_names}),
+      )
+      .await
+      .unwrap();
+    }
+
+    for (r#where, emails) in [
+      (
+        value!({"nickNames_has": "al"}),
+        vec![value!("alice@x.com"), value!("dave@x.com")],
+      ),
+      (
+        value!({"nickNames_has_every": ["ali", "al"]}),
+        vec![value!("alice@x.com")],
+      ),
+      (
+        value!({"nickNames_has_some": ["dd", "cc"]}),
+        vec![value!("carol@x.com"), value!("dave@x.com")],
+      ),
+      (
+        value!({"nickNames_is_empty": true}),
+        vec![value!("bob@x.com")],
+      ),
+      (
+        value!({"nickNames_length_gte": 2, "nickNames_has_some": ["cc", "ali"]}),
+        vec![value!("alice@x.com")],
+      ),
+      (
+        value!({"nickNames": ["al", "ali"]}),
+        vec![value!("alice@x.com")],
+      ),
+    ] {
+      assert_eq!(find_users(&store, r#where, Value::Null).await, emails);
+    }
+    assert!(matches!(
+      ObjectWhereInput::from_value(
+        &data_model(),
+        "User",
+        value!({"nickNames_length_lt": -1})
+      ),
+      Err(Error::InvalidInput(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_composite_fields() {
+    let store = store();
+    let alice_id = create(
+      &store,
+      "User",
+      value!({
+        "email": "alice@x.com",
+        "nickNames": [],
+        "address": {"street": "1 Main St", "city": "Paris", "geo": {"lat": 1, "lng": 2}},
+        "pastAddresses": [{"street": "2 Side St", "city": "Lyon"}],
+      }),
+    )
+    .await
+    .unwrap();
+    create(
+      &store,
+      "User",
+      value!({
+        "email": "bob@x.com",
+        "nickNames": [],
+        "pastAddresses": [{"street": "3 High St", "city": "Paris"}],
+      }),
+    )
+    .await
+    .unwrap();
+    assert!(matches!(
+      create(
+        &store,
+        "User",
+        value!({"email": "carol@x.com", "address": {"zip": "75001"}}),
+      )
+      .await,
+      Err(Error::InvalidInput(_))
+    ));
+
+    assert_eq!(
+      find_users(&store, value!({"address": {"city": "Paris"}}), Value::Null).await,
+      vec![value!("alice@x.com")]
+    );
+    assert_eq!(
+      find_users(
+        &store,
+        value!({"address": {"geo": {"lat_gt": 0}}}),
+        Value::Null
+      )
+      .await,
+      vec![value!("alice@x.com")]
+    );
+    assert_eq!(
+      find_users(&store, value!({"address_is_null": true}), Value::Null).await,
+      vec![value!("bob@x.com")]
+    );
+    assert_eq!(
+      find_users(
+        &store,
+        value!({"pastAddresses_some": {"city": "Paris"}}),
+        Value::Null
+      )
+      .await,
+      vec![value!("bob@x.com")]
+    );
+    assert_eq!(
+      find_users(
+        &store,
+        value!({"pastAddresses_none": {"street_contains": "St"}}),
+        Value::Null
+      )
+      .await,
+      Vec::<Value>::new()
+    );
+
+    // Single composite field is merged, while the list is replaced.
+    let mut mutation = store.mutation("User").unwrap();
+    let alice = mutation
+      .update_object(
+        where_unique("User", value!({"id": alice_id.as_str()})),
+        update_input(
+          "User",
+          value!({
+            "address": {"street": "4 New St", "geo": {"lng": 3}},
+            "pastAddresses": [],
+          }),
+        ),
+      )
+      .await
+      .unwrap();
+    assert_eq!(
+      alice.field(Name::new("address")).await,
+      value!({"street": "4 New St", "city": "Paris", "geo": {"lat": 1, "lng": 3}})
+    );
+    assert_eq!(alice.field(Name::new("pastAddresses")).await, value!([]));
+  }
+
+  #[tokio::test]
+  async fn test_pagination() {
+    let store = store();
+    let mut ids = Vec::new();
+    for idx in 0..5 {
+      let email = format!("user{idx}@x.com");
+      ids.push(
+        create(&store, "User", value!({"email": email, "nickNames": []}))
+          .await
+          .unwrap(),
+      );
+    }
+    let query = store.query("User").unwrap();
+    let connection = query
+      .get_objects(
+        ObjectWhereInput::default(),
+        ObjectOrderByInput::default(),
+        Pagination {
+          after: Some(ids[0].clone()),
+          first: Some(2),
+          ..Default::default()
+        },
+      )
+      .await
+      .unwrap();
+    assert_eq!(connection.count, 5);
+    assert_eq!(
+      field_values(&connection, "email").await,
+      vec![value!("user1@x.com"), value!("user2@x.com")]
+    );
+    assert_eq!(connection.page_info.start_cursor, Some(ids[1].clone()));
+    assert_eq!(connection.page_info.end_cursor, Some(ids[2].clone()));
+    assert!(connection.page_info.has_next_page);
+    assert!(connection.page_info.has_previous_page);
+
+    let connection = query
+      .get_objects(
+        ObjectWhereInput::default(),
+        ObjectOrderByInput::default(),
+        Pagination {
+          before: Some(ids[4].clone()),
+          last: Some(2),
+          ..Default::default()
+        },
+      )
+      .await
+      .unwrap();
+    assert_eq!(
+      field_values(&connection, "email").await,
+      vec![value!("user2@x.com"), value!("user3@x.com")]
+    );
+  }
+
+  async fn relations(
+    store: &MemoryStore,
+    model: &str,
+    id: &ID,
+    relation: &str,
+  ) -> Vec<ID> {
+    let object = store
+      .query(model)
+      .unwrap()
+      .get_object(where_unique(model, value!({"id": id.as_str()})))
+      .await
+      .unwrap()
+      .unwrap();
+    let connection = object
+      .relations(
+        Name::new(relation),
+        ObjectWhereInput::default(),
+        ObjectOrderByInput::default(),
+        Pagination::default(),
+      )
+      .await
+      .unwrap();
+    let mut ids = Vec::new();
+    for edge in &connection.edges {
+      ids.push(edge.node.id().await);
+    }
+    ids
+  }
+
+  #[tokio::test]
+  async fn test_nested_relations() {
+    let store = store();
+    let user_id = create(
+      &store,
+      "User",
+      value!({
+        "email": "alice@x.com",
+        "nickNames": [],
+        "profile": {"create": {"bio": "Hello!"}},
+        "posts": {"create": [
+          {"title": "First"},
+          {"title": "Second"},
+        ]},
+      }),
+    )
+    .await
+    .unwrap();
+    let post_ids = relations(&store, "User", &user_id, "posts").await;
+    assert_eq!(post_ids.len(), 2);
+
+    let user = store
+      .query("User")
+      .unwrap()
+      .get_object(where_unique("User", value!({"id": user_id.as_str()})))
+      .await
+      .unwrap()
+      .unwrap();
+    let profile = user.relation(Name::new("profile")).await.unwrap().unwrap();
+    assert_eq!(profile.field(Name::new("bio")).await, value!("Hello!"));
+    assert_eq!(
+      profile.field(Name::new("userEmail")).await,
+      value!("alice@x.com")
+    );
+    let author = profile
+      .relation(Name::new("user"))
+      .await
+      .unwrap()
+      .expect("Profile should be connected to the user.");
+    assert_eq!(author.id().await, user_id);
+
+    // Relation filters.
+    assert_eq!(
+      find_users(
+        &store,
+        value!({"posts_some": {"title": "Second"}, "profile": {"bio_contains": "Hello"}}),
+        Value::Null
+      )
+      .await,
+      vec![value!("alice@x.com")]
+    );
+    assert!(find_users(
+      &store,
+      value!({"posts_none": {"title": "First"}}),
+      Value::Null
+    )
+    .await
+    .is_empty());
+
+    // Many-to-many relation, connected at the given position.
+    let category_id = |name: &'static str| {
+      let store = store.clone();
+      async move {
+        create(&store, "Category", value!({"name": name, "postIDs": []}))
+          .await
+          .unwrap()
+      }
+    };
+    let (news, tech) = (category_id("News").await, category_id("Tech").await);
+    let mut mutation = store.mutation("Post").unwrap();
+    mutation
+      .update_object(
+        where_unique("Post", value!({"id": post_ids[0].as_str()})),
+        update_input(
+          "Post",
+          value!({"category": {"connect": [
+            {"where": {"id": tech.as_str()}},
+            {"where": {"id": news.as_str()}, "position": {"before": tech.as_str()}},
+          ]}}),
+        ),
+      )
+      .await
+      .unwrap();
+    assert_eq!(
+      relations(&store, "Post", &post_ids[0], "category").await,
+      vec![news.clone(), tech.clone()]
+    );
+    assert_eq!(
+      relations(&store, "Category", &tech, "posts").await,
+      vec![post_ids[0].clone()]
+    );
+
+    mutation
+      .update_object(
+        where_unique("Post", value!({"id": post_ids[0].as_str()})),
+        update_input(
+          "Post",
+          value!({"category": {"disconnect": [{"id": tech.as_str()}]}}),
+        ),
+      )
+      .await
+      .unwrap();
+    assert!(relations(&store, "Category", &tech, "posts")
+      .await
+      .is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_implicit_many_to_many() {
+    let store = store();
+    let alice_id = create(
+      &store,
+      "User",
+      value!({
+        "email": "alice@x.com",
+        "nickNames": [],
+        "posts": {"create": [{"title": "First", "tags": {"create": [{"name": "News"}]}}]},
+      }),
+    )
+    .await
+    .unwrap();
+    let post_id = relations(&store, "User", &alice_id, "posts").await[0].clone();
+    let news = relations(&store, "Post", &post_id, "tags").await[0].clone();
+    let tech = create(&store, "Tag", value!({"name": "Tech"}))
+      .await
+      .unwrap();
+    let mut mutation = store.mutation("Post").unwrap();
+    mutation
+      .update_object(
+        where_unique("Post", value!({"id": post_id.as_str()})),
+        update_input(
+          "Post",
+          value!({"tags": {"connect": [{"where": {"name": "Tech"}}]}}),
+        ),
+      )
+      .await
+      .unwrap();
+    assert_eq!(
+      relations(&store, "Post", &post_id, "tags").await,
+      vec![news.clone(), tech.clone()]
+    );
+    assert_eq!(
+      relations(&store, "Tag", &tech, "posts").await,
+      vec![post_id.clone()]
+    );
+    assert_eq!(
+      find_users(
+        &store,
+        value!({"posts_some": {"tags_some": {"name": "Tech"}}}),
+        Value::Null
+      )
+      .await,
+      vec![value!("alice@x.com")]
+    );
+
+    mutation
+      .update_object(
+        where_unique("Post", value!({"id": post_id.as_str()})),
+        update_input("Post", value!({"tags": {"set": [{"name": "Tech"}]}})),
+      )
+      .await
+      .unwrap();
+    assert_eq!(
+      relations(&store, "Post", &post_id, "tags").await,
+      vec![tech.clone()]
+    );
+    assert!(relations(&store, "Tag", &news, "posts").await.is_empty());
+
+    // Links are removed along with the object.
+    store
+      .mutation("Tag")
+      .unwrap()
+      .delete_object(where_unique("Tag", value!({"name": "Tech"})))
+      .await
+      .unwrap();
+    assert!(relations(&store, "Post", &post_id, "tags").await.is_empty());
+
+    // Self relation, each side has its own links.
+    let bob_id = create(
+      &store,
+      "User",
+      value!({
+        "email": "bob@x.com",
+        "nickNames": [],
+        "following": {"connect": [{"email": "alice@x.com"}]},
+      }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+      relations(&store, "User", &alice_id, "followers").await,
+      vec![bob_id.clone()]
+    );
+    assert!(relations(&store, "User", &alice_id, "following")
+      .await
+      .is_empty());
+    store
+      .mutation("User")
+      .unwrap()
+      .delete_object(where_unique("User", value!({"email": "bob@x.com"})))
+      .await
+      .unwrap();
+    assert!(relations(&store, "User", &alice_id, "followers")
+      .await
+      .is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_delete_object() {
+    let store = store();
+    let mentor_id = create(
+      &store,
+      "User",
+      value!({"email": "alice@x.com", "nickNames": []}),
+    )
+    .await
+    .unwrap();
+    let mentee_id = create(
+      &store,
+      "User",
+      value!({
+        "email": "bob@x.com",
+        "nickNames": [],
+        "mentor": {"connect": {"email": "alice@x.com"}},
+        "posts": {"create": [{"title": "First"}]},
+      }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+      relations(&store, "User", &mentor_id, "mentees").await,
+      vec![mentee_id.clone()]
+    );
+
+    // Posts' author is required.
+    let mut mutation = store.mutation("User").unwrap();
+    let result = mutation
+      .delete_object(where_unique("User", value!({"email": "bob@x.com"})))
+      .await;
+    assert!(matches!(result, Err(Error::RelationViolation(_))));
+    assert_eq!(find_users(&store, Value::Null, Value::Null).await.len(), 2);
+
+    // Mentor is optional, hence mentee is disconnected.
+    let mentor = mutation
+      .delete_object(where_unique("User", value!({"email": "alice@x.com"})))
+      .await
+      .unwrap();
+    assert_eq!(mentor.id().await, mentor_id);
+    let mentee = store
+      .query("User")
+      .unwrap()
+      .get_object(where_unique("User", value!({"id": mentee_id.as_str()})))
+      .await
+      .unwrap()
+      .unwrap();
+    assert_eq!(mentee.field(Name::new("mentorEmail")).await, Value::Null);
+    assert!(mentee
+      .relation(Name::new("mentor"))
+      .await
+      .unwrap()
+      .is_none());
+  }
+
+  #[tokio::test]
+  async fn test_referential_actions() {
+    let store = store();
+    let user_id = create(
+      &store,
+      "User",
+      value!({
+        "email": "alice@x.com",
+        "nickNames": [],
+        "posts": {"create": [{"title": "First"}, {"title": "Second"}]},
+      }),
+    )
+    .await
+    .unwrap();
+    create(
+      &store,
+      "User",
+      value!({"email": "bob@x.com", "nickNames": []}),
+    )
+    .await
+    .unwrap();
+    let post_ids = relations(&store, "User", &user_id, "posts").await;
+    for (post_id, editor) in [(&post_ids[0], "alice@x.com"), (&post_ids[1], "bob@x.com")]
+    {
+      create(
+        &store,
+        "Comment",
+        value!({
+          "text": "Nice!",
+          "post": {"connect": {"id": post_id.as_str()}},
+          "editor": {"connect": {"email": editor}},
+        }),
+      )
+      .await
+      .unwrap();
+    }
+    let editor_emails = || {
+      let store = &store;
+      async move {
+        let connection = store
+          .query("Comment")
+          .unwrap()
+          .get_objects(
+            ObjectWhereInput::default(),
+            ObjectOrderByInput::default(),
+            Pagination::default(),
+          )
+          .await
+          .unwrap();
+        field_values(&connection, "editorEmail").await
+      }
+    };
+
+    // onUpdate: Cascade, the comments follow the editor's new email.
+    store
+      .mutation("User")
+      .unwrap()
+      .update_object(
+        where_unique("User", value!({"email": "alice@x.com"})),
+        update_input("User", value!({"email": "alice@y.com"})),
+      )
+      .await
+      .unwrap();
+    assert_eq!(
+      editor_emails().await,
+      vec![value!("alice@y.com"), value!("bob@x.com")]
+    );
+
+    // onDelete: SetNull, the comments are kept without the editor.
+    store
+      .mutation("User")
+      .unwrap()
+      .delete_object(where_unique("User", value!({"email": "bob@x.com"})))
+      .await
+      .unwrap();
+    assert_eq!(
+      editor_emails().await,
+      vec![value!("alice@y.com"), Value::Null]
+    );
+
+    // onDelete: Cascade, the comments are deleted along with the posts.
+    store
+      .mutation("Post")
+      .unwrap()
+      .delete_many_objects(
+        r#where("Post", value!({"title": "First"})),
+        Pagination::default(),
+      )
+      .await
+      .unwrap();
+    assert_eq!(editor_emails().await, vec![Value::Null]);
+
+    // onDelete: NoAction, fails as the category still references the post.
+    create(
+      &store,
+      "Category",
+      value!({"name": "News", "posts": {"connect": [{"id": post_ids[1].as_str()}]}}),
+    )
+    .await
+    .unwrap();
+    let delete_post = || {
+      let store = &store;
+      async move {
+        store
+          .mutation("Post")
+          .unwrap()
+          .delete_many_objects(
+            r#where("Post", value!({"title": "Second"})),
+            Pagination::default(),
+          )
+          .await
+      }
+    };
+    assert!(matches!(
+      delete_post().await,
+      Err(Error::RelationViolation(_))
+    ));
+    assert_eq!(relations(&store, "User", &user_id, "posts").await.len(), 1);
+    store
+      .mutation("Category")
+      .unwrap()
+      .delete_object(where_unique("Category", value!({"name": "News"})))
+      .await
+      .unwrap();
+    assert!(delete_post().await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn test_mutation_rollback() {
+    let store = store();
+    create(&store, "Category", value!({"name": "News", "postIDs": []}))
+      .await
+      .unwrap();
+    // Second nested category fails with the unique violation.
+    let result = create(
+      &store,
+      "User",
+      value!({
+        "email": "alice@x.com",
+        "nickNames": [],
+        "posts": {"create": [{
+          "title": "First",
+          "category": {"create": [
+            {"name": "Tech", "postIDs": []},
+            {"name": "News", "postIDs": []},
+          ]},
+        }]},
+      }),
+    )
+    .await;
+    assert!(matches!(result, Err(Error::UniqueViolation { .. })));
+    assert!(find_users(&store, Value::Null, Value::Null)
+      .await
+      .is_empty());
+    let connection = store
+      .query("Category")
+      .unwrap()
+      .get_objects(
+        ObjectWhereInput::default(),
+        ObjectOrderByInput::default(),
+        Pagination::default(),
+      )
+      .await
+      .unwrap();
+    assert_eq!(
+      field_values(&connection, "name").await,
+      vec![value!("News")]
+    );
+  }
+}
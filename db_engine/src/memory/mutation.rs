@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::{object, Store};
+use crate::{
+  db_crud::{
+    DBMutation, DBObject, ObjectConnection, ObjectCreateInput, ObjectOrderByInput,
+    ObjectUpdateInput, ObjectUpsertInput, ObjectWhereInput, ObjectWhereUniqueInput,
+    Pagination, ID,
+  },
+  model::Model,
+  tables::Fields,
+  Error,
+};
+
+pub(super) struct MemoryMutation {
+  store: Arc<Store>,
+  model: Arc<Model>,
+}
+
+impl MemoryMutation {
+  pub fn new(store: Arc<Store>, model: Arc<Model>) -> Self {
+    MemoryMutation { store, model }
+  }
+
+  fn object(&self, id: ID) -> Result<Box<dyn DBObject>, Error> {
+    self.store.read(|view| {
+      let fields = view.fields(&self.model, &id)?.clone();
+      Ok(object::boxed(&self.store, &self.model, id, fields))
+    })
+  }
+}
+
+#[async_trait]
+impl DBMutation for MemoryMutation {
+  async fn create_object(
+    &mut self,
+    data: ObjectCreateInput,
+  ) -> Result<Box<dyn DBObject>, Error> {
+    let id = self
+      .store
+      .write(|txn| txn.create(&self.model, &data, Fields::new()))?;
+    self.object(id)
+  }
+
+  async fn update_object(
+    &mut self,
+    r#where: ObjectWhereUniqueInput,
+    data: ObjectUpdateInput,
+  ) -> Result<Box<dyn DBObject>, Error> {
+    let id = self.store.write(|txn| {
+      let id = txn.find(&self.model, &r#where)?;
+      txn.update(&self.model, &id, &data)?;
+      Ok(id)
+    })?;
+    self.object(id)
+  }
+
+  async fn delete_object(
+    &mut self,
+    r#where: ObjectWhereUniqueInput,
+  ) -> Result<Box<dyn DBObject>, Error> {
+    let (id, fields) = self.store.write(|txn| {
+      let id = txn.find(&self.model, &r#where)?;
+      let fields = txn.delete(&self.model, &id)?;
+      Ok((id, fields))
+    })?;
+    Ok(object::boxed(&self.store, &self.model, id, fields))
+  }
+
+  async fn upsert_object(
+    &mut self,
+    r#where: ObjectWhereUniqueInput,
+    data: ObjectUpsertInput,
+  ) -> Result<Box<dyn DBObject>, Error> {
+    let id = self
+      .store
+      .write(|txn| txn.upsert(&self.model, &r#where, &data))?;
+    self.object(id)
+  }
+
+  async fn delete_many_objects(
+    &mut self,
+    r#where: ObjectWhereInput,
+    pagination: Pagination,
+  ) -> Result<ObjectConnection, Error> {
+    let (objects, page_info, count) = self.store.write(|txn| {
+      let page = txn.view().find_many(
+        &self.model,
+        None,
+        &r#where,
+        &ObjectOrderByInput::default(),
+        &pagination,
+      )?;
+      let objects = txn.delete_many(&self.model, page.ids)?;
+      Ok((objects, page.page_info, page.count))
+    })?;
+    Ok(object::objects_connection(
+      &self.store,
+      &self.model,
+      objects,
+      page_info,
+      count,
+    ))
+  }
+
+  async fn update_many_objects(
+    &mut self,
+    r#where: ObjectWhereInput,
+    data: ObjectUpdateInput,
+    pagination: Pagination,
+  ) -> Result<ObjectConnection, Error> {
+    self.store.write(|txn| {
+      let page = txn.view().find_many(
+        &self.model,
+        None,
+        &r#where,
+        &ObjectOrderByInput::default(),
+        &pagination,
+      )?;
+      for id in &page.ids {
+        txn.update(&self.model, id, &data)?;
+      }
+      object::connection(&self.store, &self.model, &txn.view(), page)
+    })
+  }
+}
@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use graphql_value::{ConstValue as Value, Name};
+
+use super::Store;
+use crate::{
+  db_crud::{
+    DBObject, ObjectConnection, ObjectEdge, ObjectOrderByInput, ObjectWhereInput,
+    PageInfo, Pagination, ID,
+  },
+  model::{Model, Relation, FIELD_ID},
+  tables::{Fields, Page, View},
+  Error,
+};
+
+/// Object read from the store, holds a snapshot of its fields.
+/// Relations are read from the store, when asked for.
+struct MemoryObject {
+  store: Arc<Store>,
+  model: Arc<Model>,
+  id: ID,
+  fields: Fields,
+}
+
+impl MemoryObject {
+  fn relation(&self, name: &str) -> Result<&Relation, Error> {
+    self.model.relations.get(name).ok_or_else(|| {
+      Error::InvalidInput(format!(
+        r#"Unknown relation "{name}" on {}."#,
+        self.model.name
+      ))
+    })
+  }
+}
+
+#[async_trait]
+impl DBObject for MemoryObject {
+  async fn id(&self) -> ID {
+    self.id.clone()
+  }
+
+  async fn field(&self, name: Name) -> Value {
+    // Note: Model with compound ID has no ID field, its ID is the object's ID.
+    if name == FIELD_ID && self.model.has_compound_id() {
+      return Value::String(self.id.to_string());
+    }
+    self
+      .fields
+      .get(name.as_str())
+      .cloned()
+      .unwrap_or(Value::Null)
+  }
+
+  async fn relation(&self, name: Name) -> Result<Option<Box<dyn DBObject>>, Error> {
+    let relation = self.relation(&name)?;
+    self.store.read(|view| {
+      let Some(id) = view
+        .related(&self.model, relation, &self.fields)
+        .into_iter()
+        .next()
+      else {
+        return Ok(None);
+      };
+      let target = view.model(&relation.model);
+      let fields = view.fields(target, &id)?.clone();
+      Ok(Some(boxed(&self.store, target, id, fields)))
+    })
+  }
+
+  async fn relations(
+    &self,
+    name: Name,
+    r#where: ObjectWhereInput,
+    order_by: ObjectOrderByInput,
+    pagination: Pagination,
+  ) -> Result<ObjectConnection, Error> {
+    let relation = self.relation(&name)?;
+    self.store.read(|view| {
+      let target = view.model(&relation.model);
+      let related = view.related(&self.model, relation, &self.fields);
+      let page =
+        view.find_many(target, Some(related), &r#where, &order_by, &pagination)?;
+      connection(&self.store, target, view, page)
+    })
+  }
+}
+
+pub(super) fn boxed(
+  store: &Arc<Store>,
+  model: &Arc<Model>,
+  id: ID,
+  fields: Fields,
+) -> Box<dyn DBObject> {
+  Box::new(MemoryObject {
+    store: store.clone(),
+    model: model.clone(),
+    id,
+    fields,
+  })
+}
+
+/// Connection of the objects in the page.
+pub(super) fn connection(
+  store: &Arc<Store>,
+  model: &Arc<Model>,
+  view: &View<'_>,
+  page: Page,
+) -> Result<ObjectConnection, Error> {
+  let objects = page
+    .ids
+    .into_iter()
+    .map(|id| Ok((view.fields(model, &id)?.clone(), id)))
+    .collect::<Result<Vec<_>, Error>>()?;
+  Ok(objects_connection(
+    store,
+    model,
+    objects,
+    page.page_info,
+    page.count,
+  ))
+}
+
+/// Connection of the given objects, with their fields.
+pub(super) fn objects_connection(
+  store: &Arc<Store>,
+  model: &Arc<Model>,
+  objects: Vec<(Fields, ID)>,
+  page_info: PageInfo,
+  count: usize,
+) -> ObjectConnection {
+  ObjectConnection {
+    page_info,
+    edges: objects
+      .into_iter()
+      .map(|(fields, id)| ObjectEdge {
+        node: boxed(store, model, id.clone(), fields),
+        cursor: id,
+      })
+      .collect(),
+    count,
+  }
+}
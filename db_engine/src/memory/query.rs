@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::{object, Store};
+use crate::{
+  db_crud::{
+    DBObject, DBQuery, ObjectConnection, ObjectOrderByInput, ObjectWhereInput,
+    ObjectWhereUniqueInput, Pagination,
+  },
+  model::Model,
+  Error,
+};
+
+pub(super) struct MemoryQuery {
+  store: Arc<Store>,
+  model: Arc<Model>,
+}
+
+impl MemoryQuery {
+  pub fn new(store: Arc<Store>, model: Arc<Model>) -> Self {
+    MemoryQuery { store, model }
+  }
+}
+
+#[async_trait]
+impl DBQuery for MemoryQuery {
+  async fn get_object(
+    &self,
+    r#where: ObjectWhereUniqueInput,
+  ) -> Result<Option<Box<dyn DBObject>>, Error> {
+    self.store.read(|view| {
+      let Some(id) = view.find_unique(&self.model, &r#where)? else {
+        return Ok(None);
+      };
+      let fields = view.fields(&self.model, &id)?.clone();
+      Ok(Some(object::boxed(&self.store, &self.model, id, fields)))
+    })
+  }
+
+  async fn get_objects(
+    &self,
+    r#where: ObjectWhereInput,
+    order_by: ObjectOrderByInput,
+    pagination: Pagination,
+  ) -> Result<ObjectConnection, Error> {
+    self.store.read(|view| {
+      let page = view.find_many(&self.model, None, &r#where, &order_by, &pagination)?;
+      object::connection(&self.store, &self.model, view, page)
+    })
+  }
+}
@@ -0,0 +1,477 @@
+//! Data model information needed by the data store backends.
+use std::{collections::HashMap, sync::Arc};
+
+use graphql_value::ConstValue as Value;
+use indexmap::IndexMap;
+use sdml_parser::types::{
+  AttribArg, DataModel, FieldDecl, ModelAttribute, ModelDecl, PrimitiveType,
+  ReferentialActions, RelationEdge, Token, Type,
+};
+
+use crate::{
+  db_crud::ID,
+  tables::{value, Fields},
+};
+
+/// GraphQL name of the ID field of every model.
+pub const FIELD_ID: &str = "id";
+
+/// SDML function to auto generate the ID.
+const DEFAULT_FN_AUTO: &str = "auto";
+/// SDML function to set the current date-time.
+const DEFAULT_FN_NOW: &str = "now";
+/// SDML functions to generate the IDs, see the `FieldDefault` variants.
+const DEFAULT_FN_UUID: &str = "uuid";
+const DEFAULT_FN_CUID: &str = "cuid";
+const DEFAULT_FN_ULID: &str = "ulid";
+const DEFAULT_FN_NANOID: &str = "nanoid";
+const DEFAULT_FN_SEQUENCE: &str = "sequence";
+
+/// Size of the Nano ID, if it isn't given to `nanoid()`.
+const NANOID_SIZE: usize = 21;
+
+/// Prefix of the hidden join model names, can't clash with the model names as
+/// it isn't allowed in the SDML identifiers.
+const JOIN_MODEL_PREFIX: &str = "#";
+/// Fields of the join model, holding the IDs of the objects on either side of
+/// the implicit many-to-many relation.
+const JOIN_FIELD_A: &str = "A";
+const JOIN_FIELD_B: &str = "B";
+
+/// Models of the data model, by the model name.
+pub(crate) type Models = HashMap<String, Arc<Model>>;
+
+/// Builds the models from the data model, along with the hidden join models of
+/// the implicit many-to-many relations.
+pub(crate) fn models(data_model: &DataModel) -> Models {
+  let join_models = data_model
+    .relations
+    .iter()
+    .filter(|(_, (edge, _))| {
+      matches!(edge, RelationEdge::ImplicitManyToManyRelation { .. })
+    })
+    .map(|(relation_name, _)| Model::join(relation_name));
+  data_model
+    .models
+    .values()
+    .map(|model| Model::new(data_model, model))
+    .chain(join_models)
+    .map(|model| (model.name.clone(), Arc::new(model)))
+    .collect()
+}
+
+/// Model with its fields & relations.
+#[derive(Debug)]
+pub(crate) struct Model {
+  pub name: String,
+  /// Name of the field marked with `@id` attribute, or of the compound key
+  /// marked with `@@id` attribute.
+  pub id_field: String,
+  /// Scalar fields (including the relation scalar fields) & composite fields, in
+  /// the declaration order.
+  pub fields: IndexMap<String, Field>,
+  /// Relation fields, in the declaration order.
+  pub relations: IndexMap<String, Relation>,
+  /// Compound keys (`@@id`, `@@unique` & `@@index`), by the compound name.
+  pub compound_keys: IndexMap<String, CompoundKey>,
+}
+
+impl Model {
+  fn new(data_model: &DataModel, model: &ModelDecl) -> Self {
+    let model_fields = model.get_fields();
+    let indexed_fields = model_fields
+      .all_indexed()
+      .into_iter()
+      .map(field_name)
+      .collect::<Vec<_>>();
+
+    let mut id_field = String::new();
+    let mut fields = IndexMap::new();
+    let mut relations = IndexMap::new();
+    for field in &model.fields {
+      let name = field_name(field);
+      match field.field_type.r#type() {
+        Type::Relation(edge) => {
+          relations.insert(
+            name.clone(),
+            Relation {
+              name,
+              relation_name: edge.relation_name().str().unwrap(),
+              model: edge.referenced_model_name().ident_name().unwrap(),
+              is_array: field.field_type.is_array(),
+              link: RelationLink::new(data_model, edge, model, field),
+              actions: edge.referential_actions(),
+            },
+          );
+        }
+        // Note: Values of the composite types are embedded in the object.
+        Type::Primitive { .. }
+        | Type::Enum { .. }
+        | Type::Composite { .. }
+        | Type::Unknown(..) => {
+          if field.has_id_attrib() {
+            id_field = name.clone();
+          }
+          fields.insert(
+            name.clone(),
+            Field {
+              is_array: field.field_type.is_array(),
+              is_optional: field.field_type.is_optional(),
+              is_unique: field.has_id_attrib() || field.has_unique_attrib(),
+              // Note: Unique relation scalar fields aren't listed in `all_indexed`.
+              is_indexed: field.has_unique_attrib() || indexed_fields.contains(&name),
+              is_updated_at: field.has_updated_at_attrib(),
+              is_decimal: is_decimal(field),
+              default: FieldDefault::new(field),
+              name,
+            },
+          );
+        }
+      }
+    }
+
+    let mut compound_keys = IndexMap::new();
+    let compound_uniques = model
+      .compound_id()
+      .into_iter()
+      .chain(model.compound_uniques());
+    for (key, is_unique) in compound_uniques
+      .map(|key| (key, true))
+      .chain(model.compound_indexes().map(|key| (key, false)))
+    {
+      let key = CompoundKey::new(key, is_unique);
+      compound_keys.insert(key.name.clone(), key);
+    }
+    if let Some(compound_id) = model.compound_id() {
+      id_field = compound_id.compound_name();
+    }
+
+    Model {
+      name: model.name.ident_name().unwrap(),
+      id_field,
+      fields,
+      relations,
+      compound_keys,
+    }
+  }
+
+  /// Hidden model linking the objects of the implicit many-to-many relation, an
+  /// object per connected pair holding their IDs.
+  fn join(relation_name: &str) -> Self {
+    let fields = [JOIN_FIELD_A, JOIN_FIELD_B].map(|name| {
+      let field = Field {
+        name: name.to_string(),
+        is_array: false,
+        is_optional: false,
+        is_unique: false,
+        is_indexed: true,
+        is_updated_at: false,
+        is_decimal: false,
+        default: None,
+      };
+      (field.name.clone(), field)
+    });
+    let compound_id = CompoundKey {
+      name: format!("{JOIN_FIELD_A}_{JOIN_FIELD_B}"),
+      fields: vec![JOIN_FIELD_A.to_string(), JOIN_FIELD_B.to_string()],
+      is_unique: true,
+    };
+    Model {
+      name: join_model_name(relation_name),
+      id_field: compound_id.name.clone(),
+      fields: IndexMap::from(fields),
+      relations: IndexMap::new(),
+      compound_keys: IndexMap::from([(compound_id.name.clone(), compound_id)]),
+    }
+  }
+
+  /// Model's objects are identified by the compound key marked with `@@id`.
+  pub fn has_compound_id(&self) -> bool {
+    self.compound_keys.contains_key(&self.id_field)
+  }
+
+  /// ID of the object with the given fields, `None` if the ID field value is missing.
+  /// Note: ID of the compound ID is the key of its field values.
+  pub fn object_id(&self, fields: &Fields) -> Option<ID> {
+    let id = match self.compound_keys.get(&self.id_field) {
+      Some(compound_id) => compound_id.value(fields)?,
+      None => fields
+        .get(&self.id_field)
+        .filter(|id| !matches!(id, Value::Null))?
+        .clone(),
+    };
+    Some(ID::new(value::key(&id)))
+  }
+
+  /// Name of the model field for the given GraphQL field name.
+  pub fn field_name<'a>(&'a self, name: &'a str) -> &'a str {
+    if name == FIELD_ID {
+      &self.id_field
+    } else {
+      name
+    }
+  }
+
+  /// Scalar field of the given GraphQL field name.
+  pub fn field(&self, name: &str) -> Option<&Field> {
+    self.fields.get(self.field_name(name))
+  }
+
+  /// Fields whose values are unique across the objects of the model.
+  pub fn unique_fields(&self) -> impl Iterator<Item = &Field> {
+    self.fields.values().filter(|field| field.is_unique)
+  }
+
+  /// Fields indexed in the data store, for faster search.
+  pub fn indexed_fields(&self) -> impl Iterator<Item = &Field> {
+    self.fields.values().filter(|field| field.is_indexed)
+  }
+}
+
+/// Key made of more than one scalar field of a model.
+#[derive(Debug)]
+pub(crate) struct CompoundKey {
+  /// Field names joined by `_`, Ex. `tenantId_email`.
+  pub name: String,
+  pub fields: Vec<String>,
+  /// Key is marked with either `@@id` or `@@unique` attribute.
+  pub is_unique: bool,
+}
+
+impl CompoundKey {
+  fn new(key: &ModelAttribute, is_unique: bool) -> Self {
+    CompoundKey {
+      name: key.compound_name(),
+      fields: key.field_names(),
+      is_unique,
+    }
+  }
+
+  /// Value of the key, list of its field values in the key order.
+  /// `None` if any of the field values is `null`, as such objects aren't indexed.
+  pub fn value(&self, fields: &Fields) -> Option<Value> {
+    self
+      .fields
+      .iter()
+      .map(|name| {
+        fields
+          .get(name)
+          .filter(|value| !matches!(value, Value::Null))
+          .cloned()
+      })
+      .collect::<Option<Vec<_>>>()
+      .map(Value::List)
+  }
+}
+
+/// Scalar field of a model.
+#[derive(Debug)]
+pub(crate) struct Field {
+  pub name: String,
+  pub is_array: bool,
+  pub is_optional: bool,
+  /// Field is marked with either `@id` or `@unique` attribute.
+  pub is_unique: bool,
+  /// Field is indexed (`@id`, `@unique` or `@indexed`).
+  pub is_indexed: bool,
+  /// Field is marked with `@updatedAt` attribute, set to the current date-time
+  /// whenever the object is created or updated.
+  pub is_updated_at: bool,
+  /// Field is of `Decimal` type, its values are held as strings & compared numerically.
+  pub is_decimal: bool,
+  pub default: Option<FieldDefault>,
+}
+
+/// Value assigned to the field, when no value is given while creating the object.
+#[derive(Debug)]
+pub(crate) enum FieldDefault {
+  /// Auto generated ID, from the counter shared by all the models.
+  Auto,
+  /// Random UUID v4.
+  UuidV4,
+  /// Time-sortable UUID v7.
+  UuidV7,
+  /// Time-sortable CUID.
+  Cuid,
+  /// Time-sortable ULID.
+  Ulid,
+  /// Random Nano ID of the given size.
+  NanoId(usize),
+  /// Next value of the counter of the model field, a zero padded string if the field
+  /// is a string.
+  Sequence {
+    is_string: bool,
+  },
+  /// Current date-time.
+  Now,
+  Value(Value),
+}
+
+impl FieldDefault {
+  fn new(field: &FieldDecl) -> Option<Self> {
+    match field.default_attribute()?.arg.as_ref()? {
+      AttribArg::Function(function, args) => {
+        let arg = match args.first() {
+          Some(Token::Int(int, _)) => Some(*int),
+          _ => None,
+        };
+        match (function.ident_name()?.as_str(), arg) {
+          (DEFAULT_FN_AUTO, _) => Some(FieldDefault::Auto),
+          (DEFAULT_FN_UUID, Some(7)) => Some(FieldDefault::UuidV7),
+          (DEFAULT_FN_UUID, _) => Some(FieldDefault::UuidV4),
+          (DEFAULT_FN_CUID, _) => Some(FieldDefault::Cuid),
+          (DEFAULT_FN_ULID, _) => Some(FieldDefault::Ulid),
+          (DEFAULT_FN_NANOID, size) => Some(FieldDefault::NanoId(
+            size.map_or(NANOID_SIZE, |size| size as usize),
+          )),
+          (DEFAULT_FN_SEQUENCE, _) => Some(FieldDefault::Sequence {
+            is_string: matches!(
+              field.field_type.r#type(),
+              Type::Primitive {
+                r#type: PrimitiveType::ShortStr | PrimitiveType::LongStr,
+                ..
+              }
+            ),
+          }),
+          (DEFAULT_FN_NOW, _) => Some(FieldDefault::Now),
+          _ => None,
+        }
+      }
+      _ => {
+        let value = field.default_value()?;
+        if is_decimal(field) {
+          value::decimal_value(&value).map(FieldDefault::Value)
+        } else {
+          Some(FieldDefault::Value(value))
+        }
+      }
+    }
+  }
+}
+
+fn is_decimal(field: &FieldDecl) -> bool {
+  matches!(
+    field.field_type.r#type(),
+    Type::Primitive {
+      r#type: PrimitiveType::Decimal,
+      ..
+    }
+  )
+}
+
+/// Relation field of a model.
+#[derive(Debug)]
+pub(crate) struct Relation {
+  pub name: String,
+  /// Name of the relation, shared by the relation fields on both sides.
+  pub relation_name: String,
+  /// Name of the referenced model.
+  pub model: String,
+  /// Relation field holds more than one object.
+  pub is_array: bool,
+  pub link: RelationLink,
+  /// `onDelete` & `onUpdate` actions, given only on the relation field with the
+  /// `Forward` link.
+  pub actions: ReferentialActions,
+}
+
+/// How the objects in a relation are linked together.
+#[derive(Debug)]
+pub(crate) enum RelationLink {
+  /// Object's `scalar_field` holds the value(s) of the referenced object's
+  /// `referenced_field`.
+  Forward {
+    scalar_field: String,
+    referenced_field: String,
+  },
+  /// Referenced object's `scalar_field` holds the value(s) of the object's
+  /// `referenced_field`.
+  Backward {
+    scalar_field: String,
+    referenced_field: String,
+  },
+  /// Objects of the hidden `join_model` link the objects of the implicit
+  /// many-to-many relation, their `field` holds the object's ID and their
+  /// `referenced_field` holds the referenced object's ID.
+  Join {
+    join_model: String,
+    field: String,
+    referenced_field: String,
+  },
+}
+
+impl RelationLink {
+  fn new(
+    data_model: &DataModel,
+    edge: &RelationEdge,
+    model: &ModelDecl,
+    field: &FieldDecl,
+  ) -> Self {
+    if let RelationEdge::ImplicitManyToManyRelation { relation_name, .. } = edge {
+      let relation_name = relation_name.str().unwrap();
+      // Note: Sides of the relation are ordered by the model & field names, so that
+      // each of the relation fields (even of a self relation) gets its own join field.
+      let partner = data_model
+        .models
+        .values()
+        .flat_map(|model| model.fields.iter().map(move |field| (model, field)))
+        .find(|(other_model, other_field)| {
+          let Type::Relation(other_edge) = other_field.field_type.r#type() else {
+            return false;
+          };
+          other_edge.relation_name().str().as_deref() == Some(relation_name.as_str())
+            && (other_model.name != model.name || other_field.name != field.name)
+        })
+        .map(|(other_model, other_field)| {
+          (
+            other_model.name.ident_name().unwrap(),
+            field_name(other_field),
+          )
+        })
+        .expect("Implicit many-to-many relation should have both the edges.");
+      let (field, referenced_field) =
+        if (model.name.ident_name().unwrap(), field_name(field)) < partner {
+          (JOIN_FIELD_A, JOIN_FIELD_B)
+        } else {
+          (JOIN_FIELD_B, JOIN_FIELD_A)
+        };
+      return RelationLink::Join {
+        join_model: join_model_name(&relation_name),
+        field: field.to_string(),
+        referenced_field: referenced_field.to_string(),
+      };
+    }
+    let link = |edge: &RelationEdge| {
+      Some((
+        edge.scalar_field_name()?.ident_name()?,
+        edge.referenced_model_field_name()?.ident_name()?,
+      ))
+    };
+    if let Some((scalar_field, referenced_field)) = link(edge) {
+      return RelationLink::Forward {
+        scalar_field,
+        referenced_field,
+      };
+    }
+    // Note: Scalar field of the one-side relation is found on the other
+    // edge of the relation.
+    let relation_name = edge.relation_name().str().unwrap();
+    let (scalar_field, referenced_field) = data_model
+      .relations
+      .get(&relation_name)
+      .and_then(|(left, right)| link(left).or_else(|| right.as_ref().and_then(link)))
+      .expect("Relation should have an edge with the scalar field.");
+    RelationLink::Backward {
+      scalar_field,
+      referenced_field,
+    }
+  }
+}
+
+fn join_model_name(relation_name: &str) -> String {
+  format!("{JOIN_MODEL_PREFIX}{relation_name}")
+}
+
+fn field_name(field: &FieldDecl) -> String {
+  field.name.ident_name().unwrap()
+}
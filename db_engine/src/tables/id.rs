@@ -0,0 +1,73 @@
+//! Generators of the IDs, for the `@default(..)` functions of the SDML.
+//! Except UUID v4 & Nano ID, the IDs are time-sortable, i.e. the later ones
+//! are greater, so that the objects ordered by the ID follow the creation order.
+use std::{
+  process,
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    Mutex,
+  },
+};
+
+use rand::Rng;
+
+/// Characters of the Nano ID, URL safe.
+const NANOID_ALPHABET: &[u8; 64] =
+  b"_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Number of the base36 digits of each block of the CUID.
+const CUID_BLOCK_SIZE: u32 = 4;
+
+pub fn uuid_v4() -> String {
+  uuid::Uuid::new_v4().to_string()
+}
+
+pub fn uuid_v7() -> String {
+  uuid::Uuid::now_v7().to_string()
+}
+
+/// ULID, monotonic within the process.
+pub fn ulid() -> String {
+  static GENERATOR: Mutex<ulid::Generator> = Mutex::new(ulid::Generator::new());
+  let mut generator = GENERATOR.lock().unwrap();
+  // Note: Generator fails only if the random part overflows within a millisecond.
+  generator
+    .generate()
+    .unwrap_or_else(|_| ulid::Ulid::new())
+    .to_string()
+}
+
+/// CUID (version 1), `c` followed by the base36 blocks of the timestamp, a counter,
+/// the process fingerprint & random digits.
+pub fn cuid() -> String {
+  static COUNTER: AtomicU64 = AtomicU64::new(0);
+  let block_max = 36u64.pow(CUID_BLOCK_SIZE);
+  let timestamp = chrono::Utc::now().timestamp_millis() as u64;
+  let counter = COUNTER.fetch_add(1, Ordering::Relaxed) % block_max;
+  let fingerprint = process::id() as u64 % block_max;
+  let random = rand::thread_rng().gen_range(0..block_max * block_max);
+  format!(
+    "c{}{}{}{}",
+    base36(timestamp, 2 * CUID_BLOCK_SIZE),
+    base36(counter, CUID_BLOCK_SIZE),
+    base36(fingerprint, CUID_BLOCK_SIZE),
+    base36(random, 2 * CUID_BLOCK_SIZE)
+  )
+}
+
+pub fn nanoid(size: usize) -> String {
+  let mut rng = rand::thread_rng();
+  (0..size)
+    .map(|_| NANOID_ALPHABET[rng.gen_range(0..NANOID_ALPHABET.len())] as char)
+    .collect()
+}
+
+/// Base36 digits of the value, zero padded to the width.
+fn base36(mut value: u64, width: u32) -> String {
+  let mut digits = vec![b'0'; width as usize];
+  for digit in digits.iter_mut().rev() {
+    *digit = b"0123456789abcdefghijklmnopqrstuvwxyz"[(value % 36) as usize];
+    value /= 36;
+  }
+  String::from_utf8(digits).unwrap()
+}
@@ -0,0 +1,16 @@
+//! Objects of the models held in tables, along with the logic to find, filter,
+//! order, paginate & write them. Shared by the data store backends.
+mod id;
+mod table;
+mod txn;
+pub(crate) mod value;
+mod view;
+
+#[cfg(feature = "fdb")]
+pub(crate) use table::Lookup;
+pub(crate) use table::Table;
+#[cfg(feature = "fdb")]
+pub(crate) use txn::Counter;
+pub(crate) use txn::{Counters, Txn};
+pub(crate) use value::Fields;
+pub(crate) use view::{Page, Tables, View};
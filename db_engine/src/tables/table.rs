@@ -0,0 +1,640 @@
+//! Objects of a model, along with the unique & field indexes.
+use std::{
+  collections::{BTreeMap, HashMap, HashSet},
+  ops::Bound,
+  sync::Mutex,
+};
+
+use graphql_value::ConstValue as Value;
+use indexmap::{IndexMap, IndexSet};
+
+use super::value::{self, Fields};
+use crate::{db_crud::ID, model::Model, Error};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Table {
+  /// Objects by their ID, in the insertion order. Objects loaded into the
+  /// partially loaded table are in the ID order.
+  objects: IndexMap<ID, Fields>,
+  /// Map<unique field or compound key, Map<value key, object ID>>.
+  unique: HashMap<String, HashMap<String, ID>>,
+  /// Map<indexed field or compound key, Map<value key, object IDs>>.
+  indexes: HashMap<String, HashMap<String, IndexSet<ID>>>,
+  /// Map<indexed field, Map<lowercase string value, object IDs>>, to find the
+  /// objects ignoring the case. Sorted, so that the values with a prefix are
+  /// found as well.
+  lowercase: HashMap<String, BTreeMap<String, IndexSet<ID>>>,
+  /// Lookups loaded into the partially loaded table, `None` if all the objects
+  /// are loaded.
+  loaded: Option<Loaded>,
+}
+
+/// Lookup of the objects, which a partially loaded table doesn't hold yet.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Lookup {
+  /// Object of the ID.
+  Object(ID),
+  /// Objects whose indexed field (or compound key) holds the value.
+  Value { name: String, value: Value },
+  /// All the objects.
+  All,
+}
+
+/// Lookups loaded into a partially loaded table.
+#[derive(Debug, Default)]
+struct Loaded {
+  /// IDs looked up, of the objects found or not.
+  ids: HashSet<ID>,
+  /// Values looked up, as (indexed field or compound key, value key).
+  values: HashSet<(String, String)>,
+  /// Lookups made but not loaded, answered from the objects loaded so far.
+  missing: Mutex<Vec<Lookup>>,
+}
+
+impl Loaded {
+  fn miss(&self, lookup: Lookup) {
+    self.missing.lock().unwrap().push(lookup);
+  }
+}
+
+impl Clone for Loaded {
+  fn clone(&self) -> Self {
+    Loaded {
+      ids: self.ids.clone(),
+      values: self.values.clone(),
+      missing: Mutex::new(self.missing.lock().unwrap().clone()),
+    }
+  }
+}
+
+/// Partial loading, for the data stores loading the objects as they're looked up.
+#[cfg_attr(not(feature = "fdb"), allow(dead_code))]
+impl Table {
+  /// Table holding none of the objects, which are loaded as they are looked up.
+  /// Lookups of the objects not loaded are recorded as missing, to be loaded
+  /// before looking them up again. See [`Table::take_missing`].
+  /// Note: Objects are kept in the ID order, i.e. in the order of a data store
+  /// listing the objects by their keys.
+  pub fn partial() -> Self {
+    Table {
+      loaded: Some(Loaded::default()),
+      ..Default::default()
+    }
+  }
+
+  /// Loads the objects found by the lookup into the partially loaded table.
+  /// Note: Objects already loaded are kept as they are, as they may be changed.
+  pub fn load(
+    &mut self,
+    model: &Model,
+    lookup: Lookup,
+    objects: Vec<Fields>,
+  ) -> Result<(), Error> {
+    let Some(loaded) = &mut self.loaded else {
+      return Ok(());
+    };
+    match lookup {
+      Lookup::Object(id) => {
+        loaded.ids.insert(id);
+      }
+      Lookup::Value { name, value } => {
+        loaded.values.insert((name, value::key(&value)));
+      }
+      Lookup::All => self.loaded = None,
+    }
+    for fields in objects {
+      let Some(id) = model.object_id(&fields) else {
+        return Err(Error::Io(format!(
+          "Stored {} object has no ID.",
+          model.name
+        )));
+      };
+      if let Some(loaded) = &mut self.loaded {
+        loaded.ids.insert(id.clone());
+      }
+      if !self.objects.contains_key(&id) {
+        self.add_to_indexes(model, &id, &fields);
+        self.objects.insert_sorted(id, fields);
+      }
+    }
+    Ok(())
+  }
+
+  /// Objects loaded, in the insertion order.
+  pub fn iter(&self) -> impl Iterator<Item = (&ID, &Fields)> {
+    self.objects.iter()
+  }
+
+  /// Takes the lookups recorded as missing by the partially loaded table.
+  pub fn take_missing(&self) -> Vec<Lookup> {
+    let Some(loaded) = &self.loaded else {
+      return vec![];
+    };
+    let mut lookups = Vec::new();
+    for lookup in std::mem::take(&mut *loaded.missing.lock().unwrap()) {
+      if !lookups.contains(&lookup) {
+        lookups.push(lookup);
+      }
+    }
+    lookups
+  }
+}
+
+impl Table {
+  pub fn get(&self, id: &ID) -> Option<&Fields> {
+    let fields = self.objects.get(id);
+    if fields.is_none() {
+      self.check_object(id);
+    }
+    fields
+  }
+
+  /// All the objects, in the insertion order. Partially loaded table records the
+  /// lookup of all the objects as missing.
+  pub fn scan(&self) -> impl Iterator<Item = (&ID, &Fields)> {
+    if let Some(loaded) = &self.loaded {
+      loaded.miss(Lookup::All);
+    }
+    self.objects.iter()
+  }
+
+  /// IDs of the objects whose field holds the value (or holds it in the list).
+  /// Value of a compound key is the list of its field values.
+  pub fn find(&self, model: &Model, field_name: &str, value: &Value) -> Vec<ID> {
+    if let Some(compound_key) = model.compound_keys.get(field_name) {
+      self.check_value(model, field_name, value);
+      return self.find_indexed(field_name, compound_key.is_unique, value);
+    }
+    let Some(field) = model.fields.get(field_name) else {
+      return vec![];
+    };
+    if field.is_unique || field.is_indexed {
+      self.check_value(model, field_name, value);
+      return self.find_indexed(field_name, field.is_unique, value);
+    }
+    self
+      .scan()
+      .filter(|(_, fields)| {
+        fields.get(field_name).is_some_and(|field_value| {
+          value::items(field_value)
+            .iter()
+            .any(|item| value::equals(item, value))
+        })
+      })
+      .map(|(id, _)| id.clone())
+      .collect()
+  }
+
+  /// IDs of the objects whose string field holds the value ignoring the case (or
+  /// holds it in the list), in the insertion order.
+  pub fn find_ignoring_case(
+    &self,
+    model: &Model,
+    field_name: &str,
+    value: &str,
+  ) -> Vec<ID> {
+    self.find_lowercase(model, field_name, &value.to_lowercase(), false)
+  }
+
+  /// IDs of the objects whose string field starts with the prefix ignoring the
+  /// case (or holds such a string in the list), in the insertion order.
+  pub fn find_prefix_ignoring_case(
+    &self,
+    model: &Model,
+    field_name: &str,
+    prefix: &str,
+  ) -> Vec<ID> {
+    self.find_lowercase(model, field_name, &prefix.to_lowercase(), true)
+  }
+
+  /// IDs of the objects whose string field holds the lowercase value, or a value
+  /// starting with it if `is_prefix`. The lowercase index is searched for the
+  /// indexed fields, all the objects are searched otherwise.
+  fn find_lowercase(
+    &self,
+    model: &Model,
+    field_name: &str,
+    value: &str,
+    is_prefix: bool,
+  ) -> Vec<ID> {
+    let matches = |key: &str| {
+      if is_prefix {
+        key.starts_with(value)
+      } else {
+        key == value
+      }
+    };
+    // Note: Partially loaded table searches all the objects, as the lowercase
+    // values can't be looked up.
+    let mut ids = match model.fields.get(field_name) {
+      Some(field) if field.is_indexed && self.loaded.is_none() => self
+        .lowercase
+        .get(field_name)
+        .into_iter()
+        .flat_map(|index| {
+          index
+            .range::<str, _>((Bound::Included(value), Bound::Unbounded))
+            .take_while(|(key, _)| matches(key))
+            .flat_map(|(_, ids)| ids.iter().cloned())
+        })
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>(),
+      _ => self
+        .scan()
+        .filter(|(_, fields)| {
+          fields.get(field_name).is_some_and(|field_value| {
+            value::items(field_value).iter().any(|item| {
+              value::str_value(item).is_some_and(|item| matches(&item.to_lowercase()))
+            })
+          })
+        })
+        .map(|(id, _)| id.clone())
+        .collect(),
+    };
+    ids.sort_by_key(|id| self.objects.get_index_of(id));
+    ids
+  }
+
+  /// IDs of the objects whose field holds any of the values (or holds them in the
+  /// list), in the insertion order.
+  pub fn find_any(&self, model: &Model, field_name: &str, values: &[Value]) -> Vec<ID> {
+    let mut ids = values
+      .iter()
+      .flat_map(|value| self.find(model, field_name, value))
+      .collect::<IndexSet<_>>()
+      .into_iter()
+      .collect::<Vec<_>>();
+    ids.sort_by_key(|id| self.objects.get_index_of(id));
+    ids
+  }
+
+  /// IDs of the objects found in the unique or field index.
+  fn find_indexed(&self, name: &str, is_unique: bool, value: &Value) -> Vec<ID> {
+    let key = value::key(value);
+    if is_unique {
+      return self
+        .unique
+        .get(name)
+        .and_then(|index| index.get(&key))
+        .cloned()
+        .into_iter()
+        .collect();
+    }
+    let mut ids = self
+      .indexes
+      .get(name)
+      .and_then(|index| index.get(&key))
+      .map(|ids| ids.iter().cloned().collect::<Vec<_>>())
+      .unwrap_or_default();
+    ids.sort_by_key(|id| self.objects.get_index_of(id));
+    ids
+  }
+
+  /// Inserts a new object, returns its ID.
+  pub fn insert(&mut self, model: &Model, fields: Fields) -> Result<ID, Error> {
+    let Some(id) = model.object_id(&fields) else {
+      return Err(Error::InvalidInput(format!(
+        r#"Value of the ID field "{}.{}" is missing."#,
+        model.name, model.id_field
+      )));
+    };
+    if self.get(&id).is_some() {
+      return Err(Error::UniqueViolation {
+        model: model.name.clone(),
+        field: model.id_field.clone(),
+        value: id.to_string(),
+      });
+    }
+    self.check_unique(model, &id, &fields)?;
+    self.add_to_indexes(model, &id, &fields);
+    self.objects.insert(id.clone(), fields);
+    Ok(id)
+  }
+
+  /// Replaces the fields of an existing object.
+  pub fn replace(&mut self, model: &Model, id: &ID, fields: Fields) -> Result<(), Error> {
+    let Some(existing) = self.objects.get(id) else {
+      return Err(Error::NotFound {
+        model: model.name.clone(),
+      });
+    };
+    if model.object_id(&fields).is_some_and(|new_id| &new_id != id) {
+      return Err(Error::InvalidInput(format!(
+        r#"Value of the ID field "{}.{}" can't be changed."#,
+        model.name, model.id_field
+      )));
+    }
+    self.check_unique(model, id, &fields)?;
+    let existing = existing.clone();
+    self.remove_from_indexes(model, id, &existing);
+    self.add_to_indexes(model, id, &fields);
+    self.objects.insert(id.clone(), fields);
+    Ok(())
+  }
+
+  /// Removes the object, returns its fields.
+  pub fn remove(&mut self, model: &Model, id: &ID) -> Option<Fields> {
+    let fields = self.objects.shift_remove(id)?;
+    self.remove_from_indexes(model, id, &fields);
+    Some(fields)
+  }
+
+  fn check_unique(&self, model: &Model, id: &ID, fields: &Fields) -> Result<(), Error> {
+    for field in model.unique_fields() {
+      let field_value = fields.get(&field.name).unwrap_or(&Value::Null);
+      for item in value::items(field_value) {
+        self.check_value(model, &field.name, item);
+        let Some(index) = self.unique.get(&field.name) else {
+          continue;
+        };
+        if index
+          .get(&value::key(item))
+          .is_some_and(|other_id| other_id != id)
+        {
+          return Err(Error::UniqueViolation {
+            model: model.name.clone(),
+            field: field.name.clone(),
+            value: value::key(item),
+          });
+        }
+      }
+    }
+    for compound_key in model.compound_keys.values() {
+      let (true, Some(key_value)) = (compound_key.is_unique, compound_key.value(fields))
+      else {
+        continue;
+      };
+      self.check_value(model, &compound_key.name, &key_value);
+      let key = value::key(&key_value);
+      if self
+        .unique
+        .get(&compound_key.name)
+        .and_then(|index| index.get(&key))
+        .is_some_and(|other_id| other_id != id)
+      {
+        return Err(Error::UniqueViolation {
+          model: model.name.clone(),
+          field: compound_key.name.clone(),
+          value: key,
+        });
+      }
+    }
+    Ok(())
+  }
+
+  /// Records the lookup of the object as missing, if the partially loaded table
+  /// hasn't loaded it.
+  fn check_object(&self, id: &ID) {
+    if let Some(loaded) = &self.loaded {
+      if !loaded.ids.contains(id) {
+        loaded.miss(Lookup::Object(id.clone()));
+      }
+    }
+  }
+
+  /// Records the lookup of the value as missing, if the partially loaded table
+  /// hasn't loaded the objects holding it. Value of the ID field is looked up as
+  /// the object of the ID.
+  fn check_value(&self, model: &Model, name: &str, value: &Value) {
+    let Some(loaded) = &self.loaded else {
+      return;
+    };
+    if name == model.id_field && !model.has_compound_id() {
+      let id = ID::new(value::key(value));
+      if !self.objects.contains_key(&id) {
+        self.check_object(&id);
+      }
+    } else if !loaded
+      .values
+      .contains(&(name.to_string(), value::key(value)))
+    {
+      loaded.miss(Lookup::Value {
+        name: name.to_string(),
+        value: value.clone(),
+      });
+    }
+  }
+
+  fn add_to_indexes(&mut self, model: &Model, id: &ID, fields: &Fields) {
+    for (name, key) in lowercase_keys(model, fields) {
+      self
+        .lowercase
+        .entry(name.to_string())
+        .or_default()
+        .entry(key)
+        .or_default()
+        .insert(id.clone());
+    }
+    for (name, is_unique, key) in index_keys(model, fields) {
+      if is_unique {
+        self
+          .unique
+          .entry(name.to_string())
+          .or_default()
+          .insert(key, id.clone());
+      } else {
+        self
+          .indexes
+          .entry(name.to_string())
+          .or_default()
+          .entry(key)
+          .or_default()
+          .insert(id.clone());
+      }
+    }
+  }
+
+  fn remove_from_indexes(&mut self, model: &Model, id: &ID, fields: &Fields) {
+    for (name, key) in lowercase_keys(model, fields) {
+      if let Some(ids) = self
+        .lowercase
+        .get_mut(name)
+        .and_then(|index| index.get_mut(&key))
+      {
+        ids.shift_remove(id);
+        if ids.is_empty() {
+          self.lowercase.get_mut(name).unwrap().remove(&key);
+        }
+      }
+    }
+    for (name, is_unique, key) in index_keys(model, fields) {
+      if is_unique {
+        if let Some(index) = self.unique.get_mut(name) {
+          index.remove(&key);
+        }
+      } else if let Some(ids) = self
+        .indexes
+        .get_mut(name)
+        .and_then(|index| index.get_mut(&key))
+      {
+        ids.shift_remove(id);
+        if ids.is_empty() {
+          self.indexes.get_mut(name).unwrap().remove(&key);
+        }
+      }
+    }
+  }
+}
+
+/// Index entries of the object, as (indexed field or compound key, is unique, value key).
+/// Note: An entry per item of the list values, `null` values aren't indexed.
+fn index_keys<'a>(model: &'a Model, fields: &Fields) -> Vec<(&'a str, bool, String)> {
+  let mut keys = Vec::new();
+  for field in model.indexed_fields() {
+    let field_value = fields.get(&field.name).unwrap_or(&Value::Null);
+    for item in value::items(field_value) {
+      keys.push((field.name.as_str(), field.is_unique, value::key(item)));
+    }
+  }
+  for compound_key in model.compound_keys.values() {
+    if let Some(key_value) = compound_key.value(fields) {
+      keys.push((
+        compound_key.name.as_str(),
+        compound_key.is_unique,
+        value::key(&key_value),
+      ));
+    }
+  }
+  keys
+}
+
+/// Lowercase index entries of the object, as (indexed field, lowercase value).
+/// Note: An entry per item of the list values, only the strings are indexed. Every
+/// indexed field (`@id`, `@unique` or `@indexed`) has the entries, as the fields
+/// are searched ignoring the case using the same `is_indexed` check.
+fn lowercase_keys<'a>(model: &'a Model, fields: &Fields) -> Vec<(&'a str, String)> {
+  let mut keys = Vec::new();
+  for field in model.indexed_fields() {
+    let field_value = fields.get(&field.name).unwrap_or(&Value::Null);
+    for item in value::items(field_value) {
+      if let Value::String(item) = item {
+        keys.push((field.name.as_str(), item.to_lowercase()));
+      }
+    }
+  }
+  keys
+}
+
+#[cfg(test)]
+mod tests {
+  use graphql_value::value;
+
+  use super::*;
+  use crate::model;
+
+  #[test]
+  fn test_find_ignoring_case() {
+    let sdml = include_str!("../test_data/test_crud_api_def.sdml");
+    let models = model::models(&sdml_parser::parse(sdml).unwrap());
+    let user = &models["User"];
+    let mut table = Table::default();
+    for (id, email, nick_names) in [
+      ("1", "Carol@X.com", value!(["Cc"])),
+      ("2", "alice@x.com", value!(["Al", "ali"])),
+      ("3", "bob@x.com", value!(["AL"])),
+    ] {
+      let fields = Fields::from([
+        ("userId".to_string(), value!(id)),
+        ("email".to_string(), value!(email)),
+        ("nickNames".to_string(), nick_names),
+      ]);
+      table.insert(user, fields).unwrap();
+    }
+
+    // Note: Both the unique & the indexed list fields have the lowercase index.
+    assert_eq!(
+      table.lowercase["email"].keys().collect::<Vec<_>>(),
+      ["alice@x.com", "bob@x.com", "carol@x.com"]
+    );
+    assert_eq!(
+      table.find_ignoring_case(user, "email", "CAROL@x.com"),
+      [ID::new("1")]
+    );
+    assert_eq!(
+      table.find_ignoring_case(user, "nickNames", "al"),
+      [ID::new("2"), ID::new("3")]
+    );
+    assert_eq!(
+      table.find_prefix_ignoring_case(user, "nickNames", "a"),
+      [ID::new("2"), ID::new("3")]
+    );
+    assert_eq!(
+      table.find_prefix_ignoring_case(user, "email", "B"),
+      [ID::new("3")]
+    );
+
+    table.remove(user, &ID::new("3"));
+    assert_eq!(
+      table.find_ignoring_case(user, "nickNames", "AL"),
+      [ID::new("2")]
+    );
+    assert!(!table.lowercase["email"].contains_key("bob@x.com"));
+  }
+
+  #[test]
+  fn test_partial_table() {
+    let sdml = include_str!("../test_data/test_crud_api_def.sdml");
+    let models = model::models(&sdml_parser::parse(sdml).unwrap());
+    let user = &models["User"];
+    let user_fields = |id: &str, email: &str| {
+      Fields::from([
+        ("userId".to_string(), value!(id)),
+        ("email".to_string(), value!(email)),
+      ])
+    };
+    let mut table = Table::partial();
+
+    // Lookups of the objects not loaded are missing, until they're loaded.
+    assert!(table.find(user, "email", &value!("alice@x.com")).is_empty());
+    assert!(table.get(&ID::new("3")).is_none());
+    let missing = table.take_missing();
+    assert_eq!(
+      missing,
+      [
+        super::Lookup::Value {
+          name: "email".to_string(),
+          value: value!("alice@x.com")
+        },
+        super::Lookup::Object(ID::new("3"))
+      ]
+    );
+    let mut missing = missing.into_iter();
+    let alice = user_fields("2", "alice@x.com");
+    table
+      .load(user, missing.next().unwrap(), vec![alice])
+      .unwrap();
+    table.load(user, missing.next().unwrap(), vec![]).unwrap();
+    assert_eq!(
+      table.find(user, "email", &value!("alice@x.com")),
+      [ID::new("2")]
+    );
+    assert!(table.get(&ID::new("3")).is_none());
+    assert!(table.take_missing().is_empty());
+
+    // Unique values of the objects inserted are looked up as well.
+    assert!(matches!(
+      table.insert(user, user_fields("1", "alice@x.com")),
+      Err(Error::UniqueViolation { .. })
+    ));
+    table.take_missing();
+    table.insert(user, user_fields("4", "dan@x.com")).unwrap();
+    assert_eq!(
+      table.take_missing(),
+      [
+        super::Lookup::Object(ID::new("4")),
+        super::Lookup::Value {
+          name: "email".to_string(),
+          value: value!("dan@x.com")
+        }
+      ]
+    );
+
+    // Search of a field not indexed looks up all the objects.
+    table.find(user, "name", &value!("Dan"));
+    assert_eq!(table.take_missing(), [super::Lookup::All]);
+    table.load(user, super::Lookup::All, vec![]).unwrap();
+    table.find(user, "name", &value!("Dan"));
+    assert!(table.take_missing().is_empty());
+  }
+}
@@ -0,0 +1,1030 @@
+//! Writes to the store, made within a mutation.
+use std::collections::HashMap;
+
+use graphql_value::ConstValue as Value;
+use sdml_parser::types::ReferentialAction;
+
+use super::{
+  id,
+  value::{self, Fields},
+  view::{Tables, View},
+  Table,
+};
+use crate::{
+  db_crud::{
+    ConnectPosition, ConnectedObjects, ObjectCreateInput, ObjectUpdateInput,
+    ObjectUpsertInput, ObjectWhereUniqueInput, RelationCreateInput, RelationUpdateInput,
+    ID,
+  },
+  model::{Field, FieldDefault, Model, Models, Relation, RelationLink},
+  Error,
+};
+
+/// Counters of the store, to generate the `auto()` IDs & the `sequence()` values.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Counters {
+  /// Next auto generated ID, shared by all the models.
+  pub next_id: u64,
+  /// Last value of the `sequence()` fields, by the model & field name.
+  pub sequences: HashMap<(String, String), i64>,
+  /// Values reserved for the counters, `None` if they count without a limit.
+  pub reserved: Option<Reserved>,
+}
+
+/// Counter of the store.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Counter {
+  /// Auto generated IDs.
+  Id,
+  /// Values of the `sequence()` field, by the model & field name.
+  Sequence(String, String),
+}
+
+/// Values reserved for the counters, by a data store sharing them between the
+/// processes. Values beyond the reserved ones are generated, but the counters
+/// run out of values, so that the store reserves more and writes again.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Reserved {
+  /// Last auto generated ID reserved.
+  pub last_id: u64,
+  /// Last value reserved for the `sequence()` fields, by the model & field name.
+  pub sequences: HashMap<(String, String), i64>,
+  /// Counters which ran out of the reserved values.
+  pub exhausted: Vec<Counter>,
+}
+
+impl Default for Counters {
+  fn default() -> Self {
+    Counters {
+      next_id: 1,
+      sequences: HashMap::new(),
+      reserved: None,
+    }
+  }
+}
+
+#[cfg_attr(not(feature = "fdb"), allow(dead_code))]
+impl Counters {
+  /// Counters counting only the values reserved, none at first.
+  /// See [`Counters::reserve`].
+  pub fn reserved() -> Self {
+    Counters {
+      reserved: Some(Reserved::default()),
+      ..Default::default()
+    }
+  }
+
+  /// Counts the counter from `first` up to the `last` value reserved.
+  pub fn reserve(&mut self, counter: &Counter, first: i64, last: i64) {
+    let reserved = self.reserved.get_or_insert_with(Default::default);
+    match counter {
+      Counter::Id => {
+        self.next_id = first as u64;
+        reserved.last_id = last as u64;
+      }
+      Counter::Sequence(model_name, field_name) => {
+        let key = (model_name.clone(), field_name.clone());
+        self.sequences.insert(key.clone(), first - 1);
+        reserved.sequences.insert(key, last);
+      }
+    }
+  }
+
+  /// Takes the counters which ran out of the reserved values.
+  pub fn take_exhausted(&mut self) -> Vec<Counter> {
+    self
+      .reserved
+      .as_mut()
+      .map(|reserved| std::mem::take(&mut reserved.exhausted))
+      .unwrap_or_default()
+  }
+}
+
+impl Counters {
+  fn next_id(&mut self) -> u64 {
+    let id = self.next_id;
+    self.next_id += 1;
+    if let Some(reserved) = &mut self.reserved {
+      if id > reserved.last_id {
+        reserved.exhaust(Counter::Id);
+      }
+    }
+    id
+  }
+
+  fn next_sequence(&mut self, model_name: &str, field_name: &str) -> i64 {
+    let key = (model_name.to_string(), field_name.to_string());
+    let sequence = self.sequences.entry(key.clone()).or_default();
+    *sequence += 1;
+    let sequence = *sequence;
+    if let Some(reserved) = &mut self.reserved {
+      if sequence > reserved.sequences.get(&key).copied().unwrap_or_default() {
+        reserved.exhaust(Counter::Sequence(key.0, key.1));
+      }
+    }
+    sequence
+  }
+
+  fn is_exhausted(&self) -> bool {
+    self
+      .reserved
+      .as_ref()
+      .is_some_and(|reserved| !reserved.exhausted.is_empty())
+  }
+}
+
+impl Reserved {
+  fn exhaust(&mut self, counter: Counter) {
+    if !self.exhausted.contains(&counter) {
+      self.exhausted.push(counter);
+    }
+  }
+}
+
+/// Transaction of a mutation. Changes are made directly to the tables,
+/// it is up to the caller to roll them back when the mutation fails.
+pub(crate) struct Txn<'a> {
+  pub models: &'a Models,
+  pub tables: &'a mut Tables,
+  pub counters: &'a mut Counters,
+  /// References left in place by the `NoAction` referential actions,
+  /// checked when the transaction is committed.
+  deferred: Vec<DeferredReference>,
+  /// Values generated by the earlier runs of the transaction, see [`Txn::replaying`].
+  replay: Option<&'a mut Vec<String>>,
+  /// Number of the values generated by this run.
+  generated: usize,
+}
+
+/// Value referenced through a relation, which was deleted or updated.
+struct DeferredReference {
+  model_name: String,
+  relation_name: String,
+  value: Value,
+  is_update: bool,
+}
+
+impl<'a> Txn<'a> {
+  pub fn new(
+    models: &'a Models,
+    tables: &'a mut Tables,
+    counters: &'a mut Counters,
+  ) -> Self {
+    Txn {
+      models,
+      tables,
+      counters,
+      deferred: Vec::new(),
+      replay: None,
+      generated: 0,
+    }
+  }
+
+  /// Replays the values generated by the earlier runs of the transaction, i.e. the
+  /// counted, random & timestamp values, recording the ones generated beyond them.
+  /// So that running it again, on more of the objects loaded into the partially
+  /// loaded tables, looks up the same values.
+  #[cfg_attr(not(feature = "fdb"), allow(dead_code))]
+  pub fn replaying(mut self, replay: &'a mut Vec<String>) -> Self {
+    self.replay = Some(replay);
+    self
+  }
+
+  /// Checks the references left by the `NoAction` referential actions, fails if
+  /// any object still references a value which no longer exists.
+  pub fn commit(&self) -> Result<(), Error> {
+    let view = self.view();
+    for deferred in &self.deferred {
+      let other = &self.models[&deferred.model_name];
+      let relation = &other.relations[&deferred.relation_name];
+      let RelationLink::Forward {
+        scalar_field,
+        referenced_field,
+      } = &relation.link
+      else {
+        continue;
+      };
+      let model = &self.models[&relation.model];
+      if !view
+        .table(&model.name)
+        .find(model, referenced_field, &deferred.value)
+        .is_empty()
+        || view
+          .table(&other.name)
+          .find(other, scalar_field, &deferred.value)
+          .is_empty()
+      {
+        continue;
+      }
+      return Err(Error::RelationViolation(format!(
+        r#"{} object can't be {}, it is referenced by "{}.{}"."#,
+        model.name,
+        if deferred.is_update {
+          "updated"
+        } else {
+          "deleted"
+        },
+        other.name,
+        relation.name
+      )));
+    }
+    Ok(())
+  }
+}
+
+impl Txn<'_> {
+  pub fn view(&self) -> View<'_> {
+    View {
+      models: self.models,
+      tables: self.tables,
+    }
+  }
+
+  /// Finds the object using the unique filter, fails if it's not found.
+  pub fn find(
+    &self,
+    model: &Model,
+    r#where: &ObjectWhereUniqueInput,
+  ) -> Result<ID, Error> {
+    self
+      .view()
+      .find_unique(model, r#where)?
+      .ok_or_else(|| Error::NotFound {
+        model: model.name.clone(),
+      })
+  }
+
+  /// Creates a new object, `linked` fields are the ones set by the relation
+  /// through which the object is created.
+  pub fn create(
+    &mut self,
+    model: &Model,
+    data: &ObjectCreateInput,
+    linked: Fields,
+  ) -> Result<ID, Error> {
+    let now = self.now();
+    let mut fields = Fields::new();
+    for field in model.fields.values() {
+      let value = match data.fields.get(&field.name) {
+        _ if field.is_updated_at => Value::String(now.clone()),
+        Some(value) => value.clone(),
+        None => self.default_value(model, field, &now),
+      };
+      fields.insert(field.name.clone(), value);
+    }
+    fields.extend(linked);
+    for name in data.fields.keys() {
+      if !model.fields.contains_key(name) {
+        return Err(unknown_field(model, name));
+      }
+    }
+
+    let id = self.table(model).insert(model, fields)?;
+    for (name, input) in &data.relations {
+      let relation = model_relation(model, name)?;
+      self.create_relation(model, &id, relation, input)?;
+    }
+    self.check_required(model, &id)?;
+    Ok(id)
+  }
+
+  /// Updates the object's fields & relations.
+  pub fn update(
+    &mut self,
+    model: &Model,
+    id: &ID,
+    data: &ObjectUpdateInput,
+  ) -> Result<(), Error> {
+    let old_fields = self.fields(model, id)?;
+    let mut fields = old_fields.clone();
+    for (name, value) in &data.fields {
+      let field = model
+        .field(name)
+        .ok_or_else(|| unknown_field(model, name))?;
+      fields.insert(field.name.clone(), value.clone());
+    }
+    for (name, data) in &data.composites {
+      let field = model
+        .field(name)
+        .ok_or_else(|| unknown_field(model, name))?;
+      let value = value::merge(fields.get(&field.name).unwrap_or(&Value::Null), data);
+      fields.insert(field.name.clone(), value);
+    }
+    let now = self.now();
+    for field in model.fields.values().filter(|field| field.is_updated_at) {
+      fields.insert(field.name.clone(), Value::String(now.clone()));
+    }
+    self.table(model).replace(model, id, fields.clone())?;
+    self.apply_referential_actions(model, &old_fields, Some(&fields))?;
+    for (name, input) in &data.relations {
+      let relation = model_relation(model, name)?;
+      self.update_relation(model, id, relation, input)?;
+    }
+    self.check_required(model, id)
+  }
+
+  /// Updates the object if found, otherwise creates a new one.
+  pub fn upsert(
+    &mut self,
+    model: &Model,
+    r#where: &ObjectWhereUniqueInput,
+    data: &ObjectUpsertInput,
+  ) -> Result<ID, Error> {
+    match self.view().find_unique(model, r#where)? {
+      Some(id) => {
+        self.update(model, &id, &data.update)?;
+        Ok(id)
+      }
+      None => self.create(model, &data.create, Fields::new()),
+    }
+  }
+
+  /// Deletes the object along with its links in the implicit many-to-many relations,
+  /// and applies the `onDelete` actions to the objects referencing it. Without the
+  /// action, the references to the object are removed, which fails if the object is
+  /// referenced by a required relation.
+  pub fn delete(&mut self, model: &Model, id: &ID) -> Result<Fields, Error> {
+    let fields = self.fields(model, id)?;
+    // Note: Object is removed first, so that the cascades through cyclic relations
+    // stop at it.
+    self.table(model).remove(model, id);
+    for relation in model.relations.values() {
+      if matches!(relation.link, RelationLink::Join { .. }) {
+        for target_id in self.view().related(model, relation, &fields) {
+          self.unlink(model, id, relation, &target_id)?;
+        }
+      }
+    }
+    self.apply_referential_actions(model, &fields, None)?;
+    Ok(fields)
+  }
+
+  /// Deletes the objects, skipping the ones already deleted by the cascades.
+  pub fn delete_many(
+    &mut self,
+    model: &Model,
+    ids: Vec<ID>,
+  ) -> Result<Vec<(Fields, ID)>, Error> {
+    let mut objects = Vec::with_capacity(ids.len());
+    for id in ids {
+      if self.view().table(&model.name).get(&id).is_some() {
+        objects.push((self.delete(model, &id)?, id));
+      }
+    }
+    Ok(objects)
+  }
+
+  /// Applies the referential actions of the relations to the objects referencing the
+  /// object, when it is deleted (`new_fields` is `None`) or its referenced field is updated.
+  fn apply_referential_actions(
+    &mut self,
+    model: &Model,
+    fields: &Fields,
+    new_fields: Option<&Fields>,
+  ) -> Result<(), Error> {
+    let models = self.models;
+    for other in models.values() {
+      for relation in other.relations.values() {
+        let RelationLink::Forward {
+          scalar_field,
+          referenced_field,
+        } = &relation.link
+        else {
+          continue;
+        };
+        let value = fields.get(referenced_field).unwrap_or(&Value::Null);
+        if relation.model != model.name || matches!(value, Value::Null) {
+          continue;
+        }
+        let (action, new_value) = match new_fields {
+          None => (relation.actions.on_delete, None),
+          Some(new_fields) => {
+            let new_value = new_fields.get(referenced_field).unwrap_or(&Value::Null);
+            if value::equals(new_value, value) {
+              continue;
+            }
+            (relation.actions.on_update, Some(new_value))
+          }
+        };
+        for other_id in self
+          .view()
+          .table(&other.name)
+          .find(other, scalar_field, value)
+        {
+          // Note: Object could be already deleted by a cascade.
+          let Ok(mut other_fields) = self.fields(other, &other_id) else {
+            continue;
+          };
+          let reference = match (action, new_value) {
+            (Some(ReferentialAction::NoAction), _) => {
+              self.deferred.push(DeferredReference {
+                model_name: other.name.clone(),
+                relation_name: relation.name.clone(),
+                value: value.clone(),
+                is_update: new_fields.is_some(),
+              });
+              continue;
+            }
+            (None, Some(_)) => continue,
+            (Some(ReferentialAction::Restrict), _) => {
+              return Err(Error::RelationViolation(format!(
+                r#"{} object can't be {}, it is referenced by "{}.{}"."#,
+                model.name,
+                if new_fields.is_some() {
+                  "updated"
+                } else {
+                  "deleted"
+                },
+                other.name,
+                relation.name
+              )))
+            }
+            (Some(ReferentialAction::Cascade), None) => {
+              self.delete(other, &other_id)?;
+              continue;
+            }
+            (Some(ReferentialAction::Cascade), Some(new_value)) => {
+              Some(new_value.clone())
+            }
+            (Some(ReferentialAction::SetNull), _) => Some(Value::Null),
+            (Some(ReferentialAction::SetDefault), _) => {
+              let now = self.now();
+              Some(self.default_value(other, &other.fields[scalar_field], &now))
+            }
+            (None, None) => None,
+          };
+          match reference {
+            Some(reference) => {
+              replace_reference(other, &mut other_fields, scalar_field, value, reference)
+            }
+            None => remove_reference(other, &mut other_fields, scalar_field, value)?,
+          }
+          self.table(other).replace(other, &other_id, other_fields)?;
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Value of the field, when no value is given while creating the object.
+  fn default_value(&mut self, model: &Model, field: &Field, now: &str) -> Value {
+    match &field.default {
+      Some(FieldDefault::Auto) => {
+        Value::String(self.generate(|counters| counters.next_id().to_string()))
+      }
+      Some(FieldDefault::UuidV4) => Value::String(self.generate(|_| id::uuid_v4())),
+      Some(FieldDefault::UuidV7) => Value::String(self.generate(|_| id::uuid_v7())),
+      Some(FieldDefault::Cuid) => Value::String(self.generate(|_| id::cuid())),
+      Some(FieldDefault::Ulid) => Value::String(self.generate(|_| id::ulid())),
+      Some(FieldDefault::NanoId(size)) => {
+        Value::String(self.generate(|_| id::nanoid(*size)))
+      }
+      Some(FieldDefault::Sequence { is_string }) => {
+        let sequence = self.generate(|counters| {
+          counters.next_sequence(&model.name, &field.name).to_string()
+        });
+        if *is_string {
+          // Note: Zero padded to the digits of the largest value, so that the strings
+          // sort in the sequence order.
+          Value::String(format!("{sequence:0>20}"))
+        } else {
+          Value::from(sequence.parse::<i64>().unwrap_or_default())
+        }
+      }
+      Some(FieldDefault::Now) => Value::String(now.to_string()),
+      Some(FieldDefault::Value(value)) => value.clone(),
+      None if field.is_array => Value::List(vec![]),
+      None => Value::Null,
+    }
+  }
+
+  /// Current time, for the `now()` defaults & the `@updatedAt` fields.
+  fn now(&mut self) -> String {
+    self.generate(|_| chrono::Utc::now().to_rfc3339())
+  }
+
+  /// Generates the value, or replays the one generated at the same point by an
+  /// earlier run of the transaction.
+  /// Note: Values generated once the counters ran out of the reserved values aren't
+  /// recorded, as the transaction runs again with more values reserved.
+  fn generate(&mut self, generate: impl FnOnce(&mut Counters) -> String) -> String {
+    let Some(replay) = &mut self.replay else {
+      return generate(self.counters);
+    };
+    if self.generated == replay.len() {
+      let value = generate(self.counters);
+      if self.counters.is_exhausted() {
+        return value;
+      }
+      replay.push(value);
+    }
+    self.generated += 1;
+    replay[self.generated - 1].clone()
+  }
+
+  fn create_relation(
+    &mut self,
+    model: &Model,
+    id: &ID,
+    relation: &Relation,
+    input: &RelationCreateInput,
+  ) -> Result<(), Error> {
+    let target = self.target(relation);
+    for r#where in &input.connect {
+      let target_id = self.find(&target, r#where)?;
+      self.link(model, id, relation, &target_id, &ConnectPosition::End)?;
+    }
+    for data in &input.create {
+      self.create_linked(model, id, relation, data)?;
+    }
+    Ok(())
+  }
+
+  fn update_relation(
+    &mut self,
+    model: &Model,
+    id: &ID,
+    relation: &Relation,
+    input: &RelationUpdateInput,
+  ) -> Result<(), Error> {
+    let target = self.target(relation);
+    let related =
+      |txn: &Self| Ok(txn.view().related(model, relation, &txn.fields(model, id)?));
+    // Connected objects to disconnect or delete.
+    let connected = |txn: &Self, objects: &ConnectedObjects| match objects {
+      ConnectedObjects::Connected => related(txn),
+      ConnectedObjects::Unique(filters) => filters
+        .iter()
+        .map(|r#where| txn.find_related(model, id, relation, r#where))
+        .collect(),
+    };
+
+    if let Some(set) = &input.set {
+      for target_id in related(self)? {
+        self.unlink(model, id, relation, &target_id)?;
+      }
+      for r#where in set {
+        let target_id = self.find(&target, r#where)?;
+        self.link(model, id, relation, &target_id, &ConnectPosition::End)?;
+      }
+    }
+    if let Some(disconnect) = &input.disconnect {
+      for target_id in connected(self, disconnect)? {
+        self.unlink(model, id, relation, &target_id)?;
+      }
+    }
+    if let Some(delete) = &input.delete {
+      for target_id in connected(self, delete)? {
+        self.delete(&target, &target_id)?;
+      }
+    }
+    for connect in &input.connect {
+      let target_id = self.find(&target, &connect.r#where)?;
+      self.link(model, id, relation, &target_id, &connect.position)?;
+    }
+    for data in &input.create {
+      self.create_linked(model, id, relation, data)?;
+    }
+    for update in &input.update {
+      let target_id = self.find_related(model, id, relation, &update.r#where)?;
+      self.update(&target, &target_id, &update.data)?;
+    }
+    for upsert in &input.upsert {
+      match self
+        .view()
+        .find_unique(&target, &upsert.r#where)?
+        .filter(|target_id| related(self).is_ok_and(|ids| ids.contains(target_id)))
+      {
+        Some(target_id) => self.update(&target, &target_id, &upsert.data.update)?,
+        None => self.create_linked(model, id, relation, &upsert.data.create)?,
+      }
+    }
+    Ok(())
+  }
+
+  /// Creates a new object connected to the object through the relation.
+  fn create_linked(
+    &mut self,
+    model: &Model,
+    id: &ID,
+    relation: &Relation,
+    data: &ObjectCreateInput,
+  ) -> Result<(), Error> {
+    let target = self.target(relation);
+    match &relation.link {
+      RelationLink::Forward { .. } | RelationLink::Join { .. } => {
+        let target_id = self.create(&target, data, Fields::new())?;
+        self.link(model, id, relation, &target_id, &ConnectPosition::End)
+      }
+      RelationLink::Backward {
+        scalar_field,
+        referenced_field,
+      } => {
+        if !relation.is_array {
+          for target_id in self
+            .view()
+            .related(model, relation, &self.fields(model, id)?)
+          {
+            self.unlink(model, id, relation, &target_id)?;
+          }
+        }
+        let value = self.field_value(model, id, referenced_field)?;
+        let value = if target.fields[scalar_field].is_array {
+          Value::List(vec![value])
+        } else {
+          value
+        };
+        self.create(&target, data, Fields::from([(scalar_field.clone(), value)]))?;
+        Ok(())
+      }
+    }
+  }
+
+  /// Connects the target object to the object through the relation.
+  fn link(
+    &mut self,
+    model: &Model,
+    id: &ID,
+    relation: &Relation,
+    target_id: &ID,
+    position: &ConnectPosition,
+  ) -> Result<(), Error> {
+    let target = self.target(relation);
+    match &relation.link {
+      RelationLink::Forward {
+        scalar_field,
+        referenced_field,
+      } => {
+        let value = self.field_value(&target, target_id, referenced_field)?;
+        let mut fields = self.fields(model, id)?;
+        if model.fields[scalar_field].is_array {
+          let index = self.position_index(
+            &target,
+            referenced_field,
+            &fields[scalar_field],
+            position,
+          )?;
+          add_reference(&mut fields, scalar_field, value, index);
+        } else {
+          fields.insert(scalar_field.clone(), value);
+        }
+        self.table(model).replace(model, id, fields)?;
+        self.sync_partner(model, id, relation, target_id, true)
+      }
+      RelationLink::Backward {
+        scalar_field,
+        referenced_field,
+      } => {
+        if !relation.is_array {
+          for other_id in self
+            .view()
+            .related(model, relation, &self.fields(model, id)?)
+          {
+            if &other_id != target_id {
+              self.unlink(model, id, relation, &other_id)?;
+            }
+          }
+        }
+        let value = self.field_value(model, id, referenced_field)?;
+        let mut target_fields = self.fields(&target, target_id)?;
+        if target.fields[scalar_field].is_array {
+          add_reference(&mut target_fields, scalar_field, value, None);
+        } else {
+          target_fields.insert(scalar_field.clone(), value);
+        }
+        self
+          .table(&target)
+          .replace(&target, target_id, target_fields)
+      }
+      // Note: Objects connected through the join model are listed in the order of
+      // their connection, so the position isn't taken into account.
+      RelationLink::Join {
+        join_model,
+        field,
+        referenced_field,
+      } => {
+        let join = self.view().model(join_model).clone();
+        let link = join_link(field, id, referenced_field, target_id);
+        let link_id = join.object_id(&link).expect("Join link should have an ID.");
+        if self.view().table(join_model).get(&link_id).is_none() {
+          self.table(&join).insert(&join, link)?;
+        }
+        Ok(())
+      }
+    }
+  }
+
+  /// Disconnects the target object from the object in the relation.
+  fn unlink(
+    &mut self,
+    model: &Model,
+    id: &ID,
+    relation: &Relation,
+    target_id: &ID,
+  ) -> Result<(), Error> {
+    let target = self.target(relation);
+    match &relation.link {
+      RelationLink::Forward {
+        scalar_field,
+        referenced_field,
+      } => {
+        let value = self.field_value(&target, target_id, referenced_field)?;
+        let mut fields = self.fields(model, id)?;
+        remove_reference(model, &mut fields, scalar_field, &value)?;
+        self.table(model).replace(model, id, fields)?;
+        self.sync_partner(model, id, relation, target_id, false)
+      }
+      RelationLink::Backward {
+        scalar_field,
+        referenced_field,
+      } => {
+        let value = self.field_value(model, id, referenced_field)?;
+        let mut target_fields = self.fields(&target, target_id)?;
+        remove_reference(&target, &mut target_fields, scalar_field, &value)?;
+        self
+          .table(&target)
+          .replace(&target, target_id, target_fields)
+      }
+      RelationLink::Join {
+        join_model,
+        field,
+        referenced_field,
+      } => {
+        let join = self.view().model(join_model).clone();
+        let link = join_link(field, id, referenced_field, target_id);
+        let link_id = join.object_id(&link).expect("Join link should have an ID.");
+        self.table(&join).remove(&join, &link_id);
+        Ok(())
+      }
+    }
+  }
+
+  /// Keeps the scalar lists on both sides of a many-to-many relation in sync,
+  /// as either of them is enough to find the connected objects.
+  fn sync_partner(
+    &mut self,
+    model: &Model,
+    id: &ID,
+    relation: &Relation,
+    target_id: &ID,
+    connect: bool,
+  ) -> Result<(), Error> {
+    let target = self.target(relation);
+    let Some(RelationLink::Forward {
+      scalar_field,
+      referenced_field,
+    }) = target
+      .relations
+      .values()
+      .find(|partner| {
+        partner.relation_name == relation.relation_name
+          && partner.model == model.name
+          && partner.name != relation.name
+      })
+      .map(|partner| &partner.link)
+    else {
+      return Ok(());
+    };
+    if !target.fields[scalar_field].is_array {
+      return Ok(());
+    }
+    let value = self.field_value(model, id, referenced_field)?;
+    let mut target_fields = self.fields(&target, target_id)?;
+    if connect {
+      add_reference(&mut target_fields, scalar_field, value, None);
+    } else {
+      remove_reference(&target, &mut target_fields, scalar_field, &value)?;
+    }
+    self
+      .table(&target)
+      .replace(&target, target_id, target_fields)
+  }
+
+  /// Finds the object connected through the relation using the unique filter.
+  fn find_related(
+    &self,
+    model: &Model,
+    id: &ID,
+    relation: &Relation,
+    r#where: &ObjectWhereUniqueInput,
+  ) -> Result<ID, Error> {
+    let target = self.target(relation);
+    let target_id = self.find(&target, r#where)?;
+    if self
+      .view()
+      .related(model, relation, &self.fields(model, id)?)
+      .contains(&target_id)
+    {
+      Ok(target_id)
+    } else {
+      Err(Error::NotFound {
+        model: target.name.clone(),
+      })
+    }
+  }
+
+  /// Index in the list of references, to connect an object at the position.
+  fn position_index(
+    &self,
+    target: &Model,
+    referenced_field: &str,
+    references: &Value,
+    position: &ConnectPosition,
+  ) -> Result<Option<usize>, Error> {
+    let (target_id, offset) = match position {
+      ConnectPosition::Start => return Ok(Some(0)),
+      ConnectPosition::End => return Ok(None),
+      ConnectPosition::Before(target_id) => (target_id, 0),
+      ConnectPosition::After(target_id) => (target_id, 1),
+    };
+    let value = self.field_value(target, target_id, referenced_field)?;
+    value::items(references)
+      .iter()
+      .position(|reference| value::equals(reference, &value))
+      .map(|index| Some(index + offset))
+      .ok_or_else(|| Error::NotFound {
+        model: target.name.clone(),
+      })
+  }
+
+  /// Checks that the required fields of the object have a value.
+  fn check_required(&self, model: &Model, id: &ID) -> Result<(), Error> {
+    let fields = self.fields(model, id)?;
+    match model
+      .fields
+      .values()
+      .find(|field| !field.is_optional && matches!(fields[&field.name], Value::Null))
+    {
+      Some(field) => Err(Error::InvalidInput(format!(
+        r#"Value of the required field "{}.{}" is missing."#,
+        model.name, field.name
+      ))),
+      None => Ok(()),
+    }
+  }
+
+  fn target(&self, relation: &Relation) -> std::sync::Arc<Model> {
+    self.view().model(&relation.model).clone()
+  }
+
+  fn table(&mut self, model: &Model) -> &mut Table {
+    self
+      .tables
+      .get_mut(&model.name)
+      .expect("Every model should have a table.")
+  }
+
+  fn fields(&self, model: &Model, id: &ID) -> Result<Fields, Error> {
+    self.view().fields(model, id).cloned()
+  }
+
+  fn field_value(
+    &self,
+    model: &Model,
+    id: &ID,
+    field_name: &str,
+  ) -> Result<Value, Error> {
+    Ok(
+      self
+        .view()
+        .fields(model, id)?
+        .get(field_name)
+        .cloned()
+        .unwrap_or(Value::Null),
+    )
+  }
+}
+
+/// Object of the join model, linking the object to the target object.
+fn join_link(field: &str, id: &ID, referenced_field: &str, target_id: &ID) -> Fields {
+  Fields::from([
+    (field.to_string(), Value::from(id.clone())),
+    (referenced_field.to_string(), Value::from(target_id.clone())),
+  ])
+}
+
+/// Adds the reference to the list of references, if it's not there already.
+fn add_reference(
+  fields: &mut Fields,
+  field_name: &str,
+  value: Value,
+  index: Option<usize>,
+) {
+  let mut references = value::items(&fields[field_name]).to_vec();
+  if references
+    .iter()
+    .any(|reference| value::equals(reference, &value))
+  {
+    return;
+  }
+  match index {
+    Some(index) => references.insert(index.min(references.len()), value),
+    None => references.push(value),
+  }
+  fields.insert(field_name.to_string(), Value::List(references));
+}
+
+/// Replaces the reference in the field with the given one, `null` removes it from the list.
+fn replace_reference(
+  model: &Model,
+  fields: &mut Fields,
+  field_name: &str,
+  value: &Value,
+  reference: Value,
+) {
+  let field_value = if model.fields[field_name].is_array {
+    Value::List(
+      value::items(&fields[field_name])
+        .iter()
+        .filter_map(|item| {
+          if !value::equals(item, value) {
+            Some(item.clone())
+          } else if matches!(reference, Value::Null) {
+            None
+          } else {
+            Some(reference.clone())
+          }
+        })
+        .collect(),
+    )
+  } else if value::equals(&fields[field_name], value) {
+    reference
+  } else {
+    return;
+  };
+  fields.insert(field_name.to_string(), field_value);
+}
+
+/// Removes the reference from the field, fails if the field is required.
+fn remove_reference(
+  model: &Model,
+  fields: &mut Fields,
+  field_name: &str,
+  value: &Value,
+) -> Result<(), Error> {
+  let field = &model.fields[field_name];
+  let field_value = if field.is_array {
+    Value::List(
+      value::items(&fields[field_name])
+        .iter()
+        .filter(|reference| !value::equals(reference, value))
+        .cloned()
+        .collect(),
+    )
+  } else if !value::equals(&fields[field_name], value) {
+    return Ok(());
+  } else if field.is_optional {
+    Value::Null
+  } else {
+    return Err(Error::RelationViolation(format!(
+      r#"{} object can't be disconnected, "{}.{}" is required."#,
+      model.name, model.name, field_name
+    )));
+  };
+  fields.insert(field_name.to_string(), field_value);
+  Ok(())
+}
+
+/// Relation field of the given name.
+fn model_relation<'m>(model: &'m Model, name: &str) -> Result<&'m Relation, Error> {
+  model
+    .relations
+    .get(name)
+    .ok_or_else(|| unknown_field(model, name))
+}
+
+fn unknown_field(model: &Model, name: &str) -> Error {
+  Error::InvalidInput(format!(r#"Unknown field "{name}" on {}."#, model.name))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_reserved_counters() {
+    let sequence = Counter::Sequence("Post".to_string(), "number".to_string());
+    let mut counters = Counters::reserved();
+
+    // Counters run out of values, until the values are reserved.
+    counters.next_id();
+    counters.next_sequence("Post", "number");
+    assert!(counters.is_exhausted());
+    assert_eq!(counters.take_exhausted(), [Counter::Id, sequence.clone()]);
+
+    counters.reserve(&Counter::Id, 101, 102);
+    counters.reserve(&sequence, 11, 20);
+    assert_eq!(counters.next_id(), 101);
+    assert_eq!(counters.next_id(), 102);
+    assert_eq!(counters.next_sequence("Post", "number"), 11);
+    assert!(!counters.is_exhausted());
+    counters.next_id();
+    assert_eq!(counters.take_exhausted(), [Counter::Id]);
+
+    // Counters without the reserved values never run out of them.
+    let mut counters = Counters::default();
+    assert_eq!(counters.next_id(), 1);
+    assert_eq!(counters.next_sequence("Post", "number"), 1);
+    assert!(counters.take_exhausted().is_empty());
+  }
+}
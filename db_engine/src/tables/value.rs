@@ -0,0 +1,137 @@
+//! Helpers to work with the raw GraphQL values held in the store & inputs.
+use std::cmp::Ordering;
+
+use graphql_value::{ConstValue as Value, Name};
+use indexmap::IndexMap;
+use rust_decimal::Decimal;
+
+use crate::db_crud::ObjectUpdateInput;
+
+/// Field values of an object, by the model field name.
+pub(crate) type Fields = IndexMap<String, Value>;
+
+/// Items of the list value, or the value itself if it isn't a list.
+pub(crate) fn items(value: &Value) -> &[Value] {
+  match value {
+    Value::Null => &[],
+    Value::List(items) => items,
+    value => std::slice::from_ref(value),
+  }
+}
+
+/// Merges the partial update into the value embedded in a composite field,
+/// a new value is embedded if there is none.
+pub(crate) fn merge(value: &Value, data: &ObjectUpdateInput) -> Value {
+  let mut fields = match value {
+    Value::Object(fields) => fields.clone(),
+    _ => IndexMap::new(),
+  };
+  for (name, value) in &data.fields {
+    fields.insert(Name::new(name), value.clone());
+  }
+  for (name, data) in &data.composites {
+    let value = merge(fields.get(name.as_str()).unwrap_or(&Value::Null), data);
+    fields.insert(Name::new(name), value);
+  }
+  Value::Object(fields)
+}
+
+/// Key of the value in the unique & field indexes.
+pub(crate) fn key(value: &Value) -> String {
+  match value {
+    Value::String(value) => value.clone(),
+    Value::Enum(value) => value.to_string(),
+    value => value.to_string(),
+  }
+}
+
+/// Compares two values of the same type, returns `None` if they can't be compared.
+/// Note: Objects (Ex. the json values) are compared only for the equality.
+pub(crate) fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+  match (a, b) {
+    (Value::Null, Value::Null) => Some(Ordering::Equal),
+    (Value::Number(a), Value::Number(b)) => match (a.as_i64(), b.as_i64()) {
+      (Some(a), Some(b)) => Some(a.cmp(&b)),
+      _ => a.as_f64()?.partial_cmp(&b.as_f64()?),
+    },
+    (Value::String(_) | Value::Enum(_), Value::String(_) | Value::Enum(_)) => {
+      Some(str_value(a)?.cmp(str_value(b)?))
+    }
+    (Value::Boolean(a), Value::Boolean(b)) => Some(a.cmp(b)),
+    (Value::Binary(a), Value::Binary(b)) => Some(a.cmp(b)),
+    (Value::Object(a), Value::Object(b)) => (a == b).then_some(Ordering::Equal),
+    (Value::List(a), Value::List(b)) => {
+      for (a, b) in a.iter().zip(b) {
+        match compare(a, b)? {
+          Ordering::Equal => continue,
+          ordering => return Some(ordering),
+        }
+      }
+      Some(a.len().cmp(&b.len()))
+    }
+    _ => None,
+  }
+}
+
+/// Compares two decimals held as strings numerically, the other values are
+/// compared as usual.
+pub(crate) fn compare_decimals(a: &Value, b: &Value) -> Option<Ordering> {
+  match (decimal(a), decimal(b)) {
+    (Some(a), Some(b)) => Some(a.cmp(&b)),
+    _ => compare(a, b),
+  }
+}
+
+/// Checks if both the values are equal.
+pub(crate) fn equals(a: &Value, b: &Value) -> bool {
+  compare(a, b) == Some(Ordering::Equal)
+}
+
+/// Orders the values using the comparison, `null` being the lowest.
+pub(crate) fn order(
+  a: &Value,
+  b: &Value,
+  compare: fn(&Value, &Value) -> Option<Ordering>,
+) -> Ordering {
+  match (a, b) {
+    (Value::Null, Value::Null) => Ordering::Equal,
+    (Value::Null, _) => Ordering::Less,
+    (_, Value::Null) => Ordering::Greater,
+    (a, b) => compare(a, b).unwrap_or(Ordering::Equal),
+  }
+}
+
+/// String of the string or enum value.
+pub(crate) fn str_value(value: &Value) -> Option<&str> {
+  match value {
+    Value::String(value) => Some(value),
+    Value::Enum(value) => Some(value),
+    _ => None,
+  }
+}
+
+/// Decimal held as a string.
+fn decimal(value: &Value) -> Option<Decimal> {
+  match value {
+    Value::String(decimal) => parse_decimal(decimal),
+    _ => None,
+  }
+}
+
+/// Decimal value to be held in the store, Ex. both `"12.50"` & `12.5` are held
+/// as `"12.5"`. It is held as a string, so that it is kept exactly.
+/// `None` if the value isn't a decimal or it can't be held exactly.
+pub(crate) fn decimal_value(value: &Value) -> Option<Value> {
+  let decimal = match value {
+    Value::String(decimal) => parse_decimal(decimal.trim())?,
+    Value::Number(decimal) => parse_decimal(&decimal.to_string())?,
+    _ => return None,
+  };
+  Some(Value::String(decimal.normalize().to_string()))
+}
+
+fn parse_decimal(decimal: &str) -> Option<Decimal> {
+  Decimal::from_str_exact(decimal)
+    .or_else(|_| Decimal::from_scientific(decimal))
+    .ok()
+}
@@ -0,0 +1,798 @@
+//! Read-only view of the store, to find, filter, order & paginate the objects.
+use std::{cmp::Ordering, collections::HashMap, sync::Arc};
+
+use graphql_value::{ConstValue as Value, Name};
+use indexmap::IndexMap;
+
+use super::{
+  table::Table,
+  value::{self, Fields},
+};
+use crate::{
+  db_crud::{
+    CompositeFilter, FieldFilter, FieldOp, JsonPathFilter, ListFilter, ListOp,
+    ObjectFilter, ObjectOrderByInput, ObjectWhereInput, ObjectWhereUniqueInput, Order,
+    PageInfo, Pagination, RelationFilter, RelationOp, ID,
+  },
+  model::{Model, Models, Relation, RelationLink},
+  Error,
+};
+
+/// Tables of the models, by the model name.
+pub(crate) type Tables = HashMap<String, Table>;
+
+/// A page of objects found.
+pub(crate) struct Page {
+  pub ids: Vec<ID>,
+  pub page_info: PageInfo,
+  /// Number of objects matching the filter, ignoring pagination.
+  pub count: usize,
+}
+
+pub(crate) struct View<'a> {
+  pub models: &'a Models,
+  pub tables: &'a Tables,
+}
+
+impl<'a> View<'a> {
+  pub fn model(&self, name: &str) -> &'a Arc<Model> {
+    self
+      .models
+      .get(name)
+      .expect("Model should be known to the store.")
+  }
+
+  pub fn table(&self, model_name: &str) -> &'a Table {
+    self
+      .tables
+      .get(model_name)
+      .expect("Every model should have a table.")
+  }
+
+  pub fn fields(&self, model: &Model, id: &ID) -> Result<&'a Fields, Error> {
+    self
+      .table(&model.name)
+      .get(id)
+      .ok_or_else(|| Error::NotFound {
+        model: model.name.clone(),
+      })
+  }
+
+  /// Finds the object using the unique filter.
+  /// Every unique field (or compound key) given in the filter should match the same object.
+  pub fn find_unique(
+    &self,
+    model: &Model,
+    r#where: &ObjectWhereUniqueInput,
+  ) -> Result<Option<ID>, Error> {
+    let table = self.table(&model.name);
+    let mut found: Option<ID> = None;
+    for (name, value) in &r#where.0 {
+      let name = model.field_name(name);
+      let is_unique = match model.compound_keys.get(name) {
+        Some(compound_key) => compound_key.is_unique,
+        None => model.fields.get(name).is_some_and(|field| field.is_unique),
+      };
+      if !is_unique {
+        return Err(Error::InvalidInput(format!(
+          r#"Field "{name}" isn't a unique field of {}."#,
+          model.name
+        )));
+      }
+      match (table.find(model, name, value).pop(), &found) {
+        (None, _) => return Ok(None),
+        (Some(id), Some(found)) if &id != found => return Ok(None),
+        (Some(id), _) => found = Some(id),
+      }
+    }
+    match found {
+      None => Err(Error::InvalidInput(format!(
+        "Unique filter of {} should have a value.",
+        model.name
+      ))),
+      found => Ok(found),
+    }
+  }
+
+  /// IDs of the objects connected to the object of the model through the relation.
+  pub fn related(&self, model: &Model, relation: &Relation, fields: &Fields) -> Vec<ID> {
+    let target = self.model(&relation.model);
+    let table = self.table(&target.name);
+    match &relation.link {
+      RelationLink::Forward {
+        scalar_field,
+        referenced_field,
+      } => {
+        let mut ids = Vec::new();
+        for item in value::items(fields.get(scalar_field).unwrap_or(&Value::Null)) {
+          for id in table.find(target, referenced_field, item) {
+            if !ids.contains(&id) {
+              ids.push(id);
+            }
+          }
+        }
+        ids
+      }
+      RelationLink::Backward {
+        scalar_field,
+        referenced_field,
+      } => match fields.get(referenced_field) {
+        None | Some(Value::Null) => vec![],
+        Some(value) => table.find(target, scalar_field, value),
+      },
+      RelationLink::Join {
+        join_model,
+        field,
+        referenced_field,
+      } => {
+        let Some(id) = model.object_id(fields) else {
+          return vec![];
+        };
+        let join = self.model(join_model);
+        let links = self.table(join_model);
+        links
+          .find(join, field, &Value::from(id))
+          .iter()
+          .filter_map(|link_id| match links.get(link_id)?.get(referenced_field)? {
+            Value::String(target_id) => Some(ID::new(target_id)),
+            _ => None,
+          })
+          .collect()
+      }
+    }
+  }
+
+  /// Finds the objects matching the filter, orders & paginates them.
+  /// Searches among the `candidates` if given, otherwise among all the objects.
+  pub fn find_many(
+    &self,
+    model: &Model,
+    candidates: Option<Vec<ID>>,
+    r#where: &ObjectWhereInput,
+    order_by: &ObjectOrderByInput,
+    pagination: &Pagination,
+  ) -> Result<Page, Error> {
+    let table = self.table(&model.name);
+    let candidates = match candidates {
+      Some(candidates) => candidates,
+      None => self
+        .indexed_candidates(model, r#where)
+        .unwrap_or_else(|| table.scan().map(|(id, _)| id.clone()).collect()),
+    };
+    let mut ids = Vec::new();
+    for id in candidates {
+      if self.matches(model, self.fields(model, &id)?, r#where)? {
+        ids.push(id);
+      }
+    }
+    self.order(model, &mut ids, order_by)?;
+    paginate(ids, pagination)
+  }
+
+  /// Candidates found using the index, if the filter checks the equality (or the
+  /// equality or the prefix ignoring the case) of an indexed field or the items of
+  /// an indexed list field.
+  fn indexed_candidates(
+    &self,
+    model: &Model,
+    r#where: &ObjectWhereInput,
+  ) -> Option<Vec<ID>> {
+    r#where.0.iter().find_map(|filter| match filter {
+      ObjectFilter::Field(FieldFilter {
+        field,
+        op: FieldOp::Equals,
+        negated: false,
+        value,
+      })
+      | ObjectFilter::Decimal(FieldFilter {
+        field,
+        op: FieldOp::Equals,
+        negated: false,
+        value,
+      }) if !matches!(value, Value::Null | Value::List(_)) => {
+        let field = model.field(field).filter(|field| field.is_indexed)?;
+        Some(self.table(&model.name).find(model, &field.name, value))
+      }
+      // Note: Sorted lowercase index can't find the values containing or ending
+      // with a string, hence `_contains_i` & `_ends_with_i` search all the objects.
+      ObjectFilter::Field(FieldFilter {
+        field,
+        op: op @ (FieldOp::EqualsIgnoreCase | FieldOp::StartsWithIgnoreCase),
+        negated: false,
+        value: Value::String(value),
+      }) => {
+        let field = model.field(field).filter(|field| field.is_indexed)?;
+        let table = self.table(&model.name);
+        Some(if *op == FieldOp::EqualsIgnoreCase {
+          table.find_ignoring_case(model, &field.name, value)
+        } else {
+          table.find_prefix_ignoring_case(model, &field.name, value)
+        })
+      }
+      ObjectFilter::List(ListFilter { field, op }) => {
+        let field = model.field(field).filter(|field| field.is_indexed)?;
+        let table = self.table(&model.name);
+        match op {
+          ListOp::Has(value) => Some(table.find(model, &field.name, value)),
+          // Note: Objects having the first item are the candidates for having the rest.
+          ListOp::HasEvery(values) => {
+            Some(table.find(model, &field.name, values.first()?))
+          }
+          ListOp::HasSome(values) => Some(table.find_any(model, &field.name, values)),
+          ListOp::IsEmpty(_) | ListOp::Length(..) => None,
+        }
+      }
+      _ => None,
+    })
+  }
+
+  /// Checks if the object's fields match the filter.
+  pub fn matches(
+    &self,
+    model: &Model,
+    fields: &Fields,
+    r#where: &ObjectWhereInput,
+  ) -> Result<bool, Error> {
+    for filter in &r#where.0 {
+      if !self.matches_filter(model, fields, filter)? {
+        return Ok(false);
+      }
+    }
+    Ok(true)
+  }
+
+  fn matches_filter(
+    &self,
+    model: &Model,
+    fields: &Fields,
+    filter: &ObjectFilter,
+  ) -> Result<bool, Error> {
+    match filter {
+      ObjectFilter::And(filters) => self.matches_all(model, fields, filters),
+      ObjectFilter::Or(filters) => {
+        for filter in filters {
+          if self.matches(model, fields, filter)? {
+            return Ok(true);
+          }
+        }
+        Ok(false)
+      }
+      ObjectFilter::Not(filters) => Ok(!self.matches_all(model, fields, filters)?),
+      ObjectFilter::Relation(filter) => self.matches_relation(model, fields, filter),
+      ObjectFilter::Field(filter) => Ok(matches_value(
+        field_value(model, fields, &filter.field)?,
+        filter,
+        value::compare,
+      )),
+      ObjectFilter::Decimal(filter) => Ok(matches_value(
+        field_value(model, fields, &filter.field)?,
+        filter,
+        value::compare_decimals,
+      )),
+      ObjectFilter::Composite(filter) => {
+        matches_composite(field_value(model, fields, &filter.field)?, filter)
+      }
+      ObjectFilter::JsonPath(filter) => Ok(matches_json_path(
+        field_value(model, fields, &filter.filter.field)?,
+        filter,
+      )),
+      ObjectFilter::List(filter) => Ok(matches_list(
+        field_value(model, fields, &filter.field)?,
+        &filter.op,
+      )),
+    }
+  }
+
+  fn matches_relation(
+    &self,
+    model: &Model,
+    fields: &Fields,
+    filter: &RelationFilter,
+  ) -> Result<bool, Error> {
+    let relation = model.relations.get(&filter.relation).ok_or_else(|| {
+      Error::InvalidInput(format!(
+        r#"Unknown relation "{}" on {}."#,
+        filter.relation, model.name
+      ))
+    })?;
+    let ids = self.related(model, relation, fields);
+    let target = self.model(&relation.model);
+    let matches_target = |id: &ID, r#where: &ObjectWhereInput| {
+      self.matches(target, self.fields(target, id)?, r#where)
+    };
+    match &filter.op {
+      RelationOp::Is(r#where) => match (ids.first(), r#where) {
+        (Some(id), Some(r#where)) => matches_target(id, r#where),
+        (None, None) => Ok(true),
+        _ => Ok(false),
+      },
+      RelationOp::IsNull(is_empty) | RelationOp::IsEmpty(is_empty) => {
+        Ok(ids.is_empty() == *is_empty)
+      }
+      RelationOp::Every(r#where) => {
+        for id in &ids {
+          if !matches_target(id, r#where)? {
+            return Ok(false);
+          }
+        }
+        Ok(true)
+      }
+      RelationOp::Some(r#where) | RelationOp::None(r#where) => {
+        let mut some = false;
+        for id in &ids {
+          if matches_target(id, r#where)? {
+            some = true;
+            break;
+          }
+        }
+        Ok(matches!(filter.op, RelationOp::Some(_)) == some)
+      }
+    }
+  }
+
+  fn matches_all(
+    &self,
+    model: &Model,
+    fields: &Fields,
+    filters: &[ObjectWhereInput],
+  ) -> Result<bool, Error> {
+    for filter in filters {
+      if !self.matches(model, fields, filter)? {
+        return Ok(false);
+      }
+    }
+    Ok(true)
+  }
+
+  /// Orders the objects, keeps the insertion order if no order is given.
+  fn order(
+    &self,
+    model: &Model,
+    ids: &mut [ID],
+    order_by: &ObjectOrderByInput,
+  ) -> Result<(), Error> {
+    let Some(order_by) = &order_by.0 else {
+      return Ok(());
+    };
+    let field = model.field(&order_by.field).ok_or_else(|| {
+      Error::InvalidInput(format!(
+        r#"Unknown order field "{}" on {}."#,
+        order_by.field, model.name
+      ))
+    })?;
+    let table = self.table(&model.name);
+    let field_value = |id: &ID| {
+      table
+        .get(id)
+        .and_then(|fields| fields.get(&field.name))
+        .unwrap_or(&Value::Null)
+    };
+    let compare = if field.is_decimal {
+      value::compare_decimals
+    } else {
+      value::compare
+    };
+    ids.sort_by(|a, b| {
+      let ordering = value::order(field_value(a), field_value(b), compare);
+      match order_by.order {
+        Order::Asc => ordering,
+        Order::Dsc => ordering.reverse(),
+      }
+    });
+    Ok(())
+  }
+}
+
+/// Paginates the objects, cursors being the object IDs.
+fn paginate(ids: Vec<ID>, pagination: &Pagination) -> Result<Page, Error> {
+  let count = ids.len();
+  let position = |cursor: &ID| {
+    ids
+      .iter()
+      .position(|id| id == cursor)
+      .ok_or_else(|| Error::InvalidInput(format!(r#"Cursor "{cursor}" is not found."#)))
+  };
+  let mut start = 0;
+  let mut end = count;
+  if let Some(after) = &pagination.after {
+    start = position(after)? + 1;
+  }
+  if let Some(before) = &pagination.before {
+    end = position(before)?.max(start);
+  }
+  if let Some(skip) = pagination.skip {
+    start = (start + skip as usize).min(end);
+  }
+  if let Some(first) = pagination.first {
+    end = end.min(start + first as usize);
+  }
+  if let Some(last) = pagination.last {
+    start = start.max(end.saturating_sub(last as usize));
+  }
+
+  let ids = ids[start..end].to_vec();
+  Ok(Page {
+    page_info: PageInfo {
+      has_next_page: end < count,
+      has_previous_page: start > 0,
+      start_cursor: ids.first().cloned(),
+      end_cursor: ids.last().cloned(),
+      page_size: Some(ids.len() as u32),
+    },
+    ids,
+    count,
+  })
+}
+
+/// Value of the model field, `null` if the field isn't set.
+fn field_value<'f>(
+  model: &Model,
+  fields: &'f Fields,
+  field_name: &str,
+) -> Result<&'f Value, Error> {
+  let field = model.field(field_name).ok_or_else(|| {
+    Error::InvalidInput(format!(
+      r#"Unknown field "{field_name}" on {}."#,
+      model.name
+    ))
+  })?;
+  Ok(fields.get(&field.name).unwrap_or(&Value::Null))
+}
+
+/// Checks if the value found at the path inside the json value matches the filter.
+fn matches_json_path(value: &Value, filter: &JsonPathFilter) -> bool {
+  let value = filter
+    .path
+    .iter()
+    .try_fold(value, |value, key| match value {
+      Value::Object(fields) => fields.get(key.as_str()),
+      Value::List(items) => items.get(key.parse::<usize>().ok()?),
+      _ => None,
+    })
+    .unwrap_or(&Value::Null);
+  matches_value(value, &filter.filter, value::compare)
+}
+
+/// Checks if the value matches the filter, comparing the values using the given
+/// comparison.
+fn matches_value(
+  field_value: &Value,
+  filter: &FieldFilter,
+  compare: fn(&Value, &Value) -> Option<Ordering>,
+) -> bool {
+  let (op, value) = (filter.op, &filter.value);
+  let matches = match field_value {
+    Value::List(items) if !(op == FieldOp::Equals && matches!(value, Value::List(_))) => {
+      items
+        .iter()
+        .any(|item| matches_scalar(op, item, value, compare))
+    }
+    field_value => matches_scalar(op, field_value, value, compare),
+  };
+  matches != filter.negated
+}
+
+/// Checks if the items of the scalar list value match the filter.
+fn matches_list(value: &Value, op: &ListOp) -> bool {
+  let items = value::items(value);
+  let has = |operand: &Value| items.iter().any(|item| value::equals(item, operand));
+  match op {
+    ListOp::Has(operand) => has(operand),
+    ListOp::HasEvery(operands) => operands.iter().all(has),
+    ListOp::HasSome(operands) => operands.iter().any(has),
+    ListOp::IsEmpty(is_empty) => items.is_empty() == *is_empty,
+    ListOp::Length(op, length) => {
+      let ordering = items.len().cmp(length);
+      match op {
+        FieldOp::Equals => ordering == Ordering::Equal,
+        FieldOp::Lt => ordering == Ordering::Less,
+        FieldOp::Lte => ordering != Ordering::Greater,
+        FieldOp::Gt => ordering == Ordering::Greater,
+        FieldOp::Gte => ordering != Ordering::Less,
+        _ => false,
+      }
+    }
+  }
+}
+
+/// Checks if the value(s) embedded in the composite field match the filter.
+fn matches_composite(value: &Value, filter: &CompositeFilter) -> Result<bool, Error> {
+  let matches_embedded = |value: &Value, r#where: &ObjectWhereInput| match value {
+    Value::Object(fields) => matches_embedded(fields, r#where),
+    _ => Ok(false),
+  };
+  match &filter.op {
+    RelationOp::Is(None) => Ok(matches!(value, Value::Null)),
+    RelationOp::Is(Some(r#where)) => matches_embedded(value, r#where),
+    RelationOp::IsNull(is_null) => Ok(matches!(value, Value::Null) == *is_null),
+    RelationOp::IsEmpty(is_empty) => Ok(value::items(value).is_empty() == *is_empty),
+    RelationOp::Every(r#where) => {
+      for item in value::items(value) {
+        if !matches_embedded(item, r#where)? {
+          return Ok(false);
+        }
+      }
+      Ok(true)
+    }
+    RelationOp::Some(r#where) | RelationOp::None(r#where) => {
+      let mut some = false;
+      for item in value::items(value) {
+        if matches_embedded(item, r#where)? {
+          some = true;
+          break;
+        }
+      }
+      Ok(matches!(filter.op, RelationOp::Some(_)) == some)
+    }
+  }
+}
+
+/// Checks if the fields of the embedded value match the filter.
+/// Note: Filters were checked against the composite type, while converting the input.
+fn matches_embedded(
+  fields: &IndexMap<Name, Value>,
+  r#where: &ObjectWhereInput,
+) -> Result<bool, Error> {
+  let matches_all = |filters: &[ObjectWhereInput]| {
+    for filter in filters {
+      if !matches_embedded(fields, filter)? {
+        return Ok(false);
+      }
+    }
+    Ok(true)
+  };
+  let field_value = |name: &str| fields.get(name).unwrap_or(&Value::Null);
+  for filter in &r#where.0 {
+    let matches = match filter {
+      ObjectFilter::And(filters) => matches_all(filters)?,
+      ObjectFilter::Or(filters) => {
+        let mut any = false;
+        for filter in filters {
+          if matches_embedded(fields, filter)? {
+            any = true;
+            break;
+          }
+        }
+        any
+      }
+      ObjectFilter::Not(filters) => !matches_all(filters)?,
+      ObjectFilter::Field(filter) => {
+        matches_value(field_value(&filter.field), filter, value::compare)
+      }
+      ObjectFilter::Decimal(filter) => {
+        matches_value(field_value(&filter.field), filter, value::compare_decimals)
+      }
+      ObjectFilter::Composite(filter) => {
+        matches_composite(field_value(&filter.field), filter)?
+      }
+      ObjectFilter::JsonPath(filter) => {
+        matches_json_path(field_value(&filter.filter.field), filter)
+      }
+      ObjectFilter::List(filter) => matches_list(field_value(&filter.field), &filter.op),
+      ObjectFilter::Relation(filter) => {
+        return Err(Error::InvalidInput(format!(
+          r#"Composite types can't have the relation "{}"."#,
+          filter.relation
+        )))
+      }
+    };
+    if !matches {
+      return Ok(false);
+    }
+  }
+  Ok(true)
+}
+
+fn matches_scalar(
+  op: FieldOp,
+  value: &Value,
+  operand: &Value,
+  compare: fn(&Value, &Value) -> Option<Ordering>,
+) -> bool {
+  let strings = || Some((value::str_value(value)?, value::str_value(operand)?));
+  let lowercase_strings =
+    || strings().map(|(value, operand)| (value.to_lowercase(), operand.to_lowercase()));
+  match op {
+    FieldOp::Equals => compare(value, operand) == Some(Ordering::Equal),
+    FieldOp::Contains => {
+      strings().is_some_and(|(value, operand)| value.contains(operand))
+    }
+    FieldOp::StartsWith => {
+      strings().is_some_and(|(value, operand)| value.starts_with(operand))
+    }
+    FieldOp::EndsWith => {
+      strings().is_some_and(|(value, operand)| value.ends_with(operand))
+    }
+    FieldOp::EqualsIgnoreCase => {
+      lowercase_strings().is_some_and(|(value, operand)| value == operand)
+    }
+    FieldOp::ContainsIgnoreCase => {
+      lowercase_strings().is_some_and(|(value, operand)| value.contains(&operand))
+    }
+    FieldOp::StartsWithIgnoreCase => {
+      lowercase_strings().is_some_and(|(value, operand)| value.starts_with(&operand))
+    }
+    FieldOp::EndsWithIgnoreCase => {
+      lowercase_strings().is_some_and(|(value, operand)| value.ends_with(&operand))
+    }
+    FieldOp::Lt => compare(value, operand) == Some(Ordering::Less),
+    FieldOp::Lte => matches!(
+      compare(value, operand),
+      Some(Ordering::Less | Ordering::Equal)
+    ),
+    FieldOp::Gt => compare(value, operand) == Some(Ordering::Greater),
+    FieldOp::Gte => matches!(
+      compare(value, operand),
+      Some(Ordering::Greater | Ordering::Equal)
+    ),
+    FieldOp::In => value::items(operand)
+      .iter()
+      .any(|operand| compare(value, operand) == Some(Ordering::Equal)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use graphql_value::value;
+
+  use super::*;
+
+  fn filter(field: &str, op: FieldOp, value: Value) -> FieldFilter {
+    FieldFilter {
+      field: field.to_string(),
+      op,
+      negated: false,
+      value,
+    }
+  }
+
+  fn r#where(filter: FieldFilter) -> ObjectWhereInput {
+    ObjectWhereInput(vec![ObjectFilter::Field(filter)])
+  }
+
+  #[test]
+  fn test_matches_value() {
+    let matches =
+      |value: Value, filter: FieldFilter| matches_value(&value, &filter, value::compare);
+    // Decimals are compared numerically, not as the strings.
+    let balance_gt = filter("balance", FieldOp::Gt, value!("100"));
+    assert!(!matches_value(
+      &value!("9.5"),
+      &balance_gt,
+      value::compare_decimals
+    ));
+    assert!(matches_value(
+      &value!("12345678901234567.89"),
+      &balance_gt,
+      value::compare_decimals
+    ));
+    // Longs beyond 2^53 are compared exactly.
+    assert!(matches(
+      value!(9007199254740993i64),
+      filter("amount", FieldOp::Gt, value!(9007199254740992i64))
+    ));
+    assert!(matches(
+      value!("2024-06-30"),
+      filter("openedOn", FieldOp::Lt, value!("2024-07-01"))
+    ));
+
+    assert!(matches(
+      value!("Alan Turing"),
+      filter("name", FieldOp::EqualsIgnoreCase, value!("ALAN TURING"))
+    ));
+    assert!(matches(
+      value!("Ada Lovelace"),
+      filter("name", FieldOp::ContainsIgnoreCase, value!("LOVE"))
+    ));
+    assert!(!matches(
+      value!("Ada Lovelace"),
+      filter("name", FieldOp::EndsWithIgnoreCase, value!("ADA"))
+    ));
+
+    // Note: `_is_null: true` is the equality with `null`, `false` its negation.
+    let is_null = filter("nickName", FieldOp::Equals, Value::Null);
+    assert!(matches(Value::Null, is_null.clone()));
+    assert!(!matches(value!("Ada"), is_null.clone()));
+    assert!(matches(
+      value!("Ada"),
+      FieldFilter {
+        negated: true,
+        ..is_null
+      }
+    ));
+  }
+
+  #[test]
+  fn test_matches_list() {
+    let tags = value!(["lang", "systems"]);
+    assert!(matches_list(&tags, &ListOp::Has(value!("lang"))));
+    assert!(!matches_list(&tags, &ListOp::Has(value!("api"))));
+    assert!(matches_list(
+      &tags,
+      &ListOp::HasEvery(vec![value!("systems"), value!("lang")])
+    ));
+    assert!(!matches_list(
+      &tags,
+      &ListOp::HasEvery(vec![value!("lang"), value!("api")])
+    ));
+    assert!(matches_list(
+      &tags,
+      &ListOp::HasSome(vec![value!("api"), value!("lang")])
+    ));
+    assert!(matches_list(&tags, &ListOp::Length(FieldOp::Gt, 1)));
+    assert!(!matches_list(&tags, &ListOp::Length(FieldOp::Lt, 2)));
+    assert!(!matches_list(&tags, &ListOp::IsEmpty(true)));
+    assert!(matches_list(&value!([]), &ListOp::IsEmpty(true)));
+    assert!(matches_list(&Value::Null, &ListOp::IsEmpty(true)));
+  }
+
+  #[test]
+  fn test_matches_json_path() {
+    let settings = value!({"theme": {"color": "dark"}, "tags": ["a", "b"]});
+    let path_filter = |path: &[&str], value: Value| JsonPathFilter {
+      path: path.iter().map(|key| key.to_string()).collect(),
+      filter: filter("settings", FieldOp::Equals, value),
+    };
+    assert!(matches_json_path(
+      &settings,
+      &path_filter(&["theme", "color"], value!("dark"))
+    ));
+    assert!(matches_json_path(
+      &settings,
+      &path_filter(&["tags", "1"], value!("b"))
+    ));
+    // Note: Filter on a list matches any of its items.
+    assert!(matches_json_path(
+      &settings,
+      &path_filter(&["tags"], value!("b"))
+    ));
+    assert!(matches_json_path(
+      &settings,
+      &path_filter(&["theme", "font"], Value::Null)
+    ));
+  }
+
+  #[test]
+  fn test_matches_composite() {
+    let composite_filter = |op: RelationOp| CompositeFilter {
+      field: "address".to_string(),
+      op,
+    };
+    let in_city = |city: &str| r#where(filter("city", FieldOp::Equals, value!(city)));
+    let address = value!({"street": "1 Main St", "city": "Chennai"});
+    let past_addresses = value!([
+      {"street": "2 Side St", "city": "Madurai"},
+      {"street": "3 Hill Rd", "city": "Ooty"},
+    ]);
+
+    assert!(matches_composite(
+      &address,
+      &composite_filter(RelationOp::Is(Some(in_city("Chennai"))))
+    )
+    .unwrap());
+    assert!(!matches_composite(
+      &address,
+      &composite_filter(RelationOp::Is(Some(in_city("Madurai"))))
+    )
+    .unwrap());
+    assert!(
+      matches_composite(&Value::Null, &composite_filter(RelationOp::IsNull(true)))
+        .unwrap()
+    );
+    assert!(matches_composite(
+      &past_addresses,
+      &composite_filter(RelationOp::Some(in_city("Ooty")))
+    )
+    .unwrap());
+    assert!(!matches_composite(
+      &past_addresses,
+      &composite_filter(RelationOp::Every(in_city("Ooty")))
+    )
+    .unwrap());
+    assert!(matches_composite(
+      &past_addresses,
+      &composite_filter(RelationOp::None(in_city("Chennai")))
+    )
+    .unwrap());
+  }
+}
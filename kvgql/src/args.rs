@@ -0,0 +1,210 @@
+//! Parses the command-line arguments into the command to run.
+
+/// Usage of the tool, printed on the usage errors.
+pub const USAGE: &str = "\
+Usage: kvgql <COMMAND> [OPTIONS] <FILE.sdml>
+
+Commands:
+  check [--json]                     Validate the data model & print the diagnostics
+  generate [-o <FILE.graphql>]       Write the OpenCRUD GraphQL schema, to stdout by default
+  fmt [--check]                      Format the data model file in place
+  serve [--addr <ADDR>] [--backend <memory|fdb>] [--fdb-root <PREFIX>]
+                                     Serve the GraphQL API over HTTP, at `/graphql`";
+
+/// Address to serve at, by default.
+const DEFAULT_ADDR: &str = "127.0.0.1:8080";
+/// FoundationDB subspace prefix to keep the data under, by default.
+const DEFAULT_FDB_ROOT: &str = "kvgql";
+
+/// Command to run, along with its options.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+  Check {
+    path: String,
+    /// Print the diagnostics as JSON.
+    json: bool,
+  },
+  Generate {
+    path: String,
+    /// File to write the GraphQL schema to, stdout if not given.
+    output: Option<String>,
+  },
+  Fmt {
+    path: String,
+    /// Only check if the file is formatted, without writing it.
+    check: bool,
+  },
+  Serve {
+    path: String,
+    addr: String,
+    backend: Backend,
+  },
+}
+
+/// Data store backend to serve against.
+#[derive(Debug, PartialEq)]
+pub enum Backend {
+  /// In-memory store, data is lost on exit.
+  Memory,
+  /// FoundationDB store, data is kept under the `root` subspace prefix.
+  Fdb { root: String },
+}
+
+impl Command {
+  /// Parses the arguments, excluding the program name.
+  pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+    let mut args = args.into_iter();
+    let command = args.next().ok_or("Command is missing.")?;
+    let mut path = None;
+    let mut flags = Vec::new();
+    let mut options = Vec::new();
+    while let Some(arg) = args.next() {
+      match arg.as_str() {
+        "--json" | "--check" => flags.push(arg),
+        "-o" | "--output" | "--addr" | "--backend" | "--fdb-root" => {
+          let value = args
+            .next()
+            .ok_or_else(|| format!("Value of the option `{arg}` is missing."))?;
+          options.push((arg, value));
+        }
+        _ if arg.starts_with('-') => return Err(format!("Unknown option `{arg}`.")),
+        _ if path.is_some() => return Err(format!("Unexpected argument `{arg}`.")),
+        _ => path = Some(arg),
+      }
+    }
+    let path = path.ok_or("Path of the SDML file is missing.")?;
+    let mut args = Args { flags, options };
+
+    let command = match command.as_str() {
+      "check" => Command::Check {
+        path,
+        json: args.flag("--json"),
+      },
+      "generate" => Command::Generate {
+        path,
+        output: args.option(&["-o", "--output"]),
+      },
+      "fmt" => Command::Fmt {
+        path,
+        check: args.flag("--check"),
+      },
+      "serve" => {
+        let addr = args
+          .option(&["--addr"])
+          .unwrap_or_else(|| DEFAULT_ADDR.to_string());
+        let fdb_root = args.option(&["--fdb-root"]);
+        let backend = match args.option(&["--backend"]).as_deref() {
+          None | Some("memory") => Backend::Memory,
+          Some("fdb") => Backend::Fdb {
+            root: fdb_root.unwrap_or_else(|| DEFAULT_FDB_ROOT.to_string()),
+          },
+          Some(backend) => return Err(format!("Unknown backend `{backend}`.")),
+        };
+        Command::Serve {
+          path,
+          addr,
+          backend,
+        }
+      }
+      command => return Err(format!("Unknown command `{command}`.")),
+    };
+    args.finish(&command)?;
+    Ok(command)
+  }
+}
+
+/// Flags & options given to the command, taken out as the command reads them.
+struct Args {
+  flags: Vec<String>,
+  options: Vec<(String, String)>,
+}
+
+impl Args {
+  fn flag(&mut self, name: &str) -> bool {
+    let given = self.flags.iter().any(|flag| flag == name);
+    self.flags.retain(|flag| flag != name);
+    given
+  }
+
+  fn option(&mut self, names: &[&str]) -> Option<String> {
+    let index = self
+      .options
+      .iter()
+      .position(|(name, _)| names.contains(&name.as_str()))?;
+    Some(self.options.remove(index).1)
+  }
+
+  /// Fails if the command was given a flag or an option it doesn't take.
+  fn finish(self, command: &Command) -> Result<(), String> {
+    let name = match command {
+      Command::Check { .. } => "check",
+      Command::Generate { .. } => "generate",
+      Command::Fmt { .. } => "fmt",
+      Command::Serve { .. } => "serve",
+    };
+    match self
+      .flags
+      .into_iter()
+      .chain(self.options.into_iter().map(|(name, _)| name))
+      .next()
+    {
+      Some(arg) => Err(format!("Option `{arg}` isn't supported by `{name}`.")),
+      None => Ok(()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(args: &str) -> Result<Command, String> {
+    Command::parse(args.split_whitespace().map(ToString::to_string))
+  }
+
+  #[test]
+  fn test_parse() {
+    assert_eq!(
+      parse("check --json model.sdml"),
+      Ok(Command::Check {
+        path: "model.sdml".to_string(),
+        json: true
+      })
+    );
+    assert_eq!(
+      parse("generate model.sdml -o api.graphql"),
+      Ok(Command::Generate {
+        path: "model.sdml".to_string(),
+        output: Some("api.graphql".to_string())
+      })
+    );
+    assert_eq!(
+      parse("fmt model.sdml"),
+      Ok(Command::Fmt {
+        path: "model.sdml".to_string(),
+        check: false
+      })
+    );
+    assert_eq!(
+      parse("serve model.sdml --backend fdb"),
+      Ok(Command::Serve {
+        path: "model.sdml".to_string(),
+        addr: DEFAULT_ADDR.to_string(),
+        backend: Backend::Fdb {
+          root: DEFAULT_FDB_ROOT.to_string()
+        }
+      })
+    );
+  }
+
+  #[test]
+  fn test_parse_error() {
+    assert!(parse("").is_err());
+    assert!(parse("check").is_err());
+    assert!(parse("lint model.sdml").is_err());
+    assert!(parse("check model.sdml other.sdml").is_err());
+    assert!(parse("generate model.sdml -o").is_err());
+    assert!(parse("fmt --json model.sdml").is_err());
+    assert!(parse("serve model.sdml --backend sqlite").is_err());
+  }
+}
@@ -0,0 +1,199 @@
+//! `kvgql` command-line tool, to check, generate, format & serve the SDML data models.
+//!
+//! Exit codes, for the CI:
+//! * `0` - Command succeeded.
+//! * `1` - Data model has errors, or isn't formatted (`fmt --check`).
+//! * `2` - Usage error, or the command failed (Ex. I/O errors).
+mod args;
+mod serve;
+
+use std::{fs, io, process::ExitCode, sync::Arc};
+
+use args::{Backend, Command, USAGE};
+use db_engine::db_crud::DBStore;
+use query_engine::Schema;
+use sdml_parser::{diagnostic, types::DataModel};
+use thiserror::Error;
+
+/// Data model has errors, or isn't formatted.
+const EXIT_INVALID: u8 = 1;
+/// Usage error, or the command failed.
+const EXIT_FAILURE: u8 = 2;
+
+/// Errors failing the command.
+#[derive(Debug, Error)]
+enum Error {
+  #[error("{0}\n\n{USAGE}")]
+  Usage(String),
+  #[error("Failed to read `{path}`: {error}")]
+  Read { path: String, error: io::Error },
+  #[error("Failed to write `{path}`: {error}")]
+  Write { path: String, error: io::Error },
+  #[error("{0}")]
+  Schema(#[from] query_engine::SchemaError),
+  #[error("{0}")]
+  Store(#[from] db_engine::Error),
+  #[error("Failed to serve: {0}")]
+  Serve(io::Error),
+}
+
+fn main() -> ExitCode {
+  let result = Command::parse(std::env::args().skip(1))
+    .map_err(Error::Usage)
+    .and_then(run);
+  match result {
+    Ok(exit_code) => exit_code,
+    Err(error) => {
+      eprintln!("error: {error}");
+      ExitCode::from(EXIT_FAILURE)
+    }
+  }
+}
+
+fn run(command: Command) -> Result<ExitCode, Error> {
+  match command {
+    Command::Check { path, json } => check(&path, json),
+    Command::Generate { path, output } => generate(&path, output.as_deref()),
+    Command::Fmt { path, check } => fmt(&path, check),
+    Command::Serve {
+      path,
+      addr,
+      backend,
+    } => serve(&path, &addr, backend),
+  }
+}
+
+/// Validates the data model, prints the diagnostics.
+fn check(path: &str, json: bool) -> Result<ExitCode, Error> {
+  let src = read(path)?;
+  let result = sdml_parser::parse(&src);
+  if json {
+    let errors = result.as_ref().err().map_or(&[][..], Vec::as_slice);
+    println!("{}", diagnostic::to_json(&src, errors));
+  }
+  match result {
+    Ok(_) => Ok(ExitCode::SUCCESS),
+    Err(errors) => {
+      if !json {
+        eprint!("{}", diagnostic::render(&src, path, &errors));
+      }
+      Ok(ExitCode::from(EXIT_INVALID))
+    }
+  }
+}
+
+/// Writes the OpenCRUD GraphQL schema of the data model.
+fn generate(path: &str, output: Option<&str>) -> Result<ExitCode, Error> {
+  let src = read(path)?;
+  let Some(data_model) = parse(&src, path) else {
+    return Ok(ExitCode::from(EXIT_INVALID));
+  };
+  let crud_api = match schema_transpiler::generate_crud_api(&data_model) {
+    Ok(crud_api) => crud_api,
+    Err(error) => {
+      eprintln!("error: {error}");
+      return Ok(ExitCode::from(EXIT_INVALID));
+    }
+  };
+  match output {
+    Some(output) => write(output, &crud_api)?,
+    None => print!("{crud_api}"),
+  }
+  Ok(ExitCode::SUCCESS)
+}
+
+/// Formats the data model file in place, or checks if it is formatted.
+fn fmt(path: &str, check: bool) -> Result<ExitCode, Error> {
+  let src = read(path)?;
+  let formatted = match sdml_parser::formatter::format(&src) {
+    Ok(formatted) => formatted,
+    Err(errors) => {
+      eprint!("{}", diagnostic::render(&src, path, &errors));
+      return Ok(ExitCode::from(EXIT_INVALID));
+    }
+  };
+  if formatted == src {
+    return Ok(ExitCode::SUCCESS);
+  }
+  if check {
+    eprintln!("`{path}` isn't formatted, run `kvgql fmt {path}`.");
+    return Ok(ExitCode::from(EXIT_INVALID));
+  }
+  write(path, &formatted)?;
+  Ok(ExitCode::SUCCESS)
+}
+
+/// Serves the GraphQL API of the data model over HTTP, until it fails.
+fn serve(path: &str, addr: &str, backend: Backend) -> Result<ExitCode, Error> {
+  let src = read(path)?;
+  let Some(data_model) = parse(&src, path) else {
+    return Ok(ExitCode::from(EXIT_INVALID));
+  };
+  let runtime = tokio::runtime::Runtime::new().map_err(Error::Serve)?;
+  runtime.block_on(async {
+    let store = store(&data_model, backend).await?;
+    let schema = Schema::build(src, store).finish()?;
+    let listener = tokio::net::TcpListener::bind(addr)
+      .await
+      .map_err(Error::Serve)?;
+    let local_addr = listener.local_addr().map_err(Error::Serve)?;
+    eprintln!(
+      "Serving GraphQL at http://{local_addr}{}",
+      serve::GRAPHQL_PATH
+    );
+    serve::serve(listener, schema).await.map_err(Error::Serve)
+  })?;
+  Ok(ExitCode::SUCCESS)
+}
+
+/// Data store of the backend.
+async fn store(
+  data_model: &DataModel,
+  backend: Backend,
+) -> Result<Arc<dyn DBStore>, Error> {
+  match backend {
+    Backend::Memory => Ok(Arc::new(db_engine::memory::MemoryStore::new(data_model))),
+    #[cfg(feature = "fdb")]
+    Backend::Fdb { root } => {
+      use foundationdb::{tuple::Subspace, Database};
+      // Safety: Network is booted once, and stopped when the process exits.
+      let network = unsafe { foundationdb::boot() };
+      std::mem::forget(network);
+      let db =
+        Database::default().map_err(|error| Error::Serve(io::Error::other(error)))?;
+      let store =
+        db_engine::fdb::FdbStore::open(db, Subspace::from(root.as_str()), data_model)
+          .await?;
+      Ok(Arc::new(store))
+    }
+    #[cfg(not(feature = "fdb"))]
+    Backend::Fdb { .. } => Err(Error::Usage(
+      "`fdb` backend needs kvgql built with the `fdb` feature.".to_string(),
+    )),
+  }
+}
+
+/// Parses the data model, prints the diagnostics if it has errors.
+fn parse(src: &str, path: &str) -> Option<DataModel> {
+  match sdml_parser::parse(src) {
+    Ok(data_model) => Some(data_model),
+    Err(errors) => {
+      eprint!("{}", diagnostic::render(src, path, &errors));
+      None
+    }
+  }
+}
+
+fn read(path: &str) -> Result<String, Error> {
+  fs::read_to_string(path).map_err(|error| Error::Read {
+    path: path.to_string(),
+    error,
+  })
+}
+
+fn write(path: &str, contents: &str) -> Result<(), Error> {
+  fs::write(path, contents).map_err(|error| Error::Write {
+    path: path.to_string(),
+    error,
+  })
+}
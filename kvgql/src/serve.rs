@@ -0,0 +1,216 @@
+//! Serves the GraphQL API of the data model over HTTP.
+//!
+//! `POST /graphql` with a JSON body of a GraphQL request (or a batch of them) gets the
+//! JSON response. Connection is closed after every response, and it is dropped if the
+//! request isn't read & answered in time.
+use std::{convert::Infallible, io, time::Duration};
+
+use http::{header, Method, Request, Response, StatusCode};
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::{
+  body::{Body, Bytes, Incoming},
+  server::conn::http1,
+  service::service_fn,
+};
+use hyper_util::rt::{TokioIo, TokioTimer};
+use query_engine::{BatchRequest, Executor, Schema};
+use tokio::net::TcpListener;
+
+/// Path of the GraphQL endpoint.
+pub const GRAPHQL_PATH: &str = "/graphql";
+
+/// Largest request head & body accepted, in bytes.
+const MAX_HEAD_SIZE: usize = 64 * 1024;
+const MAX_BODY_SIZE: usize = 16 * 1024 * 1024;
+
+/// Time given to the client to send the request head.
+const HEAD_READ_TIMEOUT: Duration = Duration::from_secs(10);
+/// Time given to the connection to read the request & write the response.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Serves the schema on the listener, until it fails to accept a connection.
+pub async fn serve(listener: TcpListener, schema: Schema) -> io::Result<()> {
+  loop {
+    let (stream, _) = listener.accept().await?;
+    let schema = schema.clone();
+    tokio::spawn(async move {
+      let service = service_fn(|request| {
+        let schema = schema.clone();
+        async move { Ok::<_, Infallible>(respond(&schema, request).await) }
+      });
+      let connection = http1::Builder::new()
+        .timer(TokioTimer::new())
+        .header_read_timeout(HEAD_READ_TIMEOUT)
+        .max_buf_size(MAX_HEAD_SIZE)
+        .keep_alive(false)
+        .serve_connection(TokioIo::new(stream), service);
+      match tokio::time::timeout(CONNECTION_TIMEOUT, connection).await {
+        Ok(Ok(())) => {}
+        Ok(Err(error)) => eprintln!("error: Connection failed: {error}"),
+        Err(_) => eprintln!("error: Connection timed out."),
+      }
+    });
+  }
+}
+
+fn json(status: StatusCode, body: Vec<u8>) -> Response<Full<Bytes>> {
+  let mut response = Response::new(Full::new(Bytes::from(body)));
+  *response.status_mut() = status;
+  response.headers_mut().insert(
+    header::CONTENT_TYPE,
+    header::HeaderValue::from_static("application/json"),
+  );
+  response
+}
+
+/// Error response, with the message as a GraphQL error.
+fn error(status: StatusCode, message: &str) -> Response<Full<Bytes>> {
+  let body = serde_json::json!({ "errors": [{ "message": message }] });
+  json(status, body.to_string().into_bytes())
+}
+
+async fn respond(schema: &Schema, request: Request<Incoming>) -> Response<Full<Bytes>> {
+  if request.uri().path() != GRAPHQL_PATH {
+    return error(StatusCode::NOT_FOUND, "Serving GraphQL at `/graphql` only.");
+  }
+  if request.method() != Method::POST {
+    let mut response = error(
+      StatusCode::METHOD_NOT_ALLOWED,
+      "Send the requests using POST.",
+    );
+    response
+      .headers_mut()
+      .insert(header::ALLOW, header::HeaderValue::from_static("POST"));
+    return response;
+  }
+  // Note: Body is refused upfront when its declared length is over the limit, else
+  // reading it stops at the limit.
+  let too_large = || error(StatusCode::PAYLOAD_TOO_LARGE, "Request body is too large.");
+  if request.body().size_hint().lower() > MAX_BODY_SIZE as u64 {
+    return too_large();
+  }
+  let body = match Limited::new(request.into_body(), MAX_BODY_SIZE)
+    .collect()
+    .await
+  {
+    Ok(body) => body.to_bytes(),
+    Err(error) if error.is::<http_body_util::LengthLimitError>() => return too_large(),
+    Err(read_error) => {
+      return error(
+        StatusCode::BAD_REQUEST,
+        &format!("Request body is malformed: {read_error}"),
+      )
+    }
+  };
+  let batch_request = match serde_json::from_slice::<BatchRequest>(&body) {
+    Ok(batch_request) => batch_request,
+    Err(parse_error) => {
+      return error(
+        StatusCode::BAD_REQUEST,
+        &format!("Invalid request: {parse_error}"),
+      )
+    }
+  };
+  let batch_response = schema.execute_batch(batch_request).await;
+  match serde_json::to_vec(&batch_response) {
+    Ok(body) => json(StatusCode::OK, body),
+    Err(write_error) => {
+      error(StatusCode::INTERNAL_SERVER_ERROR, &write_error.to_string())
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use db_engine::memory::MemoryStore;
+  use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+  };
+
+  use super::*;
+
+  const SDML: &str = r#"
+    model User {
+      email ShortStr @id
+      name  ShortStr?
+    }
+  "#;
+
+  async fn listen() -> std::net::SocketAddr {
+    let data_model = sdml_parser::parse(SDML).unwrap();
+    let schema = Schema::build(SDML, Arc::new(MemoryStore::new(&data_model)))
+      .finish()
+      .unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(serve(listener, schema));
+    addr
+  }
+
+  async fn send(addr: std::net::SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+  }
+
+  fn post(body: &str) -> String {
+    format!(
+      "POST /graphql HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+      body.len()
+    )
+  }
+
+  #[tokio::test]
+  async fn test_serve() {
+    let addr = listen().await;
+    let response = send(
+      addr,
+      &post(
+        r#"{"query": "mutation { createUser(data: {email: \"alice@x.com\"}) { id } }"}"#,
+      ),
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+    assert!(
+      response.ends_with(r#"{"data":{"createUser":{"id":"alice@x.com"}}}"#),
+      "{response}"
+    );
+
+    let response = send(
+      addr,
+      &post(r#"[{"query": "{ users { id } }"}, {"query": "{ users { name } }"}]"#),
+    )
+    .await;
+    assert!(
+      response.ends_with(
+        r#"[{"data":{"users":[{"id":"alice@x.com"}]}},{"data":{"users":[{"name":null}]}}]"#
+      ),
+      "{response}"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_serve_error() {
+    let addr = listen().await;
+    let response = send(addr, "GET /graphql HTTP/1.1\r\n\r\n").await;
+    assert!(response.starts_with("HTTP/1.1 405 "), "{response}");
+    let response = send(addr, "POST /api HTTP/1.1\r\n\r\n").await;
+    assert!(response.starts_with("HTTP/1.1 404 "), "{response}");
+    let response = send(addr, &post("{")).await;
+    assert!(response.starts_with("HTTP/1.1 400 "), "{response}");
+    let response = send(
+      addr,
+      &format!(
+        "POST /graphql HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+        MAX_BODY_SIZE + 1
+      ),
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 413 "), "{response}");
+  }
+}
@@ -1,8 +1,8 @@
-use std::{borrow::Cow, future::Future, task::Context};
+use std::{borrow::Cow, future::Future};
 
 use crate::{
   graphql_parser::types::Directive, graphql_value::ConstValue, registry::Registry,
-  ContextDirective, ServerResult,
+  Context, ContextDirective, ServerResult,
 };
 
 pub type ResolveFut<'a> =
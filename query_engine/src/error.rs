@@ -284,3 +284,39 @@ pub enum ParseRequestError {
   #[error("Batch request are not supported.")]
   UnsupportedBatch,
 }
+
+/// Error building the [Schema](crate::Schema) from the SDML source.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum SchemaError {
+  /// SDML source has syntax or semantic errors.
+  #[error("{}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"))]
+  Sdml(Vec<sdml_parser::Error>),
+
+  /// OpenCRUD API can't be generated for the data model.
+  #[error("{0}")]
+  GraphQLGen(#[from] schema_transpiler::ErrorGraphQLGen),
+
+  /// Generated OpenCRUD API isn't a valid GraphQL schema.
+  #[error("{0}")]
+  GraphQLParse(#[from] parser::Error),
+}
+
+impl From<Vec<sdml_parser::Error>> for SchemaError {
+  fn from(errors: Vec<sdml_parser::Error>) -> Self {
+    SchemaError::Sdml(errors)
+  }
+}
+
+/// Data store error, its stable code is set as the `code` extension.
+impl From<db_engine::Error> for Error {
+  fn from(error: db_engine::Error) -> Self {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", error.code());
+    Error {
+      message: error.to_string(),
+      source: Some(Arc::new(error)),
+      extensions: Some(extensions),
+    }
+  }
+}
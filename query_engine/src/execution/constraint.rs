@@ -0,0 +1,443 @@
+//! Checks the values of the create & update inputs against the validation
+//! constraints of the fields (`@length`, `@range`, `@pattern` & `@email`),
+//! before the inputs are passed to the data store.
+use std::collections::HashMap;
+
+use super::*;
+use crate::{ErrorExtensionValues, PathSegment};
+use db_engine::db_crud::FIELD_ID;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use sdml_parser::types::{FieldConstraint, FieldDecl, ModelDecl, Type};
+
+/// Argument holding the create or update input.
+const ARG_DATA: &str = "data";
+
+/// Nested relation inputs, holding the create & update inputs of the related model.
+const INPUT_CREATE: &str = "create";
+const INPUT_UPDATE: &str = "update";
+const INPUT_UPSERT: &str = "upsert";
+const INPUT_DATA: &str = "data";
+
+/// Stable code of the constraint violations, set as the `code` extension.
+const CODE_CONSTRAINT_VIOLATION: &str = "CONSTRAINT_VIOLATION";
+
+/// Note: Only the basic shape is checked, `local@domain.tld`.
+static EMAIL: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s.]+$").unwrap());
+
+/// Constraints of the fields, by the model name & the field name.
+/// Built once along with the schema, so that the patterns are compiled only once.
+#[derive(Default)]
+pub(crate) struct Constraints(HashMap<String, HashMap<String, Vec<Constraint>>>);
+
+/// Constraint of the field, along with the compiled regular expression of `@pattern`.
+struct Constraint {
+  constraint: FieldConstraint,
+  regex: Option<Regex>,
+}
+
+impl Constraints {
+  pub(crate) fn new(data_model: &DataModel) -> Self {
+    let mut constraints: HashMap<_, HashMap<_, _>> = HashMap::new();
+    for (model_name, model) in &data_model.models {
+      for field in &model.fields {
+        let field_constraints: Vec<_> = field
+          .constraints()
+          .into_iter()
+          .map(|constraint| Constraint {
+            // Note: Patterns are checked to be valid regular expressions by the SDML parser.
+            regex: match &constraint {
+              FieldConstraint::Pattern(pattern) => Regex::new(pattern).ok(),
+              _ => None,
+            },
+            constraint,
+          })
+          .collect();
+        if let (false, Some(field_name)) =
+          (field_constraints.is_empty(), field.name.ident_name())
+        {
+          constraints
+            .entry(model_name.clone())
+            .or_default()
+            .insert(field_name, field_constraints);
+        }
+      }
+    }
+    Constraints(constraints)
+  }
+
+  fn get(&self, model: &ModelDecl, field: &FieldDecl) -> &[Constraint] {
+    model
+      .name
+      .ident_name()
+      .zip(field.name.ident_name())
+      .and_then(|(model_name, field_name)| self.0.get(&model_name)?.get(&field_name))
+      .map_or(&[], Vec::as_slice)
+  }
+}
+
+/// Input passed in the `data` argument.
+#[derive(Clone, Copy)]
+pub(super) enum Input {
+  Create,
+  Update,
+  /// Holds both the create & the update inputs.
+  Upsert,
+}
+
+/// Checks the `data` argument of the field being resolved, the error path points
+/// at the input field violating the constraint.
+pub(super) fn check_data(
+  ctx: &Context<'_>,
+  model_name: &str,
+  input: Input,
+) -> ServerResult<()> {
+  let env = &ctx.schema_env;
+  let Some(model) = env.data_model.models.get(model_name) else {
+    return Ok(());
+  };
+  let mut path = vec![PathSegment::Field(ARG_DATA.to_string())];
+  let value = arg_value(ctx, ARG_DATA)?;
+  check_input(
+    &env.data_model,
+    &env.constraints,
+    model,
+    input,
+    &value,
+    &mut path,
+  )
+  .map_err(|message| {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", CODE_CONSTRAINT_VIOLATION);
+    let mut error = field_error(
+      ctx,
+      Error {
+        message,
+        source: None,
+        extensions: Some(extensions),
+      },
+    );
+    error.path.extend(path);
+    error
+  })
+}
+
+/// Checks the input object of the model.
+/// Note: On error, the path is left pointing at the offending input field.
+fn check_input(
+  data_model: &DataModel,
+  constraints: &Constraints,
+  model: &ModelDecl,
+  input: Input,
+  value: &Value,
+  path: &mut Vec<PathSegment>,
+) -> Result<(), String> {
+  let Value::Object(values) = value else {
+    return Ok(());
+  };
+  if let Input::Upsert = input {
+    for (name, input) in [(INPUT_CREATE, Input::Create), (INPUT_UPDATE, Input::Update)] {
+      if let Some(value) = values.get(name) {
+        nested(path, PathSegment::Field(name.to_string()), |path| {
+          check_input(data_model, constraints, model, input, value, path)
+        })?;
+      }
+    }
+    return Ok(());
+  }
+  for (name, value) in values {
+    // Note: Unknown fields are reported while converting the input.
+    let Some(field) = model.fields.iter().find(|field| {
+      if name.as_str() == FIELD_ID && field.has_id_attrib() {
+        return true;
+      }
+      field.name.ident_name().as_deref() == Some(name.as_str())
+    }) else {
+      continue;
+    };
+    nested(path, PathSegment::Field(name.to_string()), |path| {
+      match field.field_type.r#type() {
+        Type::Relation(edge) => {
+          let Some(target) = edge
+            .referenced_model_name()
+            .ident_name()
+            .and_then(|model_name| data_model.models.get(&model_name))
+          else {
+            return Ok(());
+          };
+          check_relation_input(data_model, constraints, target, value, path)
+        }
+        // Note: Attributes aren't allowed on the composite type fields.
+        Type::Composite { .. } => Ok(()),
+        _ => check_value(constraints, model, field, value, path),
+      }
+    })?;
+  }
+  Ok(())
+}
+
+/// Checks the nested create & update inputs of the relation input.
+fn check_relation_input(
+  data_model: &DataModel,
+  constraints: &Constraints,
+  target: &ModelDecl,
+  value: &Value,
+  path: &mut Vec<PathSegment>,
+) -> Result<(), String> {
+  let Value::Object(values) = value else {
+    return Ok(());
+  };
+  for (name, value) in values {
+    let input = match name.as_str() {
+      INPUT_CREATE => Input::Create,
+      INPUT_UPDATE => Input::Update,
+      INPUT_UPSERT => Input::Upsert,
+      _ => continue,
+    };
+    nested(path, PathSegment::Field(name.to_string()), |path| {
+      items(value, path, |value, path| match input {
+        Input::Create => check_input(data_model, constraints, target, input, value, path),
+        // Note: Nested updates & upserts hold their input in the `data` field.
+        Input::Update | Input::Upsert => match value {
+          Value::Object(nested_values) => match nested_values.get(INPUT_DATA) {
+            Some(value) => {
+              nested(path, PathSegment::Field(INPUT_DATA.to_string()), |path| {
+                check_input(data_model, constraints, target, input, value, path)
+              })
+            }
+            None => Ok(()),
+          },
+          _ => Ok(()),
+        },
+      })
+    })?;
+  }
+  Ok(())
+}
+
+/// Checks the value (or each item of the list value) of the field against its
+/// constraints.
+fn check_value(
+  constraints: &Constraints,
+  model: &ModelDecl,
+  field: &FieldDecl,
+  value: &Value,
+  path: &mut Vec<PathSegment>,
+) -> Result<(), String> {
+  let constraints = constraints.get(model, field);
+  if constraints.is_empty() {
+    return Ok(());
+  }
+  items(value, path, |value, _path| {
+    for constraint in constraints {
+      if let Some(expected) = violation(constraint, value) {
+        return Err(format!(
+          r#"Value of the field "{}.{}" should {expected}."#,
+          model.name.ident_name().unwrap(),
+          field.name.ident_name().unwrap()
+        ));
+      }
+    }
+    Ok(())
+  })
+}
+
+/// What the value is expected to be, if it violates the constraint.
+/// Note: `null` is never a violation, required fields are checked by the schema.
+fn violation(constraint: &Constraint, value: &Value) -> Option<String> {
+  let bounds = |min: Option<String>, max: Option<String>| match (min, max) {
+    (Some(min), Some(max)) => format!("{min} to {max}"),
+    (Some(min), None) => format!("at least {min}"),
+    (None, Some(max)) => format!("at most {max}"),
+    (None, None) => String::new(),
+  };
+  match (&constraint.constraint, value) {
+    (FieldConstraint::Length { min, max }, Value::String(str)) => {
+      let length = str.chars().count();
+      (min.is_some_and(|min| length < min) || max.is_some_and(|max| length > max)).then(
+        || {
+          format!(
+            "be {} characters long",
+            bounds(
+              min.map(|min| min.to_string()),
+              max.map(|max| max.to_string())
+            )
+          )
+        },
+      )
+    }
+    (FieldConstraint::Range { min, max }, Value::Number(_) | Value::String(_)) => {
+      // Note: `Long` & `Decimal` values may be inputted as numeric strings.
+      let number = match value {
+        Value::Number(number) => number.as_f64()?,
+        Value::String(number) => number.trim().parse::<f64>().ok()?,
+        _ => return None,
+      };
+      (min.is_some_and(|min| number < min) || max.is_some_and(|max| number > max)).then(
+        || {
+          format!(
+            "be {}",
+            bounds(
+              min.map(|min| min.to_string()),
+              max.map(|max| max.to_string())
+            )
+          )
+        },
+      )
+    }
+    (FieldConstraint::Pattern(pattern), Value::String(str)) => {
+      let regex = constraint.regex.as_ref()?;
+      (!regex.is_match(str)).then(|| format!(r#"match the pattern "{pattern}""#))
+    }
+    (FieldConstraint::Email, Value::String(str)) => {
+      (!EMAIL.is_match(str)).then(|| "be an email address".to_string())
+    }
+    _ => None,
+  }
+}
+
+/// Checks each item of the list value, or the value itself if it isn't a list.
+fn items(
+  value: &Value,
+  path: &mut Vec<PathSegment>,
+  mut check: impl FnMut(&Value, &mut Vec<PathSegment>) -> Result<(), String>,
+) -> Result<(), String> {
+  match value {
+    Value::List(items) => items.iter().enumerate().try_for_each(|(index, item)| {
+      nested(path, PathSegment::Index(index), |path| check(item, path))
+    }),
+    value => check(value, path),
+  }
+}
+
+/// Checks the nested input at the path segment, the segment is kept on error.
+fn nested(
+  path: &mut Vec<PathSegment>,
+  segment: PathSegment,
+  check: impl FnOnce(&mut Vec<PathSegment>) -> Result<(), String>,
+) -> Result<(), String> {
+  path.push(segment);
+  check(path)?;
+  path.pop();
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::graphql_value::value;
+
+  const SDML: &str = r#"
+    model User {
+      userId    ShortStr  @id @default(auto())
+      email     ShortStr  @unique @email
+      name      ShortStr? @length(min: 1, max: 8)
+      credits   Int64?    @range(min: 0, max: 10)
+      posts     Post[]    @relation(name: "UserPosts")
+    }
+
+    model Post {
+      postId    ShortStr  @id @default(auto())
+      title     ShortStr  @pattern("^[A-Z]")
+      author    User      @relation(name: "UserPosts", field: authorId, references: userId)
+      authorId  ShortStr
+    }
+  "#;
+
+  /// Checks the input of the user, returns the error along with its path.
+  fn check_user(input: Input, value: Value) -> Result<(), (String, Vec<PathSegment>)> {
+    let data_model = sdml_parser::parse(SDML).unwrap();
+    let constraints = Constraints::new(&data_model);
+    let mut path = Vec::new();
+    check_input(
+      &data_model,
+      &constraints,
+      &data_model.models["User"],
+      input,
+      &value,
+      &mut path,
+    )
+    .map_err(|message| (message, path))
+  }
+
+  fn field(name: &str) -> PathSegment {
+    PathSegment::Field(name.to_string())
+  }
+
+  #[test]
+  fn test_check_input() {
+    assert_eq!(
+      check_user(Input::Create, value!({"email": "mohan", "name": "Mohan"})),
+      Err((
+        r#"Value of the field "User.email" should be an email address."#.to_string(),
+        vec![field("email")]
+      ))
+    );
+    assert_eq!(
+      check_user(Input::Update, value!({"name": "Mohan Kumar"})),
+      Err((
+        r#"Value of the field "User.name" should be 1 to 8 characters long."#.to_string(),
+        vec![field("name")]
+      ))
+    );
+    // Note: `Long` values inputted as the numeric strings are checked as well.
+    assert_eq!(
+      check_user(
+        Input::Upsert,
+        value!({"create": {"email": "mohan@beusefultech.com", "credits": "99999"}})
+      ),
+      Err((
+        r#"Value of the field "User.credits" should be 0 to 10."#.to_string(),
+        vec![field("create"), field("credits")]
+      ))
+    );
+    assert_eq!(
+      check_user(
+        Input::Create,
+        value!({"email": "mohan@beusefultech.com", "name": null, "credits": 10})
+      ),
+      Ok(())
+    );
+  }
+
+  #[test]
+  fn test_check_relation_input() {
+    assert_eq!(
+      check_user(
+        Input::Create,
+        value!({
+          "email": "mohan@beusefultech.com",
+          "posts": {"create": [{"title": "Hello"}, {"title": "world"}]},
+        })
+      ),
+      Err((
+        r#"Value of the field "Post.title" should match the pattern "^[A-Z]"."#
+          .to_string(),
+        vec![
+          field("posts"),
+          field("create"),
+          PathSegment::Index(1),
+          field("title")
+        ]
+      ))
+    );
+    assert_eq!(
+      check_user(
+        Input::Update,
+        value!({"posts": {"update": [{"where": {}, "data": {"title": "world"}}]}})
+      ),
+      Err((
+        r#"Value of the field "Post.title" should match the pattern "^[A-Z]"."#
+          .to_string(),
+        vec![
+          field("posts"),
+          field("update"),
+          PathSegment::Index(0),
+          field("data"),
+          field("title")
+        ]
+      ))
+    );
+  }
+}
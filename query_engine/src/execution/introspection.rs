@@ -0,0 +1,218 @@
+//! Resolves the introspection fields (`__schema` & `__type`) from the registry.
+use super::*;
+use crate::{
+  introspection::{
+    types::{__Directive, __EnumValue, __Field, __InputValue, __Schema, __Type},
+    FIELD_SCHEMA, FIELD_TYPE,
+  },
+  IntrospectionMode,
+};
+
+/// Object of the introspection system.
+#[derive(Clone)]
+pub(super) enum Introspection<'a> {
+  Schema(__Schema<'a>),
+  Type(__Type<'a>),
+  Field(__Field<'a>),
+  InputValue(__InputValue<'a>),
+  EnumValue(__EnumValue<'a>),
+  Directive(__Directive<'a>),
+}
+
+/// Introspection mode in effect for the request, which is the more
+/// restrictive one of the schema's and the request's modes.
+pub(super) fn introspection_mode<T>(ctx: &ContextBase<'_, T>) -> IntrospectionMode {
+  match (
+    ctx.schema_env.registry.introspection_mode,
+    ctx.query_env.introspection_mode,
+  ) {
+    (IntrospectionMode::Disabled, _) | (_, IntrospectionMode::Disabled) => {
+      IntrospectionMode::Disabled
+    }
+    (IntrospectionMode::IntrospectionOnly, _)
+    | (_, IntrospectionMode::IntrospectionOnly) => IntrospectionMode::IntrospectionOnly,
+    _ => IntrospectionMode::Enabled,
+  }
+}
+
+/// Checks if the root field is an introspection field.
+pub(super) fn is_introspection_field(field: &MetaField) -> bool {
+  field.name == FIELD_SCHEMA || field.name == FIELD_TYPE
+}
+
+/// Resolves the root `__schema` & `__type` fields.
+pub(super) fn resolve_root_field<'a>(
+  ctx: &Context<'a>,
+  field: &MetaField,
+) -> ServerResult<Resolved<'a>> {
+  let registry = &ctx.schema_env.registry;
+  if introspection_mode(ctx) == IntrospectionMode::Disabled {
+    return Err(field_error(
+      ctx,
+      Error::new(format!(
+        r#"Unknown field "{}" on type "{}"."#,
+        field.name, registry.query_type
+      )),
+    ));
+  }
+  if field.name == FIELD_SCHEMA {
+    return Ok(object(Introspection::Schema(__Schema::new(registry))));
+  }
+  match arg_value(ctx, "name")? {
+    Value::String(name) => Ok(registry.types.get(&name).map_or(Resolved::null(), |ty| {
+      object(Introspection::Type(__Type::new_simple(registry, ty)))
+    })),
+    _ => Err(field_error(
+      ctx,
+      Error::new(r#"Argument "name" should be a string."#),
+    )),
+  }
+}
+
+/// Resolves the field of the introspection object.
+pub(super) fn resolve_field<'a>(
+  ctx: &Context<'a>,
+  field: &MetaField,
+  introspection: &Introspection<'a>,
+) -> ServerResult<Resolved<'a>> {
+  let resolved = match (introspection, field.name.as_str()) {
+    (Introspection::Schema(schema), "description") => string(Some(&schema.description())),
+    (Introspection::Schema(schema), "types") => list(schema.types(), Introspection::Type),
+    (Introspection::Schema(schema), "queryType") => {
+      object(Introspection::Type(schema.query_type()))
+    }
+    (Introspection::Schema(schema), "mutationType") => {
+      optional(schema.mutation_type(), Introspection::Type)
+    }
+    (Introspection::Schema(schema), "subscriptionType") => {
+      optional(schema.subscription_type(), Introspection::Type)
+    }
+    (Introspection::Schema(schema), "directives") => {
+      list(schema.directives(), Introspection::Directive)
+    }
+
+    (Introspection::Type(ty), "kind") => {
+      Resolved::Value(Value::Enum(Name::new(ty.kind().name())))
+    }
+    (Introspection::Type(ty), "name") => string(ty.name()),
+    (Introspection::Type(ty), "description") => string(ty.description()),
+    (Introspection::Type(ty), "fields") => {
+      optional_list(ty.fields(include_deprecated(ctx)?), Introspection::Field)
+    }
+    (Introspection::Type(ty), "interfaces") => {
+      optional_list(ty.interfaces(), Introspection::Type)
+    }
+    (Introspection::Type(ty), "possibleTypes") => {
+      optional_list(ty.possible_types(), Introspection::Type)
+    }
+    (Introspection::Type(ty), "enumValues") => optional_list(
+      ty.enum_values(include_deprecated(ctx)?),
+      Introspection::EnumValue,
+    ),
+    (Introspection::Type(ty), "inputFields") => {
+      optional_list(ty.input_fields(), Introspection::InputValue)
+    }
+    (Introspection::Type(ty), "ofType") => optional(ty.of_type(), Introspection::Type),
+    (Introspection::Type(ty), "specifiedByURL") => string(ty.specified_by_url()),
+    (Introspection::Type(ty), "isOneOf") => {
+      Resolved::Value(ty.is_one_of().map_or(Value::Null, Value::Boolean))
+    }
+
+    (Introspection::Field(field), "name") => string(Some(field.name())),
+    (Introspection::Field(field), "description") => string(field.description()),
+    (Introspection::Field(field), "args") => {
+      list(field.args(), Introspection::InputValue)
+    }
+    (Introspection::Field(field), "type") => object(Introspection::Type(field.r#type())),
+    (Introspection::Field(field), "isDeprecated") => {
+      Resolved::Value(Value::Boolean(field.is_deprecated()))
+    }
+    (Introspection::Field(field), "deprecationReason") => {
+      string(field.deprecation_reason())
+    }
+
+    (Introspection::InputValue(input_value), "name") => string(Some(input_value.name())),
+    (Introspection::InputValue(input_value), "description") => {
+      string(input_value.description())
+    }
+    (Introspection::InputValue(input_value), "type") => {
+      object(Introspection::Type(input_value.r#type()))
+    }
+    (Introspection::InputValue(input_value), "defaultValue") => {
+      string(input_value.default_value())
+    }
+    (Introspection::InputValue(_), "isDeprecated") => {
+      Resolved::Value(Value::Boolean(false))
+    }
+    (Introspection::InputValue(_), "deprecationReason") => Resolved::null(),
+
+    (Introspection::EnumValue(enum_value), "name") => string(Some(enum_value.name())),
+    (Introspection::EnumValue(enum_value), "description") => {
+      string(enum_value.description())
+    }
+    (Introspection::EnumValue(enum_value), "isDeprecated") => {
+      Resolved::Value(Value::Boolean(enum_value.is_deprecated()))
+    }
+    (Introspection::EnumValue(enum_value), "deprecationReason") => {
+      string(enum_value.deprecation_reason())
+    }
+
+    (Introspection::Directive(directive), "name") => string(Some(directive.name())),
+    (Introspection::Directive(directive), "description") => {
+      string(directive.description())
+    }
+    (Introspection::Directive(directive), "locations") => Resolved::List(
+      directive
+        .locations()
+        .iter()
+        .map(|location| {
+          Resolved::Value(Value::Enum(Name::new(format!("{:?}", location))))
+        })
+        .collect(),
+    ),
+    (Introspection::Directive(directive), "args") => {
+      list(directive.args(), Introspection::InputValue)
+    }
+    (Introspection::Directive(directive), "isRepeatable") => {
+      Resolved::Value(Value::Boolean(directive.is_repeatable()))
+    }
+
+    _ => return Err(unknown_field(ctx, field)),
+  };
+  Ok(resolved)
+}
+
+/// Value of the `includeDeprecated` argument, defaults to `false`.
+fn include_deprecated(ctx: &Context<'_>) -> ServerResult<bool> {
+  match arg_value(ctx, "includeDeprecated")? {
+    Value::Null => Ok(false),
+    Value::Boolean(include_deprecated) => Ok(include_deprecated),
+    _ => Err(field_error(
+      ctx,
+      Error::new(r#"Argument "includeDeprecated" should be a boolean."#),
+    )),
+  }
+}
+
+fn object(introspection: Introspection<'_>) -> Resolved<'_> {
+  Resolved::Object(ObjectValue::Introspection(introspection))
+}
+
+fn string(value: Option<&str>) -> Resolved<'static> {
+  Resolved::Value(value.map_or(Value::Null, |value| Value::String(value.to_string())))
+}
+
+fn optional<'a, T>(item: Option<T>, to: fn(T) -> Introspection<'a>) -> Resolved<'a> {
+  item.map_or(Resolved::null(), |item| object(to(item)))
+}
+
+fn list<'a, T>(items: Vec<T>, to: fn(T) -> Introspection<'a>) -> Resolved<'a> {
+  Resolved::List(items.into_iter().map(|item| object(to(item))).collect())
+}
+
+fn optional_list<'a, T>(
+  items: Option<Vec<T>>,
+  to: fn(T) -> Introspection<'a>,
+) -> Resolved<'a> {
+  items.map_or(Resolved::null(), |items| list(items, to))
+}
@@ -1 +1,1252 @@
+//! Executes the operation in the request against the data store.
+//!
+//! Root `Query` & `Mutation` fields are resolved by calling into the model
+//! specific [DBQuery](db_engine::db_crud::DBQuery) and
+//! [DBMutation](db_engine::db_crud::DBMutation) interfaces, and the returned
+//! objects are completed as per the selection set.
+mod constraint;
+mod introspection;
+mod object;
+mod root;
 
+pub(crate) use constraint::Constraints;
+
+use std::sync::Arc;
+
+use crate::{
+  graphql_parser::{
+    types::{
+      Directive, DocumentOperations, Field, OperationDefinition, Selection, SelectionSet,
+    },
+    Positioned,
+  },
+  graphql_value::{ConstValue as Value, Name},
+  registry::{MetaField, MetaType, MetaTypeName},
+  Context, ContextBase, ContextSelectionSet, DataModel, Error, ServerError, ServerResult,
+};
+use db_engine::db_crud::{DBObject, ObjectConnection, PageInfo, Pagination, ID};
+use futures_util::future::{self, BoxFuture};
+use indexmap::IndexMap;
+
+/// Fields of a selection set grouped by their response key.
+type CollectedFields<'a> = IndexMap<Name, Vec<&'a Positioned<Field>>>;
+
+/// Returns the operation to be executed from the document.
+pub(crate) fn get_operation(
+  operations: DocumentOperations,
+  operation_name: Option<&str>,
+) -> ServerResult<Positioned<OperationDefinition>> {
+  match operations {
+    DocumentOperations::Single(operation) => Ok(operation),
+    DocumentOperations::Multiple(mut operations) => match operation_name {
+      Some(operation_name) => operations.remove(operation_name).ok_or_else(|| {
+        ServerError::new(
+          format!(r#"Unknown operation named "{}"."#, operation_name),
+          None,
+        )
+      }),
+      None if operations.len() == 1 => Ok(operations.into_values().next().unwrap()),
+      None => Err(ServerError::new(
+        "Operation name is required, when document has more than one operation.",
+        None,
+      )),
+    },
+  }
+}
+
+/// Resolves the selection set of the operation against the root type.
+/// ### Arguments
+/// * `ctx` - context of the operation's selection set.
+/// * `root_type` - root `Query` or `Mutation` type.
+/// * `serial` - resolve the root fields one after the other (mutations).
+pub(crate) async fn resolve_root(
+  ctx: &ContextSelectionSet<'_>,
+  root_type: &MetaType,
+  serial: bool,
+) -> ServerResult<Value> {
+  resolve_object(ctx, root_type, vec![ctx.item], ObjectValue::Root, serial).await
+}
+
+/// Value from which the fields of an output object are resolved.
+#[derive(Clone)]
+enum ObjectValue<'a> {
+  /// Root `Query` or `Mutation` object.
+  Root,
+  /// Object of the model with the given name.
+  Object(Name, Arc<dyn DBObject>),
+  /// Connection of the objects.
+  Connection(Arc<Connection>),
+  /// Edge at the given index in the connection.
+  Edge(Arc<Connection>, usize),
+  /// Page info of the connection.
+  PageInfo(Arc<Connection>),
+  /// Aggregate of the connection.
+  Aggregate(Arc<Connection>),
+  /// Value of the composite type, embedded in the object.
+  Embedded(IndexMap<Name, Value>),
+  /// Object of the introspection system.
+  Introspection(introspection::Introspection<'a>),
+}
+
+impl ObjectValue<'_> {
+  /// Name of the concrete object type, if known from the value.
+  fn type_name(&self) -> Option<&str> {
+    match self {
+      ObjectValue::Object(model_name, _) => Some(model_name),
+      _ => None,
+    }
+  }
+}
+
+/// Objects of a model returned from the data store, along with pagination data.
+struct Connection {
+  model_name: Name,
+  page_info: PageInfo,
+  edges: Vec<(Arc<dyn DBObject>, ID)>,
+  count: usize,
+}
+
+impl Connection {
+  fn new(model_name: &str, connection: ObjectConnection) -> Self {
+    Connection {
+      model_name: Name::new(model_name),
+      page_info: connection.page_info,
+      edges: connection
+        .edges
+        .into_iter()
+        .map(|edge| (Arc::from(edge.node), edge.cursor))
+        .collect(),
+      count: connection.count,
+    }
+  }
+}
+
+/// Value returned by a field resolver, which is yet to be completed as per
+/// the field's type and selection set.
+enum Resolved<'a> {
+  /// Leaf value (scalar or enum) or null.
+  Value(Value),
+  /// Output object.
+  Object(ObjectValue<'a>),
+  /// List of values.
+  List(Vec<Resolved<'a>>),
+}
+
+impl Resolved<'_> {
+  fn null() -> Self {
+    Resolved::Value(Value::Null)
+  }
+
+  fn object(model_name: &str, object: Option<Box<dyn DBObject>>) -> Self {
+    object.map_or(Resolved::null(), |object| {
+      Resolved::Object(ObjectValue::Object(
+        Name::new(model_name),
+        Arc::from(object),
+      ))
+    })
+  }
+
+  fn objects(model_name: &str, connection: ObjectConnection) -> Self {
+    let model_name = Name::new(model_name);
+    Resolved::List(
+      connection
+        .edges
+        .into_iter()
+        .map(|edge| {
+          Resolved::Object(ObjectValue::Object(
+            model_name.clone(),
+            Arc::from(edge.node),
+          ))
+        })
+        .collect(),
+    )
+  }
+
+  fn connection(model_name: &str, connection: ObjectConnection) -> Self {
+    Resolved::Object(ObjectValue::Connection(Arc::new(Connection::new(
+      model_name, connection,
+    ))))
+  }
+}
+
+/// Kind of output of a field returning model object(s).
+enum ModelOutput<'a> {
+  /// Single object of the model.
+  Object(&'a str),
+  /// List of objects of the model.
+  List(&'a str),
+  /// Connection of objects of the model.
+  Connection(&'a str),
+}
+
+/// Finds the kind of model output for the given field type.
+/// Returns `None` if the field doesn't return model object(s).
+fn model_output<'a>(data_model: &DataModel, ty: &'a str) -> Option<ModelOutput<'a>> {
+  let type_name = MetaTypeName::concrete_typename(ty);
+  if data_model.models.contains_key(type_name) {
+    if MetaTypeName::create(ty).is_list() {
+      Some(ModelOutput::List(type_name))
+    } else {
+      Some(ModelOutput::Object(type_name))
+    }
+  } else {
+    type_name
+      .strip_suffix("Connection")
+      .filter(|model_name| data_model.models.contains_key(*model_name))
+      .map(ModelOutput::Connection)
+  }
+}
+
+/// Value of the argument passed to the field being resolved.
+/// Returns `Value::Null` if the argument is not passed.
+fn arg_value(ctx: &Context<'_>, name: &str) -> ServerResult<Value> {
+  match ctx.item.node.get_argument(name) {
+    Some(value) => ctx
+      .resolve_input_value(value.clone())
+      .map_err(|error| ctx.set_error_path(error)),
+    None => Ok(Value::Null),
+  }
+}
+
+/// Typed input of the model, converted from the argument passed to the field
+/// being resolved.
+fn arg_input<T>(
+  ctx: &Context<'_>,
+  name: &str,
+  model_name: &str,
+  from_value: fn(&DataModel, &str, Value) -> Result<T, db_engine::Error>,
+) -> ServerResult<T> {
+  from_value(
+    &ctx.schema_env.data_model,
+    model_name,
+    arg_value(ctx, name)?,
+  )
+  .map_err(|error| field_error(ctx, error))
+}
+
+/// Pagination arguments passed to the field being resolved.
+fn pagination_args(ctx: &Context<'_>) -> ServerResult<Pagination> {
+  let count_arg = |name: &str| match arg_value(ctx, name)? {
+    Value::Null => Ok(None),
+    Value::Number(count) => count
+      .as_u64()
+      .and_then(|count| u32::try_from(count).ok())
+      .map(Some)
+      .ok_or_else(|| {
+        field_error(
+          ctx,
+          Error::new(format!(
+            r#"Argument "{}" should be a non-negative integer."#,
+            name
+          )),
+        )
+      }),
+    _ => Err(field_error(
+      ctx,
+      Error::new(format!(r#"Argument "{}" should be an integer."#, name)),
+    )),
+  };
+  let cursor_arg = |name: &str| match arg_value(ctx, name)? {
+    Value::Null => Ok(None),
+    Value::String(cursor) => Ok(Some(ID::new(cursor))),
+    _ => Err(field_error(
+      ctx,
+      Error::new(format!(r#"Argument "{}" should be an ID."#, name)),
+    )),
+  };
+  Ok(Pagination {
+    skip: count_arg("skip")?,
+    after: cursor_arg("after")?,
+    first: count_arg("first")?,
+    before: cursor_arg("before")?,
+    last: count_arg("last")?,
+  })
+}
+
+/// Creates an error at the position and path of the field being resolved.
+fn field_error(ctx: &Context<'_>, error: impl Into<Error>) -> ServerError {
+  ctx.set_error_path(error.into().into_server_error(ctx.item.pos))
+}
+
+fn unknown_field(ctx: &Context<'_>, field: &MetaField) -> ServerError {
+  field_error(
+    ctx,
+    Error::new(format!(r#"Field "{}" can't be resolved."#, field.name)),
+  )
+}
+
+fn resolve_object<'a>(
+  ctx: &'a ContextSelectionSet<'a>,
+  ty: &'a MetaType,
+  selection_sets: Vec<&'a Positioned<SelectionSet>>,
+  object: ObjectValue<'a>,
+  serial: bool,
+) -> BoxFuture<'a, ServerResult<Value>> {
+  Box::pin(async move {
+    let mut fields = CollectedFields::new();
+    for selection_set in selection_sets {
+      collect_fields(ctx, ty, selection_set, &mut fields)?;
+    }
+
+    let mut values = IndexMap::with_capacity(fields.len());
+    if serial {
+      for (response_key, fields) in &fields {
+        let ctx_field = ctx.with_field(fields[0]);
+        let value = resolve_field(&ctx_field, ty, fields, &object).await?;
+        values.insert(response_key.clone(), value);
+      }
+    } else {
+      let results = future::join_all(fields.iter().map(|(response_key, fields)| {
+        let object = &object;
+        async move {
+          let ctx_field = ctx.with_field(fields[0]);
+          let value = resolve_field(&ctx_field, ty, fields, object).await;
+          (response_key.clone(), value)
+        }
+      }))
+      .await;
+      for (response_key, value) in results {
+        values.insert(response_key, value?);
+      }
+    }
+    Ok(Value::Object(values))
+  })
+}
+
+/// Collects the fields to be resolved on the object type `ty`, from the selection set
+/// and the fragments in it.
+fn collect_fields<'a>(
+  ctx: &ContextSelectionSet<'a>,
+  ty: &MetaType,
+  selection_set: &'a Positioned<SelectionSet>,
+  fields: &mut CollectedFields<'a>,
+) -> ServerResult<()> {
+  for selection in &selection_set.node.items {
+    if is_skipped(ctx, selection.node.directives())? {
+      continue;
+    }
+    match &selection.node {
+      Selection::Field(field) => fields
+        .entry(field.node.response_key().node.clone())
+        .or_default()
+        .push(field),
+      Selection::FragmentSpread(fragment_spread) => {
+        let fragment_name = &fragment_spread.node.fragment_name.node;
+        let fragment = ctx.query_env.fragments.get(fragment_name).ok_or_else(|| {
+          ServerError::new(
+            format!(r#"Unknown fragment "{}"."#, fragment_name),
+            Some(fragment_spread.pos),
+          )
+        })?;
+        if does_fragment_apply(ctx, ty, &fragment.node.type_condition.node.on.node) {
+          collect_fields(ctx, ty, &fragment.node.selection_set, fields)?;
+        }
+      }
+      Selection::InlineFragment(inline_fragment) => {
+        let applies = inline_fragment
+          .node
+          .type_condition
+          .as_ref()
+          .is_none_or(|condition| does_fragment_apply(ctx, ty, &condition.node.on.node));
+        if applies {
+          collect_fields(ctx, ty, &inline_fragment.node.selection_set, fields)?;
+        }
+      }
+    }
+  }
+  Ok(())
+}
+
+fn does_fragment_apply<T>(ctx: &ContextBase<'_, T>, ty: &MetaType, on: &str) -> bool {
+  ty.name() == on
+    || ctx
+      .schema_env
+      .registry
+      .types
+      .get(on)
+      .is_some_and(|on| ctx.schema_env.registry.is_possible_type(on, ty.name()))
+}
+
+/// Evaluates `@skip` and `@include` directives.
+fn is_skipped<T>(
+  ctx: &ContextBase<'_, T>,
+  directives: &[Positioned<Directive>],
+) -> ServerResult<bool> {
+  for directive in directives {
+    let include = match directive.node.name.node.as_str() {
+      "skip" => false,
+      "include" => true,
+      _ => continue,
+    };
+    let condition = directive.node.get_argument("if").ok_or_else(|| {
+      ServerError::new(
+        format!(
+          r#"Directive "@{}" requires argument "if"."#,
+          directive.node.name.node
+        ),
+        Some(directive.pos),
+      )
+    })?;
+    match ctx.resolve_input_value(condition.clone())? {
+      Value::Boolean(condition) if condition != include => return Ok(true),
+      Value::Boolean(_) => {}
+      _ => {
+        return Err(ServerError::new(
+          r#"Argument "if" should be a boolean."#,
+          Some(condition.pos),
+        ))
+      }
+    }
+  }
+  Ok(false)
+}
+
+async fn resolve_field<'a>(
+  ctx: &Context<'a>,
+  parent_type: &MetaType,
+  fields: &[&Positioned<Field>],
+  object: &ObjectValue<'a>,
+) -> ServerResult<Value> {
+  let field_name = ctx.item.node.name.node.as_str();
+  if field_name == "__typename" {
+    return Ok(Value::String(parent_type.name().to_string()));
+  }
+  let Some(field) = parent_type.field_by_name(field_name) else {
+    return Err(field_error(
+      ctx,
+      Error::new(format!(
+        r#"Unknown field "{}" on type "{}"."#,
+        field_name,
+        parent_type.name()
+      )),
+    ));
+  };
+
+  let mut resolve: BoxFuture<'_, ServerResult<Option<Value>>> = Box::pin(async move {
+    let resolved = match object {
+      ObjectValue::Root if parent_type.name() == ctx.schema_env.registry.query_type => {
+        root::resolve_query_field(ctx, field).await
+      }
+      ObjectValue::Root => root::resolve_mutation_field(ctx, field).await,
+      ObjectValue::Introspection(object) => {
+        introspection::resolve_field(ctx, field, object)
+      }
+      object => object::resolve_field(ctx, field, object).await,
+    };
+    complete_value(ctx, &field.ty, fields, resolved)
+      .await
+      .map(Some)
+  });
+
+  // Custom directives wrap the field resolution, in the order of appearance.
+  for directive in &ctx.item.node.directives {
+    let Some(factory) = ctx
+      .schema_env
+      .custom_directives
+      .get(directive.node.name.node.as_str())
+    else {
+      continue;
+    };
+    let ctx_directive = ContextBase {
+      path_node: ctx.path_node,
+      is_for_introspection: ctx.is_for_introspection,
+      item: directive,
+      schema_env: ctx.schema_env,
+      query_env: ctx.query_env,
+      execute_data: ctx.execute_data,
+    };
+    let directive = factory.create(&ctx_directive, &directive.node)?;
+    let mut inner = resolve;
+    resolve = Box::pin(async move { directive.resolve_field(ctx, &mut inner).await });
+  }
+  Ok(resolve.await?.unwrap_or_default())
+}
+
+/// Completes the resolved value as per the type. If completing a value of
+/// nullable type fails, the error is reported and the value becomes null.
+fn complete_value<'a>(
+  ctx: &'a Context<'a>,
+  ty: &'a str,
+  fields: &'a [&'a Positioned<Field>],
+  resolved: ServerResult<Resolved<'a>>,
+) -> BoxFuture<'a, ServerResult<Value>> {
+  Box::pin(async move {
+    match MetaTypeName::create(ty) {
+      MetaTypeName::NonNull(ty) => {
+        match complete_nullable(ctx, ty, fields, resolved).await? {
+          Value::Null => Err(field_error(
+            ctx,
+            Error::new(format!(
+              r#"Cannot return null for non-nullable field "{}"."#,
+              ctx.item.node.name.node
+            )),
+          )),
+          value => Ok(value),
+        }
+      }
+      _ => match complete_nullable(ctx, ty, fields, resolved).await {
+        Ok(value) => Ok(value),
+        Err(error) => {
+          ctx.add_error(error);
+          Ok(Value::Null)
+        }
+      },
+    }
+  })
+}
+
+fn complete_nullable<'a>(
+  ctx: &'a Context<'a>,
+  ty: &'a str,
+  fields: &'a [&'a Positioned<Field>],
+  resolved: ServerResult<Resolved<'a>>,
+) -> BoxFuture<'a, ServerResult<Value>> {
+  Box::pin(async move {
+    match (MetaTypeName::create(ty), resolved?) {
+      (_, Resolved::Value(Value::Null)) => Ok(Value::Null),
+      (MetaTypeName::List(item_ty), Resolved::List(items)) => {
+        let mut values = Vec::with_capacity(items.len());
+        for (idx, item) in items.into_iter().enumerate() {
+          let ctx_item = ctx.with_index(idx);
+          values.push(complete_value(&ctx_item, item_ty, fields, Ok(item)).await?);
+        }
+        Ok(Value::List(values))
+      }
+      (MetaTypeName::List(_), Resolved::Value(Value::List(items))) => {
+        let items = items.into_iter().map(Resolved::Value).collect();
+        complete_nullable(ctx, ty, fields, Ok(Resolved::List(items))).await
+      }
+      (MetaTypeName::Named(type_name), Resolved::Value(value)) => {
+        match ctx.schema_env.registry.types.get(type_name) {
+          Some(MetaType::Scalar {
+            serialize: Some(serialize),
+            ..
+          }) => Ok(serialize(value)),
+          _ => Ok(value),
+        }
+      }
+      (MetaTypeName::Named(type_name), Resolved::Object(object)) => {
+        let type_name = object.type_name().unwrap_or(type_name);
+        let ty = ctx
+          .schema_env
+          .registry
+          .types
+          .get(type_name)
+          .ok_or_else(|| {
+            field_error(ctx, Error::new(format!(r#"Unknown type "{}"."#, type_name)))
+          })?;
+        let ctx_selection_set = ctx.with_selection_set(&ctx.item.node.selection_set);
+        let selection_sets = fields
+          .iter()
+          .map(|field| &field.node.selection_set)
+          .collect();
+        resolve_object(&ctx_selection_set, ty, selection_sets, object, false).await
+      }
+      _ => Err(field_error(
+        ctx,
+        Error::new(format!(r#"Resolved value is not of type "{}"."#, ty)),
+      )),
+    }
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{borrow::Cow, fs, sync::Arc};
+
+  use async_trait::async_trait;
+  use db_engine::{
+    db_crud::{
+      DBMutation, DBObject, DBQuery, DBStore, ObjectConnection, ObjectCreateInput,
+      ObjectEdge, ObjectOrderByInput, ObjectUpdateInput, ObjectUpsertInput,
+      ObjectWhereInput, ObjectWhereUniqueInput, Pagination, ID,
+    },
+    memory::MemoryStore,
+    Error as DBError,
+  };
+
+  use crate::{
+    graphql_parser::types::Directive,
+    graphql_value::{value, ConstValue as Value, Name, Variables},
+    introspection::types::__DirectiveLocation,
+    registry::{MetaDirective, Registry},
+    Context, ContextDirective, CustomDirective, CustomDirectiveFactory, Executor,
+    IntrospectionMode, PathSegment, Request, ResolveFut, Schema, SchemaBuilder,
+    ServerResult,
+  };
+
+  type Fields = indexmap::IndexMap<Name, Value>;
+
+  struct TestObject(Fields);
+
+  #[async_trait]
+  impl DBObject for TestObject {
+    async fn id(&self) -> ID {
+      match self.0.get("userId") {
+        Some(Value::String(id)) => ID::new(id),
+        _ => ID::new(""),
+      }
+    }
+
+    async fn field(&self, name: Name) -> Value {
+      self.0.get(&name).cloned().unwrap_or_default()
+    }
+
+    async fn relation(&self, _name: Name) -> Result<Option<Box<dyn DBObject>>, DBError> {
+      Ok(None)
+    }
+
+    async fn relations(
+      &self,
+      _name: Name,
+      _where: ObjectWhereInput,
+      _order_by: ObjectOrderByInput,
+      _pagination: Pagination,
+    ) -> Result<ObjectConnection, DBError> {
+      Ok(ObjectConnection::default())
+    }
+  }
+
+  /// Store holding `User` objects only.
+  struct TestStore(Vec<Fields>);
+
+  impl DBStore for TestStore {
+    fn query(&self, model_name: &str) -> Option<Box<dyn DBQuery>> {
+      (model_name == "User").then(|| Box::new(TestQuery(self.0.clone())) as _)
+    }
+
+    fn mutation(&self, model_name: &str) -> Option<Box<dyn DBMutation>> {
+      (model_name == "User").then(|| Box::new(TestMutation) as _)
+    }
+  }
+
+  struct TestQuery(Vec<Fields>);
+
+  #[async_trait]
+  impl DBQuery for TestQuery {
+    async fn get_object(
+      &self,
+      r#where: ObjectWhereUniqueInput,
+    ) -> Result<Option<Box<dyn DBObject>>, DBError> {
+      Ok(
+        self
+          .0
+          .iter()
+          .find(|fields| {
+            r#where
+              .0
+              .iter()
+              .all(|(name, value)| fields.get(name.as_str()) == Some(value))
+          })
+          .map(|fields| Box::new(TestObject(fields.clone())) as _),
+      )
+    }
+
+    async fn get_objects(
+      &self,
+      _where: ObjectWhereInput,
+      _order_by: ObjectOrderByInput,
+      _pagination: Pagination,
+    ) -> Result<ObjectConnection, DBError> {
+      let mut connection = ObjectConnection::default();
+      for fields in &self.0 {
+        let node = TestObject(fields.clone());
+        let cursor = node.id().await;
+        connection.edges.push(ObjectEdge {
+          node: Box::new(node),
+          cursor,
+        });
+      }
+      connection.count = self.0.len();
+      Ok(connection)
+    }
+  }
+
+  struct TestMutation;
+
+  /// Error of the mutations, which the test store doesn't support.
+  fn unsupported() -> DBError {
+    DBError::Io("Mutation isn't supported by the test store.".to_string())
+  }
+
+  #[async_trait]
+  impl DBMutation for TestMutation {
+    async fn create_object(
+      &mut self,
+      data: ObjectCreateInput,
+    ) -> Result<Box<dyn DBObject>, DBError> {
+      let mut fields = data
+        .fields
+        .into_iter()
+        .map(|(name, value)| (Name::new(name), value))
+        .collect::<Fields>();
+      fields.insert(Name::new("userId"), Value::String("new_user".to_string()));
+      Ok(Box::new(TestObject(fields)))
+    }
+
+    async fn update_object(
+      &mut self,
+      _where: ObjectWhereUniqueInput,
+      _data: ObjectUpdateInput,
+    ) -> Result<Box<dyn DBObject>, DBError> {
+      Err(unsupported())
+    }
+
+    async fn delete_object(
+      &mut self,
+      _where: ObjectWhereUniqueInput,
+    ) -> Result<Box<dyn DBObject>, DBError> {
+      Err(unsupported())
+    }
+
+    async fn upsert_object(
+      &mut self,
+      _where: ObjectWhereUniqueInput,
+      _data: ObjectUpsertInput,
+    ) -> Result<Box<dyn DBObject>, DBError> {
+      Err(unsupported())
+    }
+
+    async fn delete_many_objects(
+      &mut self,
+      _where: ObjectWhereInput,
+      _pagination: Pagination,
+    ) -> Result<ObjectConnection, DBError> {
+      Err(unsupported())
+    }
+
+    async fn update_many_objects(
+      &mut self,
+      _where: ObjectWhereInput,
+      _data: ObjectUpdateInput,
+      _pagination: Pagination,
+    ) -> Result<ObjectConnection, DBError> {
+      Err(unsupported())
+    }
+  }
+
+  fn user(user_id: &str, email: Option<&str>) -> Fields {
+    let mut fields = Fields::new();
+    fields.insert(Name::new("userId"), Value::String(user_id.to_string()));
+    if let Some(email) = email {
+      fields.insert(Name::new("email"), Value::String(email.to_string()));
+    }
+    fields.insert(Name::new("role"), Value::Enum(Name::new("USER")));
+    fields
+  }
+
+  fn schema(users: Vec<Fields>) -> SchemaBuilder {
+    let sdml = fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/test_crud_api_def.sdml"
+    ))
+    .unwrap();
+    Schema::build(sdml, Arc::new(TestStore(users)))
+  }
+
+  #[tokio::test]
+  async fn test_query_objects() {
+    let schema = schema(vec![
+      user("1", Some("mohan@beusefultech.com")),
+      user("2", Some("puni@beusefultech.com")),
+    ])
+    .finish()
+    .unwrap();
+    let response = schema
+      .execute(
+        Request::new(
+          r#"
+        fragment userFields on User {
+          userId: id
+          role
+        }
+        query Users($skipEmail: Boolean!) {
+          users {
+            __typename
+            ...userFields
+            email @skip(if: $skipEmail)
+          }
+          usersConnection(first: 2) {
+            edges {
+              cursor
+              node {
+                ... on User {
+                  email
+                }
+              }
+            }
+            aggregate {
+              count
+            }
+          }
+        }"#,
+        )
+        .variables(Variables::from_json(serde_json::json!({"skipEmail": true}))),
+      )
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+    assert_eq!(
+      response.data,
+      value!({
+        "users": [
+          {"__typename": "User", "userId": "1", "role": "USER"},
+          {"__typename": "User", "userId": "2", "role": "USER"},
+        ],
+        "usersConnection": {
+          "edges": [
+            {"cursor": "1", "node": {"email": "mohan@beusefultech.com"}},
+            {"cursor": "2", "node": {"email": "puni@beusefultech.com"}},
+          ],
+          "aggregate": {"count": 2},
+        }
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_query_object() {
+    let schema = schema(vec![user("1", Some("mohan@beusefultech.com"))])
+      .finish()
+      .unwrap();
+    let response = schema
+      .execute(Request::new(
+        r#"{
+          found: user(where: {email: "mohan@beusefultech.com"}) { id email }
+          notFound: user(where: {email: "puni@beusefultech.com"}) { id email }
+        }"#,
+      ))
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+    assert_eq!(
+      response.data,
+      value!({
+        "found": {"id": "1", "email": "mohan@beusefultech.com"},
+        "notFound": null,
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_non_null_field_error_path() {
+    let schema = schema(vec![user("1", None)]).finish().unwrap();
+    let response = schema
+      .execute(Request::new(
+        r#"{
+          user(where: {id: "1"}) { id email }
+          users { id email }
+        }"#,
+      ))
+      .await;
+    assert_eq!(response.data, Value::Null);
+    assert_eq!(response.errors.len(), 2);
+    assert_eq!(
+      response.errors[0].path,
+      vec![
+        PathSegment::Field("user".to_string()),
+        PathSegment::Field("email".to_string())
+      ]
+    );
+    assert_eq!(
+      response.errors[1].path,
+      vec![
+        PathSegment::Field("users".to_string()),
+        PathSegment::Index(0),
+        PathSegment::Field("email".to_string())
+      ]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_mutation() {
+    let schema = schema(vec![]).finish().unwrap();
+    let response = schema
+      .execute(Request::new(
+        r#"mutation {
+          createUser(data: {email: "vaibhav@beusefultech.com", nickNames: ["Vaibhav"]}) {
+            id
+            email
+            nickNames
+          }
+        }"#,
+      ))
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+    assert_eq!(
+      response.data,
+      value!({
+        "createUser": {
+          "id": "new_user",
+          "email": "vaibhav@beusefultech.com",
+          "nickNames": ["Vaibhav"],
+        }
+      })
+    );
+
+    let response = schema
+      .execute(Request::new(
+        r#"mutation {
+          deleteUser(where: {email: "vaibhav@beusefultech.com"}) { id }
+        }"#,
+      ))
+      .await;
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+      response.errors[0].message,
+      "Data store failed: Mutation isn't supported by the test store."
+    );
+  }
+
+  #[tokio::test]
+  async fn test_invalid_request() {
+    let schema = schema(vec![]).finish().unwrap();
+    let response = schema.execute(Request::new("{ users { id ")).await;
+    assert!(response.is_err());
+
+    let response = schema
+      .execute(Request::new("{ users { unknownField } }"))
+      .await;
+    assert!(response.is_err());
+    assert_eq!(response.data, Value::Null);
+
+    let response = schema
+      .execute(Request::new(
+        "query A { users { id } } query B { users { id } }",
+      ))
+      .await;
+    assert_eq!(
+      response.errors[0].message,
+      "Operation name is required, when document has more than one operation."
+    );
+  }
+
+  /// Directive converting the string field value to uppercase.
+  struct UpperCase;
+
+  #[async_trait]
+  impl CustomDirective for UpperCase {
+    async fn resolve_field(
+      &self,
+      _ctx: &Context<'_>,
+      resolve: ResolveFut<'_>,
+    ) -> ServerResult<Option<Value>> {
+      Ok(resolve.await?.map(|value| match value {
+        Value::String(value) => Value::String(value.to_uppercase()),
+        value => value,
+      }))
+    }
+  }
+
+  struct UpperCaseFactory;
+
+  impl CustomDirectiveFactory for UpperCaseFactory {
+    fn name(&self) -> Cow<'static, str> {
+      "upper".into()
+    }
+
+    fn register(&self, registry: &mut Registry) {
+      registry.directives.insert(
+        "upper".to_string(),
+        MetaDirective {
+          name: "upper".to_string(),
+          description: None,
+          locations: vec![__DirectiveLocation::FIELD],
+          args: Default::default(),
+          is_repeatable: false,
+        },
+      );
+    }
+
+    fn create(
+      &self,
+      _ctx: &ContextDirective<'_>,
+      _directive: &Directive,
+    ) -> ServerResult<Box<dyn CustomDirective>> {
+      Ok(Box::new(UpperCase))
+    }
+  }
+
+  #[tokio::test]
+  async fn test_custom_directive() {
+    let response = schema(vec![user("1", Some("mohan@beusefultech.com"))])
+      .directive(UpperCaseFactory)
+      .finish()
+      .unwrap()
+      .execute(Request::new(
+        r#"{ user(where: {id: "1"}) { id @upper email @upper } }"#,
+      ))
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+    assert_eq!(
+      response.data,
+      value!({
+        "user": {
+          "id": "1",
+          "email": "MOHAN@BEUSEFULTECH.COM",
+        }
+      })
+    );
+
+    // Unregistered directives are rejected by the validation.
+    let response = schema(vec![])
+      .finish()
+      .unwrap()
+      .execute(Request::new(r#"{ users { email @upper } }"#))
+      .await;
+    assert!(response.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_introspection() {
+    let schema = schema(vec![]).finish().unwrap();
+    let response = schema
+      .execute(Request::new(
+        r#"{
+          __schema {
+            queryType { name }
+            mutationType { name }
+            subscriptionType { name }
+            directives { name locations }
+          }
+          __type(name: "Profile") {
+            kind
+            name
+            fields {
+              name
+              type { kind name ofType { kind name } }
+            }
+          }
+          unknownType: __type(name: "Unknown") { name }
+        }"#,
+      ))
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["__schema"]["queryType"]["name"], "Query");
+    assert_eq!(data["__schema"]["mutationType"]["name"], "Mutation");
+    assert!(data["__schema"]["subscriptionType"].is_null());
+    assert!(data["__schema"]["directives"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .any(
+        |directive| directive["name"] == "skip" && directive["locations"][0] == "FIELD"
+      ));
+    assert_eq!(data["__type"]["kind"], "OBJECT");
+    assert_eq!(data["__type"]["name"], "Profile");
+    assert_eq!(
+      data["__type"]["fields"][0],
+      serde_json::json!({
+        "name": "id",
+        "type": { "kind": "NON_NULL", "name": null, "ofType": { "kind": "SCALAR", "name": "ID" } }
+      })
+    );
+    assert!(data["unknownType"].is_null());
+  }
+
+  #[tokio::test]
+  async fn test_full_introspection_query() {
+    let query = fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/introspection_query.graphql"
+    ))
+    .unwrap();
+    let response = schema(vec![])
+      .finish()
+      .unwrap()
+      .execute(Request::new(query))
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    let types = data["__schema"]["types"].as_array().unwrap();
+    assert!(types.iter().any(|ty| ty["name"] == "UserWhereUniqueInput"
+      && ty["kind"] == "INPUT_OBJECT"
+      && ty["inputFields"]
+        .as_array()
+        .is_some_and(|fields| !fields.is_empty())));
+    assert!(types
+      .iter()
+      .any(|ty| ty["name"] == "__TypeKind" && ty["kind"] == "ENUM"));
+  }
+
+  #[tokio::test]
+  async fn test_introspection_mode() {
+    let query = "{ __schema { queryType { name } } }";
+    let response = schema(vec![])
+      .introspection_mode(IntrospectionMode::Disabled)
+      .finish()
+      .unwrap()
+      .execute(Request::new(query))
+      .await;
+    assert_eq!(
+      response.errors[0].message,
+      r#"Unknown field "__schema" on type "Query"."#
+    );
+
+    let schema = schema(vec![]).finish().unwrap();
+    let response = schema
+      .execute(Request::new(query).disable_introspection())
+      .await;
+    assert!(response.is_err());
+    let response = schema
+      .execute(Request::new(query).only_introspection())
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+    let response = schema
+      .execute(Request::new("{ users { id } }").only_introspection())
+      .await;
+    assert!(response.is_err());
+  }
+
+  /// Schema of the data model, served from the memory store.
+  fn memory_schema(sdml: &str) -> Schema {
+    let data_model = sdml_parser::parse(sdml).unwrap();
+    Schema::build(sdml.to_string(), Arc::new(MemoryStore::new(&data_model)))
+      .finish()
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_memory_store() {
+    let schema = memory_schema(
+      &fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/test_crud_api_def.sdml"
+      ))
+      .unwrap(),
+    );
+    let response = schema
+      .execute(Request::new(
+        r#"
+        mutation {
+          createUser(data: {
+            email: "mohan@beusefultech.com"
+            nickNames: ["mo"]
+            posts: {create: [
+              {title: "Hello"}
+              {title: "World"}
+            ]}
+          }) {
+            email
+          }
+        }"#,
+      ))
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+
+    let response = schema
+      .execute(Request::new(
+        r#"
+        {
+          users(where: {posts_some: {title_starts_with: "W"}}) {
+            email
+            posts(orderBy: title_DSC, first: 1) {
+              title
+              author { email }
+            }
+          }
+        }"#,
+      ))
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+    assert_eq!(
+      response.data,
+      value!({
+        "users": [{
+          "email": "mohan@beusefultech.com",
+          "posts": [{"title": "World", "author": {"email": "mohan@beusefultech.com"}}],
+        }],
+      })
+    );
+
+    let response = schema
+      .execute(Request::new(
+        r#"mutation { createUser(data: {email: "mohan@beusefultech.com", nickNames: []}) { email } }"#,
+      ))
+      .await;
+    assert_eq!(
+      response.errors[0].message,
+      r#"Value "mohan@beusefultech.com" of the unique field "User.email" is already taken."#
+    );
+    assert_eq!(
+      response.errors[0]
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.get("code")),
+      Some(&value!("UNIQUE_VIOLATION"))
+    );
+
+    let response = schema
+      .execute(Request::new(
+        r#"mutation { deleteUser(where: {email: "nobody@beusefultech.com"}) { email } }"#,
+      ))
+      .await;
+    assert_eq!(
+      response.errors[0]
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.get("code")),
+      Some(&value!("NOT_FOUND"))
+    );
+  }
+
+  #[tokio::test]
+  async fn test_long_and_decimal_output() {
+    let schema = memory_schema(
+      r#"
+      model Transfer {
+        transferId ShortStr @id @default(auto())
+        amount     Int64
+        fee        Decimal  @default(0)
+      }
+    "#,
+    );
+    // Note: Both are returned as the strings, so that the clients get them exactly.
+    let response = schema
+      .execute(Request::new(
+        r#"
+        mutation {
+          first: createTransfer(data: {amount: "9007199254740993", fee: "12345678901234567.89"}) {
+            amount
+            fee
+          }
+          second: createTransfer(data: {amount: 4294967296}) { amount fee }
+        }"#,
+      ))
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+    assert_eq!(
+      response.data,
+      value!({
+        "first": {"amount": "9007199254740993", "fee": "12345678901234567.89"},
+        "second": {"amount": "4294967296", "fee": "0"},
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_float_default() {
+    let schema = memory_schema(
+      r#"
+      model Account {
+        accountId ShortStr @id @default(auto())
+        name      ShortStr
+        balance   Float64  @default(10.5)
+      }
+    "#,
+    );
+    let response = schema
+      .execute(Request::new(
+        r#"
+        mutation {
+          first: createAccount(data: {name: "first"}) { balance }
+          second: createAccount(data: {name: "second", balance: 2.25}) { balance }
+        }"#,
+      ))
+      .await;
+    assert!(response.is_ok(), "{:?}", response.errors);
+    assert_eq!(
+      response.data,
+      value!({"first": {"balance": 10.5}, "second": {"balance": 2.25}})
+    );
+  }
+}
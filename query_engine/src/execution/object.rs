@@ -0,0 +1,163 @@
+//! Resolves the fields of model object types and their auxiliary types
+//! (connection, edge, page info & aggregate), along with the embedded values
+//! of the composite types.
+use super::*;
+use db_engine::db_crud::{ObjectOrderByInput, ObjectWhereInput};
+
+/// Directive mapping the GraphQL field to a differently named SDML field.
+const DIRECTIVE_MAP: &str = "map";
+const DIRECTIVE_MAP_ARG_NAME: &str = "name";
+
+pub(super) async fn resolve_field<'a>(
+  ctx: &Context<'a>,
+  field: &MetaField,
+  object: &ObjectValue<'a>,
+) -> ServerResult<Resolved<'a>> {
+  let field_name = field.name.as_str();
+  match object {
+    ObjectValue::Object(_, object) => {
+      resolve_model_field(ctx, field, object.as_ref()).await
+    }
+    ObjectValue::Connection(connection) => match field_name {
+      "pageInfo" => Ok(Resolved::Object(ObjectValue::PageInfo(connection.clone()))),
+      "edges" => Ok(Resolved::List(
+        (0..connection.edges.len())
+          .map(|idx| Resolved::Object(ObjectValue::Edge(connection.clone(), idx)))
+          .collect(),
+      )),
+      "aggregate" => Ok(Resolved::Object(ObjectValue::Aggregate(connection.clone()))),
+      _ => Err(unknown_field(ctx, field)),
+    },
+    ObjectValue::Edge(connection, idx) => {
+      let (node, cursor) = &connection.edges[*idx];
+      match field_name {
+        "node" => Ok(Resolved::Object(ObjectValue::Object(
+          connection.model_name.clone(),
+          node.clone(),
+        ))),
+        "cursor" => Ok(Resolved::Value(cursor.clone().into())),
+        _ => Err(unknown_field(ctx, field)),
+      }
+    }
+    ObjectValue::PageInfo(connection) => {
+      let page_info = &connection.page_info;
+      match field_name {
+        "hasNextPage" => Ok(Resolved::Value(page_info.has_next_page.into())),
+        "hasPreviousPage" => Ok(Resolved::Value(page_info.has_previous_page.into())),
+        "startCursor" => Ok(Resolved::Value(
+          page_info
+            .start_cursor
+            .clone()
+            .map_or(Value::Null, Into::into),
+        )),
+        "endCursor" => Ok(Resolved::Value(
+          page_info.end_cursor.clone().map_or(Value::Null, Into::into),
+        )),
+        "pageSize" => Ok(Resolved::Value(
+          page_info.page_size.map_or(Value::Null, Into::into),
+        )),
+        _ => Err(unknown_field(ctx, field)),
+      }
+    }
+    ObjectValue::Aggregate(connection) => match field_name {
+      "count" => Ok(Resolved::Value((connection.count as u64).into())),
+      _ => Err(unknown_field(ctx, field)),
+    },
+    ObjectValue::Embedded(fields) => Ok(embedded(
+      &ctx.schema_env.data_model,
+      &field.ty,
+      fields.get(field_name).cloned().unwrap_or(Value::Null),
+    )),
+    ObjectValue::Root | ObjectValue::Introspection(_) => Err(unknown_field(ctx, field)),
+  }
+}
+
+async fn resolve_model_field(
+  ctx: &Context<'_>,
+  field: &MetaField,
+  object: &dyn DBObject,
+) -> ServerResult<Resolved<'static>> {
+  match model_output(&ctx.schema_env.data_model, &field.ty) {
+    Some(ModelOutput::Object(model_name)) => {
+      let relation = object
+        .relation(Name::new(&field.name))
+        .await
+        .map_err(|error| field_error(ctx, error))?;
+      Ok(Resolved::object(model_name, relation))
+    }
+    Some(ModelOutput::List(model_name)) => {
+      let connection = get_relations(ctx, object, &field.name, model_name).await?;
+      Ok(Resolved::objects(model_name, connection))
+    }
+    Some(ModelOutput::Connection(model_name)) => {
+      let relation_name = field.name.strip_suffix("Connection").unwrap_or(&field.name);
+      let connection = get_relations(ctx, object, relation_name, model_name).await?;
+      let connection = Resolved::connection(model_name, connection);
+      if MetaTypeName::create(&field.ty).is_list() {
+        Ok(Resolved::List(vec![connection]))
+      } else {
+        Ok(connection)
+      }
+    }
+    None => Ok(embedded(
+      &ctx.schema_env.data_model,
+      &field.ty,
+      object.field(Name::new(model_field_name(field))).await,
+    )),
+  }
+}
+
+/// Value of the field, the values of the composite type are resolved as the objects,
+/// so that only the selected fields are returned.
+fn embedded(data_model: &DataModel, ty: &str, value: Value) -> Resolved<'static> {
+  if !data_model
+    .types
+    .contains_key(MetaTypeName::concrete_typename(ty))
+  {
+    return Resolved::Value(value);
+  }
+  match value {
+    Value::Object(fields) => Resolved::Object(ObjectValue::Embedded(fields)),
+    Value::List(items) => Resolved::List(
+      items
+        .into_iter()
+        .map(|item| embedded(data_model, ty, item))
+        .collect(),
+    ),
+    value => Resolved::Value(value),
+  }
+}
+
+async fn get_relations(
+  ctx: &Context<'_>,
+  object: &dyn DBObject,
+  relation_name: &str,
+  model_name: &str,
+) -> ServerResult<ObjectConnection> {
+  let r#where = arg_input(ctx, "where", model_name, ObjectWhereInput::from_value)?;
+  let order_by = arg_input(ctx, "orderBy", model_name, ObjectOrderByInput::from_value)?;
+  object
+    .relations(
+      Name::new(relation_name),
+      r#where,
+      order_by,
+      pagination_args(ctx)?,
+    )
+    .await
+    .map_err(|error| field_error(ctx, error))
+}
+
+/// Name of the field in the SDML model, for the given GraphQL field.
+fn model_field_name(field: &MetaField) -> &str {
+  field
+    .directive_invocations
+    .iter()
+    .find(|directive| directive.name == DIRECTIVE_MAP)
+    .and_then(
+      |directive| match directive.args.get(DIRECTIVE_MAP_ARG_NAME) {
+        Some(Value::String(name)) => Some(name.as_str()),
+        _ => None,
+      },
+    )
+    .unwrap_or(&field.name)
+}
@@ -0,0 +1,196 @@
+//! Resolves the fields of root `Query` and `Mutation` types.
+use super::*;
+use crate::IntrospectionMode;
+use constraint::Input;
+use db_engine::db_crud::{
+  DBMutation, DBQuery, ObjectCreateInput, ObjectOrderByInput, ObjectUpdateInput,
+  ObjectUpsertInput, ObjectWhereInput, ObjectWhereUniqueInput,
+};
+
+/// Root query field to fetch object by its global ID.
+const FIELD_NODE: &str = "node";
+
+const MUTATION_CREATE: &str = "create";
+const MUTATION_UPDATE: &str = "update";
+const MUTATION_DELETE: &str = "delete";
+const MUTATION_UPSERT: &str = "upsert";
+const MUTATION_UPDATE_MANY: &str = "updateMany";
+const MUTATION_DELETE_MANY: &str = "deleteMany";
+
+pub(super) async fn resolve_query_field<'a>(
+  ctx: &Context<'a>,
+  field: &MetaField,
+) -> ServerResult<Resolved<'a>> {
+  if introspection::is_introspection_field(field) {
+    return introspection::resolve_root_field(ctx, field);
+  }
+  check_introspection_only(ctx, field)?;
+  if field.name == FIELD_NODE {
+    return resolve_node(ctx).await;
+  }
+  match model_output(&ctx.schema_env.data_model, &field.ty) {
+    Some(ModelOutput::Object(model_name)) => {
+      let r#where =
+        arg_input(ctx, "where", model_name, ObjectWhereUniqueInput::from_value)?;
+      let object = db_query(ctx, model_name)?
+        .get_object(r#where)
+        .await
+        .map_err(|error| field_error(ctx, error))?;
+      Ok(Resolved::object(model_name, object))
+    }
+    Some(ModelOutput::List(model_name)) => {
+      let connection = get_objects(ctx, model_name).await?;
+      Ok(Resolved::objects(model_name, connection))
+    }
+    Some(ModelOutput::Connection(model_name)) => {
+      let connection = get_objects(ctx, model_name).await?;
+      Ok(Resolved::connection(model_name, connection))
+    }
+    None => Err(unknown_root_field(ctx, field)),
+  }
+}
+
+pub(super) async fn resolve_mutation_field(
+  ctx: &Context<'_>,
+  field: &MetaField,
+) -> ServerResult<Resolved<'static>> {
+  check_introspection_only(ctx, field)?;
+  let model_name = match model_output(&ctx.schema_env.data_model, &field.ty) {
+    Some(ModelOutput::Object(model_name)) | Some(ModelOutput::Connection(model_name)) => {
+      model_name
+    }
+    _ => return Err(unknown_root_field(ctx, field)),
+  };
+  let mut mutation = db_mutation(ctx, model_name)?;
+  let field_name = field.name.as_str();
+
+  // Note: `updateMany` and `deleteMany` fields should be checked before
+  // `update` and `delete` fields, as they share the same prefix.
+  if field_name.starts_with(MUTATION_UPDATE_MANY) {
+    let r#where = arg_input(ctx, "where", model_name, ObjectWhereInput::from_value)?;
+    constraint::check_data(ctx, model_name, Input::Update)?;
+    let data = arg_input(ctx, "data", model_name, ObjectUpdateInput::from_value)?;
+    let connection = mutation
+      .update_many_objects(r#where, data, pagination_args(ctx)?)
+      .await
+      .map_err(|error| field_error(ctx, error))?;
+    Ok(Resolved::connection(model_name, connection))
+  } else if field_name.starts_with(MUTATION_DELETE_MANY) {
+    let r#where = arg_input(ctx, "where", model_name, ObjectWhereInput::from_value)?;
+    let connection = mutation
+      .delete_many_objects(r#where, pagination_args(ctx)?)
+      .await
+      .map_err(|error| field_error(ctx, error))?;
+    Ok(Resolved::connection(model_name, connection))
+  } else {
+    let object = if field_name.starts_with(MUTATION_CREATE) {
+      constraint::check_data(ctx, model_name, Input::Create)?;
+      let data = arg_input(ctx, "data", model_name, ObjectCreateInput::from_value)?;
+      mutation.create_object(data).await
+    } else if field_name.starts_with(MUTATION_UPDATE) {
+      let r#where =
+        arg_input(ctx, "where", model_name, ObjectWhereUniqueInput::from_value)?;
+      constraint::check_data(ctx, model_name, Input::Update)?;
+      let data = arg_input(ctx, "data", model_name, ObjectUpdateInput::from_value)?;
+      mutation.update_object(r#where, data).await
+    } else if field_name.starts_with(MUTATION_DELETE) {
+      let r#where =
+        arg_input(ctx, "where", model_name, ObjectWhereUniqueInput::from_value)?;
+      mutation.delete_object(r#where).await
+    } else if field_name.starts_with(MUTATION_UPSERT) {
+      let r#where =
+        arg_input(ctx, "where", model_name, ObjectWhereUniqueInput::from_value)?;
+      constraint::check_data(ctx, model_name, Input::Upsert)?;
+      let data = arg_input(ctx, "data", model_name, ObjectUpsertInput::from_value)?;
+      mutation.upsert_object(r#where, data).await
+    } else {
+      return Err(unknown_root_field(ctx, field));
+    }
+    .map_err(|error| field_error(ctx, error))?;
+    Ok(Resolved::object(model_name, Some(object)))
+  }
+}
+
+/// Resolves the object of any model by its ID.
+async fn resolve_node(ctx: &Context<'_>) -> ServerResult<Resolved<'static>> {
+  let id = arg_value(ctx, "id")?;
+  for model_name in ctx.schema_env.data_model.models.keys() {
+    let r#where = ObjectWhereUniqueInput::from_value(
+      &ctx.schema_env.data_model,
+      model_name,
+      Value::Object(IndexMap::from([(Name::new("id"), id.clone())])),
+    )
+    .map_err(|error| field_error(ctx, error))?;
+    let object = db_query(ctx, model_name)?
+      .get_object(r#where)
+      .await
+      .map_err(|error| field_error(ctx, error))?;
+    if object.is_some() {
+      return Ok(Resolved::object(model_name, object));
+    }
+  }
+  Ok(Resolved::null())
+}
+
+async fn get_objects(
+  ctx: &Context<'_>,
+  model_name: &str,
+) -> ServerResult<ObjectConnection> {
+  let r#where = arg_input(ctx, "where", model_name, ObjectWhereInput::from_value)?;
+  let order_by = arg_input(ctx, "orderBy", model_name, ObjectOrderByInput::from_value)?;
+  db_query(ctx, model_name)?
+    .get_objects(r#where, order_by, pagination_args(ctx)?)
+    .await
+    .map_err(|error| field_error(ctx, error))
+}
+
+fn db_query(ctx: &Context<'_>, model_name: &str) -> ServerResult<Box<dyn DBQuery>> {
+  ctx
+    .schema_env
+    .db
+    .query(model_name)
+    .ok_or_else(|| unknown_model(ctx, model_name))
+}
+
+fn db_mutation(ctx: &Context<'_>, model_name: &str) -> ServerResult<Box<dyn DBMutation>> {
+  ctx
+    .schema_env
+    .db
+    .mutation(model_name)
+    .ok_or_else(|| unknown_model(ctx, model_name))
+}
+
+/// Rejects the non-introspection root fields, when only the introspection
+/// queries are allowed.
+fn check_introspection_only(ctx: &Context<'_>, field: &MetaField) -> ServerResult<()> {
+  if introspection::introspection_mode(ctx) == IntrospectionMode::IntrospectionOnly {
+    return Err(field_error(
+      ctx,
+      Error::new(format!(
+        r#"Root field "{}" can't be resolved, only introspection queries are allowed."#,
+        field.name
+      )),
+    ));
+  }
+  Ok(())
+}
+
+fn unknown_model(ctx: &Context<'_>, model_name: &str) -> ServerError {
+  field_error(
+    ctx,
+    Error::new(format!(
+      r#"Model "{}" is not found in the data store."#,
+      model_name
+    )),
+  )
+}
+
+fn unknown_root_field(ctx: &Context<'_>, field: &MetaField) -> ServerError {
+  field_error(
+    ctx,
+    Error::new(format!(
+      r#"Root field "{}" is not an OpenCRUD field."#,
+      field.name
+    )),
+  )
+}
@@ -1,5 +1,3 @@
-use std::collections::HashSet;
-
 use crate::registry;
 
 use super::input_value::__InputValue;
@@ -74,6 +72,7 @@ pub enum __DirectiveLocation {
 /// behaviour in ways field arguments will not suffice, such as conditionally
 /// including or skipping a field. Directives provide this by describing
 /// additional information to the execution module.
+#[derive(Clone)]
 pub struct __Directive<'a> {
   pub registry: &'a registry::Registry,
   pub directive: &'a registry::MetaDirective,
@@ -81,21 +80,21 @@ pub struct __Directive<'a> {
 
 impl<'a> __Directive<'a> {
   #[inline]
-  fn name(&self) -> &str {
+  pub(crate) fn name(&self) -> &str {
     &self.directive.name
   }
 
   #[inline]
-  fn description(&self) -> Option<&str> {
+  pub(crate) fn description(&self) -> Option<&str> {
     self.directive.description.as_deref()
   }
 
   #[inline]
-  fn locations(&self) -> &Vec<__DirectiveLocation> {
+  pub(crate) fn locations(&self) -> &Vec<__DirectiveLocation> {
     &self.directive.locations
   }
 
-  fn args(&self) -> Vec<__InputValue<'a>> {
+  pub(crate) fn args(&self) -> Vec<__InputValue<'a>> {
     self
       .directive
       .args
@@ -107,7 +106,7 @@ impl<'a> __Directive<'a> {
       .collect()
   }
 
-  fn is_repeatable(&self) -> bool {
+  pub(crate) fn is_repeatable(&self) -> bool {
     self.directive.is_repeatable
   }
 }
@@ -1,11 +1,10 @@
-use std::collections::HashSet;
-
 use crate::registry;
 
 use super::{input_value::__InputValue, r#type::__Type};
 
 /// Object and Interface types are described by list of Fields, each of which
 /// has a name, potentially a list of arguments and a return type.
+#[derive(Clone)]
 pub struct __Field<'a> {
   pub registry: &'a registry::Registry,
   pub field: &'a registry::MetaField,
@@ -13,16 +12,16 @@ pub struct __Field<'a> {
 
 impl<'a> __Field<'a> {
   #[inline]
-  fn name(&self) -> &str {
+  pub(crate) fn name(&self) -> &str {
     &self.field.name
   }
 
   #[inline]
-  fn description(&self) -> Option<&str> {
+  pub(crate) fn description(&self) -> Option<&str> {
     self.field.description.as_deref()
   }
 
-  fn args(&self) -> Vec<__InputValue> {
+  pub(crate) fn args(&self) -> Vec<__InputValue<'a>> {
     // ToDo::
     // Avoiding visibility filter for the fields based on the context.
     // to be added later when it becomes necessary.
@@ -38,17 +37,17 @@ impl<'a> __Field<'a> {
   }
 
   #[inline]
-  fn r#type(&self) -> __Type<'a> {
+  pub(crate) fn r#type(&self) -> __Type<'a> {
     __Type::new(self.registry, &self.field.ty)
   }
 
   #[inline]
-  fn is_deprecated(&self) -> bool {
+  pub(crate) fn is_deprecated(&self) -> bool {
     self.field.deprecation.is_deprecated()
   }
 
   #[inline]
-  fn deprecation_reason(&self) -> Option<&str> {
+  pub(crate) fn deprecation_reason(&self) -> Option<&str> {
     self.field.deprecation.reason()
   }
 }
@@ -1,5 +1,3 @@
-use std::collections::HashSet;
-
 use crate::registry;
 
 use super::r#type::__Type;
@@ -7,6 +5,7 @@ use super::r#type::__Type;
 /// Arguments provided to Fields or Directives and the input fields of an InputObject
 /// are represented as Input Values which describe their type and
 /// optionally a default value.
+#[derive(Clone)]
 pub struct __InputValue<'a> {
   pub registry: &'a registry::Registry,
   pub input_value: &'a registry::MetaInputValue,
@@ -14,22 +13,22 @@ pub struct __InputValue<'a> {
 
 impl<'a> __InputValue<'a> {
   #[inline]
-  fn name(&self) -> &str {
+  pub(crate) fn name(&self) -> &str {
     &self.input_value.name
   }
 
   #[inline]
-  fn description(&self) -> Option<&str> {
+  pub(crate) fn description(&self) -> Option<&str> {
     self.input_value.description.as_deref()
   }
 
   #[inline]
-  fn r#type(&self) -> __Type<'a> {
+  pub(crate) fn r#type(&self) -> __Type<'a> {
     __Type::new(self.registry, &self.input_value.ty)
   }
 
   #[inline]
-  fn default_value(&self) -> Option<&str> {
+  pub(crate) fn default_value(&self) -> Option<&str> {
     self.input_value.default_value.as_deref()
   }
 }
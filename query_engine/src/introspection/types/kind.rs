@@ -1,3 +1,4 @@
+/// An enum describing what kind of type a given `__Type` is.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum __TypeKind {
   /// Indicates this type is a scalar.
@@ -18,3 +19,19 @@ pub enum __TypeKind {
   /// Indicates this type is a non-null. `ofType` is a valid field.
   NonNull,
 }
+
+impl __TypeKind {
+  /// Name of the enum value in the introspection schema.
+  pub fn name(&self) -> &'static str {
+    match self {
+      __TypeKind::Scalar => "SCALAR",
+      __TypeKind::Object => "OBJECT",
+      __TypeKind::Interface => "INTERFACE",
+      __TypeKind::Union => "UNION",
+      __TypeKind::Enum => "ENUM",
+      __TypeKind::InputObject => "INPUT_OBJECT",
+      __TypeKind::List => "LIST",
+      __TypeKind::NonNull => "NON_NULL",
+    }
+  }
+}
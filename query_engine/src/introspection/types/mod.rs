@@ -7,4 +7,9 @@ mod schema;
 
 mod r#type;
 
-pub use directive::__DirectiveLocation;
+pub use directive::{__Directive, __DirectiveLocation};
+pub use enum_value::__EnumValue;
+pub use field::__Field;
+pub use input_value::__InputValue;
+pub use r#type::__Type;
+pub use schema::__Schema;
@@ -1,5 +1,3 @@
-use std::collections::HashSet;
-
 use crate::registry;
 
 use super::{directive::__Directive, r#type::__Type};
@@ -7,6 +5,7 @@ use super::{directive::__Directive, r#type::__Type};
 /// A GraphQL schema defines capabilities of a GraphQL server. It exposes
 /// all available types and directives on the server, as well as entry points
 /// for a query, mutation, and subscription operations.
+#[derive(Clone)]
 pub struct __Schema<'a> {
   registry: &'a registry::Registry,
 }
@@ -20,12 +19,12 @@ impl<'a> __Schema<'a> {
 impl<'a> __Schema<'a> {
   /// Description of __Schema for newer graphiql interospection schema
   /// requirements
-  fn description(&self) -> String {
+  pub(crate) fn description(&self) -> String {
     String::from("A GraphQL Schema defines the capabilities of a GraphQL server. It exposes all available types and directives on the server, as well as the entry points for query, mutation, and subscription operations.")
   }
 
   /// A list of all types supported by this server.
-  fn types(&self) -> Vec<__Type<'a>> {
+  pub(crate) fn types(&self) -> Vec<__Type<'a>> {
     let mut types: Vec<_> = self
       .registry
       .types
@@ -39,7 +38,7 @@ impl<'a> __Schema<'a> {
 
   /// The root query type.
   #[inline]
-  fn query_type(&self) -> __Type<'a> {
+  pub(crate) fn query_type(&self) -> __Type<'a> {
     __Type::new_simple(
       self.registry,
       &self.registry.types[&self.registry.query_type],
@@ -47,7 +46,7 @@ impl<'a> __Schema<'a> {
   }
 
   /// The root mutation type if this server supports.
-  fn mutation_type(&self) -> Option<__Type<'a>> {
+  pub(crate) fn mutation_type(&self) -> Option<__Type<'a>> {
     self
       .registry
       .mutation_type
@@ -56,7 +55,7 @@ impl<'a> __Schema<'a> {
   }
 
   /// The root subscription type if this server supports.
-  fn subscription_type(&self) -> Option<__Type<'a>> {
+  pub(crate) fn subscription_type(&self) -> Option<__Type<'a>> {
     self
       .registry
       .subscription_type
@@ -65,7 +64,7 @@ impl<'a> __Schema<'a> {
   }
 
   /// A list of all directives supported by this server.
-  fn directives(&self) -> Vec<__Directive<'a>> {
+  pub(crate) fn directives(&self) -> Vec<__Directive<'a>> {
     let mut directives: Vec<_> = self
       .registry
       .directives
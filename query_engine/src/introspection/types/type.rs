@@ -1,11 +1,10 @@
-use std::collections::HashSet;
-
 use crate::registry::{self, MetaType};
 
 use super::{
   enum_value::__EnumValue, field::__Field, input_value::__InputValue, kind::__TypeKind,
 };
 
+#[derive(Clone)]
 enum TypeDetail<'a> {
   Named(&'a registry::MetaType),
   NonNull(String),
@@ -20,6 +19,7 @@ enum TypeDetail<'a> {
 /// description, while enum type provide their values. Object and interface
 /// provide the object types possible at runtime. List and NonNull types compose
 /// other types.
+#[derive(Clone)]
 pub struct __Type<'a> {
   registry: &'a registry::Registry,
   detail: TypeDetail<'a>,
@@ -37,7 +37,7 @@ impl<'a> __Type<'a> {
     }
   }
 
-  pub fn new(registry: &'a registry::Registry, type_name: &'a str) -> Self {
+  pub fn new(registry: &'a registry::Registry, type_name: &str) -> Self {
     match registry::MetaTypeName::create(type_name) {
       registry::MetaTypeName::NonNull(ty) => __Type {
         registry,
@@ -60,7 +60,7 @@ impl<'a> __Type<'a> {
 
 impl<'a> __Type<'a> {
   #[inline]
-  fn kind(&self) -> __TypeKind {
+  pub(crate) fn kind(&self) -> __TypeKind {
     match &self.detail {
       TypeDetail::Named(ty) => match ty {
         registry::MetaType::Scalar { .. } => __TypeKind::Scalar,
@@ -76,7 +76,7 @@ impl<'a> __Type<'a> {
   }
 
   #[inline]
-  fn name(&self) -> Option<&str> {
+  pub(crate) fn name(&self) -> Option<&str> {
     match &self.detail {
       TypeDetail::Named(ty) => Some(ty.name()),
       TypeDetail::NonNull(_) => None,
@@ -85,7 +85,7 @@ impl<'a> __Type<'a> {
   }
 
   #[inline]
-  fn description(&self) -> Option<&str> {
+  pub(crate) fn description(&self) -> Option<&str> {
     match self.detail {
       TypeDetail::Named(ty) => match ty {
         registry::MetaType::Scalar { description, .. }
@@ -100,7 +100,7 @@ impl<'a> __Type<'a> {
     }
   }
 
-  fn fields(&self, include_deprecated: bool) -> Option<Vec<__Field<'a>>> {
+  pub(crate) fn fields(&self, include_deprecated: bool) -> Option<Vec<__Field<'a>>> {
     if let TypeDetail::Named(ty) = &self.detail {
       ty.fields().map(|fields| {
         fields
@@ -120,7 +120,7 @@ impl<'a> __Type<'a> {
     }
   }
 
-  fn interfaces(&self) -> Option<Vec<__Type<'a>>> {
+  pub(crate) fn interfaces(&self) -> Option<Vec<__Type<'a>>> {
     if let TypeDetail::Named(registry::MetaType::Object { implements, .. })
     | TypeDetail::Named(registry::MetaType::Interface { implements, .. }) =
       &self.detail
@@ -136,7 +136,7 @@ impl<'a> __Type<'a> {
     }
   }
 
-  fn possible_types(&self) -> Option<Vec<__Type<'a>>> {
+  pub(crate) fn possible_types(&self) -> Option<Vec<__Type<'a>>> {
     if let TypeDetail::Named(registry::MetaType::Interface { name, .. })
     | TypeDetail::Named(registry::MetaType::Union { name, .. }) = &self.detail
     {
@@ -151,7 +151,10 @@ impl<'a> __Type<'a> {
     }
   }
 
-  fn enum_values(&self, include_deprecated: bool) -> Option<Vec<__EnumValue>> {
+  pub(crate) fn enum_values(
+    &self,
+    include_deprecated: bool,
+  ) -> Option<Vec<__EnumValue<'a>>> {
     if let TypeDetail::Named(registry::MetaType::Enum { enum_values, .. }) = &self.detail
     {
       Some(
@@ -166,7 +169,7 @@ impl<'a> __Type<'a> {
     }
   }
 
-  fn input_fields(&self) -> Option<Vec<__InputValue<'a>>> {
+  pub(crate) fn input_fields(&self) -> Option<Vec<__InputValue<'a>>> {
     if let TypeDetail::Named(MetaType::InputObject { input_fields, .. }) = &self.detail {
       Some(
         input_fields
@@ -182,17 +185,17 @@ impl<'a> __Type<'a> {
     }
   }
 
-  fn of_type(&'a self) -> Option<__Type<'a>> {
+  pub(crate) fn of_type(&self) -> Option<__Type<'a>> {
     if let TypeDetail::List(ty) = &self.detail {
-      Some(__Type::new(self.registry, &ty))
+      Some(__Type::new(self.registry, ty))
     } else if let TypeDetail::NonNull(ty) = &self.detail {
-      Some(__Type::new(self.registry, &ty))
+      Some(__Type::new(self.registry, ty))
     } else {
       None
     }
   }
 
-  fn specified_by_url(&self) -> Option<&'a str> {
+  pub(crate) fn specified_by_url(&self) -> Option<&'a str> {
     if let TypeDetail::Named(registry::MetaType::Scalar {
       specified_by_url, ..
     }) = &self.detail
@@ -203,7 +206,7 @@ impl<'a> __Type<'a> {
     }
   }
 
-  fn is_one_of(&self) -> Option<bool> {
+  pub(crate) fn is_one_of(&self) -> Option<bool> {
     if let TypeDetail::Named(registry::MetaType::InputObject { oneof, .. }) = &self.detail
     {
       Some(*oneof)
@@ -17,15 +17,17 @@ pub use context::*;
 pub use custom_directive::{CustomDirective, CustomDirectiveFactory, ResolveFut};
 pub use error::{
   Error, ErrorExtensionValues, InputValueError, InputValueResult, ParseRequestError,
-  PathSegment, Result, ServerError, ServerResult,
+  PathSegment, Result, SchemaError, ServerError, ServerResult,
 };
+pub use executor::Executor;
 pub use graphql_parser;
 pub use graphql_value;
 pub use registry::CacheControl;
 pub use request::{BatchRequest, Request};
 pub use response::{BatchResponse, Response};
-pub use schema::{IntrospectionMode, SchemaEnv};
+pub use schema::{IntrospectionMode, Schema, SchemaBuilder, SchemaEnv};
 pub use sdml_parser::types::DataModel;
+pub use validation::ValidationMode;
 
 #[cfg(test)]
 mod tests {}
@@ -32,6 +32,7 @@ fn from_scalar_type(value: TypeDefinition) -> MetaType {
       name: value.name.node.to_string(),
       description: value.description.map(|desc| desc.node),
       is_valid: None, // ToDo:: Add scalar validator.
+      serialize: None,
       specified_by_url: None,
     }
   } else {
@@ -188,6 +188,9 @@ impl fmt::Display for MetaTypeId {
 /// A validator for scalar
 pub type ScalarValidatorFn = Arc<dyn Fn(&Value) -> bool + Send + Sync>;
 
+/// A serializer for scalar
+pub type ScalarSerializerFn = Arc<dyn Fn(Value) -> Value + Send + Sync>;
+
 /// Type metadata.
 #[derive(Clone)]
 pub enum MetaType {
@@ -201,6 +204,9 @@ pub enum MetaType {
     description: Option<String>,
     /// A function that uses to check if the scalar is valid
     is_valid: Option<ScalarValidatorFn>,
+    /// A function that uses to serialize the resolved value of the scalar for
+    /// the response
+    serialize: Option<ScalarSerializerFn>,
     /// Provide a specification URL for this scalar type, it must link to a
     /// human-readable specification of the data format, serialization and
     /// coercion rules for this scalar.
@@ -1,8 +1,9 @@
 //! Impements necessary meta-data types for introspection.
 mod cache_control;
 mod meta_types;
+use bytes::Bytes;
 pub use cache_control::CacheControl;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 pub use meta_types::*;
 
 use crate::graphql_parser::types::{
@@ -11,7 +12,8 @@ use crate::graphql_parser::types::{
 };
 use crate::InputType;
 use crate::{
-  graphql_value::ConstValue as Value, introspection::types::__DirectiveLocation,
+  introspection::{self, types::__DirectiveLocation},
+  scalar::{decimal::Decimal, json::Json},
   schema::IntrospectionMode,
 };
 use core::panic;
@@ -22,6 +24,7 @@ use std::{
   fmt,
   sync::Arc,
 };
+use uuid::Uuid;
 
 #[derive(Default, Debug)]
 struct InterfacesImplementedByMap {
@@ -143,6 +146,7 @@ impl Registry {
     registry.subscription_type = None;
     registry.introspection_mode = IntrospectionMode::default();
     registry.add_system_types(); // Add system types.
+    registry.add_introspection_types();
 
     registry
   }
@@ -324,6 +328,48 @@ impl Registry {
     self.add_type(crate::scalar::id::ID::create_type_info());
   }
 
+  /// Adds the introspection types, and the `__schema` & `__type` fields to the
+  /// root query type.
+  fn add_introspection_types(&mut self) {
+    let service_doc =
+      crate::graphql_parser::parse_schema(introspection::INTROSPECTION_TYPES)
+        .expect("Introspection types should be valid.");
+    service_doc.definitions.into_iter().for_each(|def| {
+      if let TypeSystemDefinition::Type(ty) = def {
+        self.add_type(ty.node.into())
+      }
+    });
+
+    let introspection_field = |name: &str, ty: &str, args| MetaField {
+      name: name.to_string(),
+      description: None,
+      args,
+      ty: ty.to_string(),
+      deprecation: Deprecation::NoDeprecated,
+      directive_invocations: vec![],
+    };
+    let type_args = IndexMap::from([(
+      "name".to_string(),
+      MetaInputValue {
+        name: "name".to_string(),
+        description: None,
+        ty: "String!".to_string(),
+        default_value: None,
+        directive_invocations: vec![],
+      },
+    )]);
+    if let Some(MetaType::Object { fields, .. }) = self.types.get_mut(&self.query_type) {
+      fields.insert(
+        introspection::FIELD_SCHEMA.to_string(),
+        introspection_field(introspection::FIELD_SCHEMA, "__Schema!", IndexMap::new()),
+      );
+      fields.insert(
+        introspection::FIELD_TYPE.to_string(),
+        introspection_field(introspection::FIELD_TYPE, "__Type", type_args),
+      );
+    }
+  }
+
   fn add_type(&mut self, r#type: MetaType) {
     let (name, type_id) = (r#type.name(), r#type.type_id());
     match self.types.get(name) {
@@ -368,6 +414,34 @@ impl Registry {
               .types
               .insert(name.to_string(), DateTime::<Utc>::create_type_info());
           }
+          MetaType::Scalar { name, .. } if name.eq("Long") => {
+            self.types.insert(name.to_string(), i64::create_type_info());
+          }
+          MetaType::Scalar { name, .. } if name.eq("Decimal") => {
+            self
+              .types
+              .insert(name.to_string(), Decimal::create_type_info());
+          }
+          MetaType::Scalar { name, .. } if name.eq("Json") => {
+            self
+              .types
+              .insert(name.to_string(), Json::create_type_info());
+          }
+          MetaType::Scalar { name, .. } if name.eq("Bytes") => {
+            self
+              .types
+              .insert(name.to_string(), Bytes::create_type_info());
+          }
+          MetaType::Scalar { name, .. } if name.eq("Date") => {
+            self
+              .types
+              .insert(name.to_string(), NaiveDate::create_type_info());
+          }
+          MetaType::Scalar { name, .. } if name.eq("UUID") => {
+            self
+              .types
+              .insert(name.to_string(), Uuid::create_type_info());
+          }
           _ => {
             self.types.insert(name.to_string(), r#type);
           }
@@ -0,0 +1,51 @@
+use super::ScalarType;
+use crate::{graphql_value::ConstValue as Value, InputValueError, InputValueResult};
+use ::bytes::Bytes;
+
+impl ScalarType for Bytes {
+  type RawScalarType = Self;
+
+  fn description() -> Option<String> {
+    Some("The `Bytes` scalar type represents an opaque binary blob. The input/output is a list of bytes, such as [104, 105].".to_string())
+  }
+
+  fn type_name() -> std::borrow::Cow<'static, str> {
+    std::borrow::Cow::Borrowed("Bytes")
+  }
+
+  fn parse(value: Value) -> InputValueResult<Self> {
+    match value {
+      Value::Binary(bytes) => Ok(bytes),
+      Value::List(items) => items
+        .iter()
+        .map(byte)
+        .collect::<Option<Vec<u8>>>()
+        .map(Bytes::from)
+        .ok_or_else(|| InputValueError::from("Invalid byte")),
+      _ => Err(InputValueError::expected_type(value)),
+    }
+  }
+
+  fn is_valid(value: &Value) -> bool {
+    match value {
+      Value::Binary(_) => true,
+      Value::List(items) => items.iter().all(|item| byte(item).is_some()),
+      _ => false,
+    }
+  }
+
+  fn to_value(&self) -> Value {
+    Value::Binary(self.clone())
+  }
+
+  fn as_raw_scalar(&self) -> &Self::RawScalarType {
+    self
+  }
+}
+
+fn byte(value: &Value) -> Option<u8> {
+  match value {
+    Value::Number(n) => u8::try_from(n.as_u64()?).ok(),
+    _ => None,
+  }
+}
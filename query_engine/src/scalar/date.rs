@@ -0,0 +1,66 @@
+use super::ScalarType;
+use crate::{graphql_value::ConstValue as Value, InputValueError, InputValueResult};
+use chrono::NaiveDate;
+
+/// Format of the full-date, as per RFC3339.
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+impl ScalarType for NaiveDate {
+  type RawScalarType = Self;
+
+  fn description() -> Option<String> {
+    Some(
+      "Calendar date without the time. The input/output is a string in RFC3339 full-date format."
+        .to_string(),
+    )
+  }
+
+  fn specified_by_url() -> Option<String> {
+    Some("https://datatracker.ietf.org/doc/html/rfc3339#section-5.6".to_string())
+  }
+
+  fn type_name() -> std::borrow::Cow<'static, str> {
+    std::borrow::Cow::Borrowed("Date")
+  }
+
+  fn parse(value: Value) -> InputValueResult<Self> {
+    match value {
+      Value::String(s) => Ok(NaiveDate::parse_from_str(&s, DATE_FORMAT)?),
+      _ => Err(InputValueError::expected_type(value)),
+    }
+  }
+
+  fn is_valid(value: &Value) -> bool {
+    match value {
+      Value::String(s) => NaiveDate::parse_from_str(s, DATE_FORMAT).is_ok(),
+      _ => false,
+    }
+  }
+
+  fn to_value(&self) -> Value {
+    Value::String(self.format(DATE_FORMAT).to_string())
+  }
+
+  fn as_raw_scalar(&self) -> &Self::RawScalarType {
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_date() {
+    let date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+    assert_eq!(
+      Some(date),
+      <NaiveDate as ScalarType>::parse(Value::from("2024-06-30")).ok()
+    );
+    assert_eq!(Value::from("2024-06-30"), date.to_value());
+    assert!(!<NaiveDate as ScalarType>::is_valid(&Value::from(
+      "2024-02-30"
+    )));
+    assert!(<NaiveDate as ScalarType>::parse(Value::from("2024-02-30")).is_err());
+  }
+}
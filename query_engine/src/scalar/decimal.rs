@@ -0,0 +1,84 @@
+use super::ScalarType;
+use crate::{graphql_value::ConstValue as Value, InputValueError, InputValueResult};
+use serde::{Deserialize, Serialize};
+
+/// Exact decimal number, inputted either as a string or as a number.
+/// Note: It is returned as a string, so that the clients get it exactly. The
+/// numbers inputted are only as exact as the 64-bit floats.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Decimal(pub rust_decimal::Decimal);
+
+impl Decimal {
+  fn from_str(decimal: &str) -> Option<Self> {
+    rust_decimal::Decimal::from_str_exact(decimal)
+      .or_else(|_| rust_decimal::Decimal::from_scientific(decimal))
+      .ok()
+      .map(Decimal)
+  }
+}
+
+impl ScalarType for Decimal {
+  type RawScalarType = Self;
+
+  fn description() -> Option<String> {
+    Some("The `Decimal` scalar type represents an exact decimal number, such as 1234.56. It is inputted either as a string or as a number, and returned as a string.".to_string())
+  }
+
+  fn type_name() -> std::borrow::Cow<'static, str> {
+    std::borrow::Cow::Borrowed("Decimal")
+  }
+
+  fn parse(value: Value) -> InputValueResult<Self> {
+    match &value {
+      Value::Number(n) => Decimal::from_str(&n.to_string()),
+      Value::String(s) => Decimal::from_str(s.trim()),
+      _ => return Err(InputValueError::expected_type(value)),
+    }
+    .ok_or_else(|| InputValueError::from("Invalid decimal"))
+  }
+
+  fn is_valid(value: &Value) -> bool {
+    match value {
+      Value::Number(_) => true,
+      Value::String(s) => Decimal::from_str(s.trim()).is_some(),
+      _ => false,
+    }
+  }
+
+  fn to_value(&self) -> Value {
+    Value::String(self.0.normalize().to_string())
+  }
+
+  fn serialize(value: Value) -> Value {
+    <Self as ScalarType>::parse(value.clone()).map_or(value, |decimal| decimal.to_value())
+  }
+
+  fn as_raw_scalar(&self) -> &Self::RawScalarType {
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_decimal_is_exact() {
+    let decimal = "12345678901234567.89";
+    let parse = |value| <Decimal as ScalarType>::parse(value).ok();
+    assert_eq!(
+      Some(Value::from(decimal)),
+      parse(Value::from(decimal)).map(|decimal| decimal.to_value())
+    );
+    assert_eq!(
+      Some(Value::from("0.001")),
+      parse(Value::from("1e-3")).map(|decimal| decimal.to_value())
+    );
+    assert_eq!(None, parse(Value::from("twelve")));
+    assert_eq!(
+      Value::from(decimal),
+      <Decimal as ScalarType>::serialize(Value::from(decimal))
+    );
+  }
+}
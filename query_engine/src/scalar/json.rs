@@ -0,0 +1,36 @@
+use super::ScalarType;
+use crate::{graphql_value::ConstValue as Value, InputValueResult};
+use serde::{Deserialize, Serialize};
+
+/// Schemaless JSON value, held as is.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct Json(pub Value);
+
+impl ScalarType for Json {
+  type RawScalarType = Self;
+
+  fn description() -> Option<String> {
+    Some("The `Json` scalar type represents a schemaless JSON value, such as an object, a list, a string, a number or a boolean.".to_string())
+  }
+
+  fn specified_by_url() -> Option<String> {
+    Some("https://www.rfc-editor.org/rfc/rfc8259".to_string())
+  }
+
+  fn type_name() -> std::borrow::Cow<'static, str> {
+    std::borrow::Cow::Borrowed("Json")
+  }
+
+  fn parse(value: Value) -> InputValueResult<Self> {
+    Ok(Json(value))
+  }
+
+  fn to_value(&self) -> Value {
+    self.0.clone()
+  }
+
+  fn as_raw_scalar(&self) -> &Self::RawScalarType {
+    self
+  }
+}
@@ -0,0 +1,65 @@
+use super::ScalarType;
+use crate::{graphql_value::ConstValue as Value, InputValueError, InputValueResult};
+
+/// 64-bit signed integer, inputted either as a number or as a numeric string.
+/// Note: It is returned as a string, as the clients may not hold the numbers
+/// beyond 2^53 exactly.
+impl ScalarType for i64 {
+  type RawScalarType = Self;
+
+  fn description() -> Option<String> {
+    Some("The `Long` scalar type represents 64-bit signed whole numeric values. It is inputted either as a number or as a numeric string, and returned as a string.".to_string())
+  }
+
+  fn type_name() -> std::borrow::Cow<'static, str> {
+    std::borrow::Cow::Borrowed("Long")
+  }
+
+  fn parse(value: Value) -> InputValueResult<Self> {
+    match value {
+      Value::Number(n) => n
+        .as_i64()
+        .ok_or_else(|| InputValueError::from("Invalid number")),
+      Value::String(s) => Ok(s.trim().parse::<i64>()?),
+      _ => Err(InputValueError::expected_type(value)),
+    }
+  }
+
+  fn is_valid(value: &Value) -> bool {
+    match value {
+      Value::Number(n) => n.is_i64(),
+      Value::String(s) => s.trim().parse::<i64>().is_ok(),
+      _ => false,
+    }
+  }
+
+  fn to_value(&self) -> Value {
+    Value::String(self.to_string())
+  }
+
+  fn serialize(value: Value) -> Value {
+    <Self as ScalarType>::parse(value.clone()).map_or(value, |long| long.to_value())
+  }
+
+  fn as_raw_scalar(&self) -> &Self::RawScalarType {
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_long_beyond_2_pow_53() {
+    let long = 9007199254740993i64;
+    let parse = |value| <i64 as ScalarType>::parse(value).ok();
+    assert_eq!(Some(long), parse(Value::from(long)));
+    assert_eq!(Some(long), parse(Value::from(" 9007199254740993 ")));
+    assert_eq!(None, parse(Value::from("9007199254740993.5")));
+    assert_eq!(
+      Value::from("9007199254740993"),
+      <i64 as ScalarType>::serialize(Value::from(long))
+    );
+  }
+}
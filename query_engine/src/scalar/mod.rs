@@ -1,9 +1,15 @@
 pub mod r#bool;
+pub mod bytes;
+pub mod date;
 pub mod datetime;
+pub mod decimal;
 pub mod float;
 pub mod id;
 pub mod int;
+pub mod json;
+pub mod long;
 pub mod string;
+pub mod uuid;
 
 use std::{borrow::Cow, sync::Arc};
 
@@ -40,6 +46,12 @@ pub trait ScalarType: Sized + Sync + Send {
   /// Convert the scalar to `Value`
   fn to_value(&self) -> Value;
 
+  /// Serializes the resolved value for the response, Ex. `Long` values are
+  /// returned as strings. By default the value is returned as is.
+  fn serialize(value: Value) -> Value {
+    value
+  }
+
   /// Reference to the raw value.
   fn as_raw_scalar(&self) -> &Self::RawScalarType;
 }
@@ -55,6 +67,7 @@ where
       name: <Self as ScalarType>::type_name().to_string(),
       description: <Self as ScalarType>::description(),
       is_valid: Option::Some(Arc::new(|value| <Self as ScalarType>::is_valid(value))),
+      serialize: Option::Some(Arc::new(|value| <Self as ScalarType>::serialize(value))),
       specified_by_url: <Self as ScalarType>::specified_by_url(),
     }
   }
@@ -0,0 +1,44 @@
+use super::ScalarType;
+use crate::{graphql_value::ConstValue as Value, InputValueError, InputValueResult};
+use ::uuid::Uuid;
+
+impl ScalarType for Uuid {
+  type RawScalarType = Self;
+
+  fn description() -> Option<String> {
+    Some(
+      "The `UUID` scalar type represents a UUID. The input/output is a string in the hyphenated format."
+        .to_string(),
+    )
+  }
+
+  fn specified_by_url() -> Option<String> {
+    Some("https://www.rfc-editor.org/rfc/rfc9562".to_string())
+  }
+
+  fn type_name() -> std::borrow::Cow<'static, str> {
+    std::borrow::Cow::Borrowed("UUID")
+  }
+
+  fn parse(value: Value) -> InputValueResult<Self> {
+    match value {
+      Value::String(s) => Ok(Uuid::parse_str(&s)?),
+      _ => Err(InputValueError::expected_type(value)),
+    }
+  }
+
+  fn is_valid(value: &Value) -> bool {
+    match value {
+      Value::String(s) => Uuid::parse_str(s).is_ok(),
+      _ => false,
+    }
+  }
+
+  fn to_value(&self) -> Value {
+    Value::String(self.hyphenated().to_string())
+  }
+
+  fn as_raw_scalar(&self) -> &Self::RawScalarType {
+    self
+  }
+}
@@ -1,9 +1,15 @@
-use std::{collections::HashMap, ops::Deref, sync::Arc};
+use std::{any::Any, collections::HashMap, ops::Deref, sync::Arc};
 
 use crate::{
-  context::Data, registry::Registry, validation::ValidationMode, CustomDirectiveFactory,
-  DataModel,
+  context::{Data, QueryEnv, QueryEnvInner},
+  execution,
+  graphql_parser::{self, types::OperationType},
+  registry::Registry,
+  validation::{check_rules, ValidationMode},
+  CustomDirectiveFactory, DataModel, Executor, Request, Response, SchemaError,
+  ServerError,
 };
+use db_engine::db_crud::DBStore;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum IntrospectionMode {
@@ -19,9 +25,12 @@ pub enum IntrospectionMode {
 #[doc(hidden)]
 pub struct SchemaEnvInner {
   pub data_model: DataModel,
+  /// Validation constraints of the model fields, with the patterns compiled.
+  pub(crate) constraints: execution::Constraints,
   pub registry: Registry,
   pub data: Data,
   pub custom_directives: HashMap<String, Box<dyn CustomDirectiveFactory>>,
+  pub db: Arc<dyn DBStore>,
 }
 
 #[doc(hidden)]
@@ -35,3 +44,248 @@ impl Deref for SchemaEnv {
     &self.0
   }
 }
+
+/// Builds the [Schema] from the SDML source.
+pub struct SchemaBuilder {
+  sdml: String,
+  db: Arc<dyn DBStore>,
+  data: Data,
+  custom_directives: HashMap<String, Box<dyn CustomDirectiveFactory>>,
+  introspection_mode: IntrospectionMode,
+  validation_mode: ValidationMode,
+  limit_depth: Option<usize>,
+}
+
+impl SchemaBuilder {
+  /// Sets the introspection mode of the schema.
+  #[must_use]
+  pub fn introspection_mode(mut self, introspection_mode: IntrospectionMode) -> Self {
+    self.introspection_mode = introspection_mode;
+    self
+  }
+
+  /// Sets the maximum depth of the queries.
+  #[must_use]
+  pub fn limit_depth(mut self, depth: usize) -> Self {
+    self.limit_depth = Some(depth);
+    self
+  }
+
+  /// Sets the validation mode, default is `ValidationMode::Strict`.
+  #[must_use]
+  pub fn validation_mode(mut self, validation_mode: ValidationMode) -> Self {
+    self.validation_mode = validation_mode;
+    self
+  }
+
+  /// Adds a global data that can be accessed in the `Schema`.
+  /// Accessed with `ctx.data::<T>()`.
+  #[must_use]
+  pub fn data<D: Any + Send + Sync>(mut self, data: D) -> Self {
+    self.data.insert(data);
+    self
+  }
+
+  /// Registers a custom directive.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the directive with the same name is already registered.
+  #[must_use]
+  pub fn directive<T: CustomDirectiveFactory>(mut self, directive: T) -> Self {
+    let name = directive.name();
+    let instance = Box::new(directive);
+
+    if self
+      .custom_directives
+      .insert(name.to_string(), instance)
+      .is_some()
+    {
+      panic!("custom directive `{}` already registered", name);
+    }
+    self
+  }
+
+  /// Parses the SDML source, generates the OpenCRUD API for the data model
+  /// and builds the schema.
+  pub fn finish(self) -> Result<Schema, SchemaError> {
+    let data_model = sdml_parser::parse(&self.sdml)?;
+    let crud_api = schema_transpiler::generate_crud_api(&data_model)?;
+    let service_doc = graphql_parser::parse_schema(crud_api)?;
+
+    let mut registry = Registry::build_registry(service_doc);
+    registry.introspection_mode = self.introspection_mode;
+    self
+      .custom_directives
+      .values()
+      .for_each(|directive| directive.register(&mut registry));
+
+    Ok(Schema {
+      env: SchemaEnv(Arc::new(SchemaEnvInner {
+        constraints: execution::Constraints::new(&data_model),
+        data_model,
+        registry,
+        data: self.data,
+        custom_directives: self.custom_directives,
+        db: self.db,
+      })),
+      validation_mode: self.validation_mode,
+      limit_depth: self.limit_depth,
+    })
+  }
+}
+
+/// GraphQL schema of the data model.
+///
+/// Executes the OpenCRUD queries & mutations against the data store.
+#[derive(Clone)]
+pub struct Schema {
+  env: SchemaEnv,
+  validation_mode: ValidationMode,
+  limit_depth: Option<usize>,
+}
+
+impl Schema {
+  /// Creates a schema builder for the data model in the SDML source,
+  /// backed by the given data store.
+  pub fn build(sdml: impl Into<String>, db: Arc<dyn DBStore>) -> SchemaBuilder {
+    SchemaBuilder {
+      sdml: sdml.into(),
+      db,
+      data: Data::default(),
+      custom_directives: HashMap::new(),
+      introspection_mode: IntrospectionMode::default(),
+      validation_mode: ValidationMode::Strict,
+      limit_depth: None,
+    }
+  }
+
+  /// Data model of the schema.
+  pub fn data_model(&self) -> &DataModel {
+    &self.env.data_model
+  }
+
+  async fn execute_once(&self, mut request: Request) -> Response {
+    // Parse & validate the request.
+    if let Err(error) = request.parsed_query() {
+      return Response::from_errors(vec![error]);
+    }
+    let document = request.parsed_query.take().unwrap();
+    if let Err(errors) = check_rules(
+      &self.env.registry,
+      &document,
+      Some(&request.variables),
+      self.validation_mode,
+      self.limit_depth,
+    ) {
+      return Response::from_errors(errors);
+    }
+    let operation = match execution::get_operation(
+      document.operations,
+      request.operation_name.as_deref(),
+    ) {
+      Ok(operation) => operation,
+      Err(error) => return Response::from_errors(vec![error]),
+    };
+
+    let root_type = match operation.node.ty {
+      OperationType::Query => Some(&self.env.registry.query_type),
+      OperationType::Mutation => self.env.registry.mutation_type.as_ref(),
+      OperationType::Subscription => None,
+    };
+    let Some(root_type) = root_type.and_then(|ty| self.env.registry.types.get(ty)) else {
+      return Response::from_errors(vec![ServerError::new(
+        format!("Schema is not configured for {}s.", operation.node.ty),
+        Some(operation.pos),
+      )]);
+    };
+
+    let query_env = QueryEnv::new(QueryEnvInner {
+      variables: request.variables,
+      operation_name: request.operation_name,
+      operation,
+      fragments: document.fragments,
+      session_data: Arc::new(Data::default()),
+      query_data: Arc::new(request.data),
+      http_headers: Default::default(),
+      introspection_mode: request.introspection_mode,
+      errors: Default::default(),
+    });
+    let ctx = query_env.create_context(
+      &self.env,
+      None,
+      &query_env.operation.node.selection_set,
+      None,
+    );
+    let serial = query_env.operation.node.ty == OperationType::Mutation;
+    let result = execution::resolve_root(&ctx, root_type, serial).await;
+
+    let mut errors = std::mem::take(&mut *query_env.errors.lock().unwrap());
+    let response = match result {
+      Ok(data) => Response::new(data),
+      Err(error) => {
+        errors.push(error);
+        Response::default()
+      }
+    };
+    let http_headers = std::mem::take(&mut *query_env.http_headers.lock().unwrap());
+    Response { errors, ..response }.http_headers(http_headers)
+  }
+}
+
+impl Executor for Schema {
+  async fn execute(&self, request: Request) -> Response {
+    self.execute_once(request).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use db_engine::db_crud::{DBMutation, DBQuery, DBStore};
+
+  use super::*;
+
+  struct EmptyStore;
+
+  impl DBStore for EmptyStore {
+    fn query(&self, _model_name: &str) -> Option<Box<dyn DBQuery>> {
+      None
+    }
+
+    fn mutation(&self, _model_name: &str) -> Option<Box<dyn DBMutation>> {
+      None
+    }
+  }
+
+  #[test]
+  fn test_schema_builder() {
+    let schema = Schema::build(
+      r#"
+      model User {
+        email ShortStr @id
+        name  ShortStr?
+      }
+      "#,
+      Arc::new(EmptyStore),
+    )
+    .introspection_mode(IntrospectionMode::Disabled)
+    .limit_depth(5)
+    .finish()
+    .unwrap();
+    assert!(schema.data_model().models.contains_key("User"));
+    assert_eq!(
+      schema.env.registry.introspection_mode,
+      IntrospectionMode::Disabled
+    );
+    assert_eq!(schema.limit_depth, Some(5));
+  }
+
+  #[test]
+  fn test_schema_builder_sdml_error() {
+    let result =
+      Schema::build("model User { email ShortStr }", Arc::new(EmptyStore)).finish();
+    assert!(matches!(result, Err(SchemaError::Sdml(errors)) if !errors.is_empty()));
+  }
+}
@@ -18,6 +18,7 @@ pub struct ValidationResult {
   pub depth: usize,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ValidationMode {
   /// Executes all validation rules.
   Strict,
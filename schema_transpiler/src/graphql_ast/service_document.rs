@@ -312,16 +312,24 @@ impl Type {
     r#type: &sdml_parser::types::PrimitiveType,
   ) -> String {
     use crate::graphql_gen::{
-      FIELD_TYPE_NAME_BOOL, FIELD_TYPE_NAME_INT, FIELD_TYPE_NAME_STRING,
-      FIELD_TYPE_SCALAR_DATETIME,
+      FIELD_TYPE_NAME_BOOL, FIELD_TYPE_NAME_FLOAT, FIELD_TYPE_NAME_INT,
+      FIELD_TYPE_NAME_STRING, FIELD_TYPE_SCALAR_BYTES, FIELD_TYPE_SCALAR_DATE,
+      FIELD_TYPE_SCALAR_DATETIME, FIELD_TYPE_SCALAR_DECIMAL, FIELD_TYPE_SCALAR_JSON,
+      FIELD_TYPE_SCALAR_LONG, FIELD_TYPE_SCALAR_UUID,
     };
     use sdml_parser::types::PrimitiveType;
     match r#type {
       PrimitiveType::ShortStr | PrimitiveType::LongStr => FIELD_TYPE_NAME_STRING,
       PrimitiveType::DateTime => FIELD_TYPE_SCALAR_DATETIME,
       PrimitiveType::Boolean => FIELD_TYPE_NAME_BOOL,
-      PrimitiveType::Int32 | PrimitiveType::Int64 => FIELD_TYPE_NAME_INT,
-      PrimitiveType::Float64 => FIELD_TYPE_NAME_BOOL,
+      PrimitiveType::Int32 => FIELD_TYPE_NAME_INT,
+      PrimitiveType::Int64 => FIELD_TYPE_SCALAR_LONG,
+      PrimitiveType::Float64 => FIELD_TYPE_NAME_FLOAT,
+      PrimitiveType::Decimal => FIELD_TYPE_SCALAR_DECIMAL,
+      PrimitiveType::Json => FIELD_TYPE_SCALAR_JSON,
+      PrimitiveType::Bytes => FIELD_TYPE_SCALAR_BYTES,
+      PrimitiveType::Date => FIELD_TYPE_SCALAR_DATE,
+      PrimitiveType::Uuid => FIELD_TYPE_SCALAR_UUID,
     }
     .to_string()
   }
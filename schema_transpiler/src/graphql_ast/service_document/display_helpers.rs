@@ -265,6 +265,7 @@ impl fmt::Display for ConstDirective {
 
 /// When there is a description, writes the description encloded by new-lines both before and after.
 /// If the description is empty, then it just writes a new-line.
+/// Note: `"""` in the description is escaped, as it is written as a block string.
 #[inline(always)]
 fn ln_display_description_ln(
   f: &mut fmt::Formatter,
@@ -272,7 +273,11 @@ fn ln_display_description_ln(
 ) -> fmt::Result {
   write!(f, "\n")?;
   if desc.is_some() && desc.as_ref().unwrap().len() > 0 {
-    write!(f, "\"\"\"{}\"\"\"\n", desc.as_ref().unwrap())
+    writeln!(
+      f,
+      "\"\"\"{}\"\"\"",
+      desc.as_ref().unwrap().replace(r#"""""#, r#"\""""#)
+    )
   } else {
     Ok(())
   }
@@ -5,9 +5,17 @@ use super::*;
 pub fn crud_api_def(
   data_model: &sdml_ast::DataModel,
 ) -> GraphQLGenResult<Vec<TypeSystemDefinition>> {
+  // Note: Types are generated in the order the declarations are found in the source.
+  let models = data_model.models().values().collect::<Vec<_>>();
   let mut api_type_defs = Vec::new();
   // Custom Scalars.
   api_type_defs.push(TypeSystemDefinition::Type(misc_type::scalar_date_time_def()));
+  api_type_defs.push(TypeSystemDefinition::Type(misc_type::scalar_long_def()));
+  api_type_defs.push(TypeSystemDefinition::Type(misc_type::scalar_decimal_def()));
+  api_type_defs.push(TypeSystemDefinition::Type(misc_type::scalar_json_def()));
+  api_type_defs.push(TypeSystemDefinition::Type(misc_type::scalar_bytes_def()));
+  api_type_defs.push(TypeSystemDefinition::Type(misc_type::scalar_date_def()));
+  api_type_defs.push(TypeSystemDefinition::Type(misc_type::scalar_uuid_def()));
   // Custom Directives.
   api_type_defs.push(TypeSystemDefinition::Directive(
     misc_type::directive_map_def(),
@@ -21,11 +29,11 @@ pub fn crud_api_def(
 
   // Root query type.
   api_type_defs.push(TypeSystemDefinition::Type(
-    root_query_type::root_query_type_def(&data_model.models_sorted())?,
+    root_query_type::root_query_type_def(&models)?,
   ));
   // Root mutation type
   api_type_defs.push(TypeSystemDefinition::Type(
-    root_mutation_type::root_mutation_type_def(&data_model.models_sorted())?,
+    root_mutation_type::root_mutation_type_def(&models)?,
   ));
 
   // Root Node interface.
@@ -33,21 +41,40 @@ pub fn crud_api_def(
   // Enums
   let mut api_type_defs =
     data_model
-      .enums_sorted()
-      .iter()
+      .enums()
+      .values()
       .try_fold(api_type_defs, |mut acc, r#enum| {
         acc.push(TypeSystemDefinition::Type(enum_type::enum_def(r#enum)?));
         Ok(acc)
       })?;
+  // Composite types, with their create, update & where input types.
+  data_model.types().values().try_for_each(|r#type| {
+    api_type_defs.push(TypeSystemDefinition::Type(r#type::composite_type_def(
+      r#type,
+    )?));
+    api_type_defs.push(TypeSystemDefinition::Type(
+      input_type::create::composite_create_input_def(r#type)?,
+    ));
+    api_type_defs.push(TypeSystemDefinition::Type(
+      input_type::update::composite_update_input_def(r#type)?,
+    ));
+    api_type_defs.push(TypeSystemDefinition::Type(
+      input_type::filter::r#where::composite_where_input_def(r#type)?,
+    ));
+    GraphQLGenResult::Ok(())
+  })?;
   // Common types.
   api_type_defs.push(TypeSystemDefinition::Type(aux_type::page_info_type_def()?));
   api_type_defs.push(TypeSystemDefinition::Type(aux_type::aggregage_type_def()?));
   api_type_defs.push(TypeSystemDefinition::Type(
     input_type::update::connect_position_input_def()?,
   ));
+  api_type_defs.push(TypeSystemDefinition::Type(
+    input_type::filter::r#where::json_path_filter_input_def()?,
+  ));
 
   // Model specific types & Models.
-  data_model.models_sorted().iter().try_for_each(|model| {
+  models.iter().try_for_each(|model| {
     // Filters & Order_By
     api_type_defs.push(TypeSystemDefinition::Type(
       input_type::filter::r#where::where_input_def(model)?,
@@ -55,6 +82,11 @@ pub fn crud_api_def(
     api_type_defs.push(TypeSystemDefinition::Type(
       input_type::filter::where_unique::where_unique_unique_input_def(model)?,
     ));
+    api_type_defs.extend(
+      input_type::filter::where_unique::where_unique_compound_input_defs(model)?
+        .into_iter()
+        .map(TypeSystemDefinition::Type),
+    );
     api_type_defs.push(TypeSystemDefinition::Type(
       input_type::order_by_input::order_by_input_enum_def(model)?,
     ));
@@ -115,4 +147,48 @@ mod tests {
     actual_crud_api_graphql_str.retain(|c| !c.is_whitespace());
     assert_eq!(expected_graphql_str, actual_crud_api_graphql_str);
   }
+
+  #[test]
+  fn test_crud_api_def_composite_types() {
+    let sdml_str = fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/test_composite_types_def.sdml"
+    ))
+    .unwrap();
+    let mut expected_graphql_str = fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/test_composite_types_def.graphql"
+    ))
+    .unwrap();
+    expected_graphql_str.retain(|c| !c.is_whitespace());
+
+    let sdml_ast = sdml_parser::parse(&sdml_str).unwrap();
+    let crud_api = crud_api_def(&sdml_ast).unwrap();
+    // Note: Composite types & the user's input types, which embed them.
+    let type_names = [
+      "Address",
+      "AddressCreateInput",
+      "AddressUpdateInput",
+      "AddressWhereInput",
+      "Landmark",
+      "LandmarkCreateInput",
+      "LandmarkUpdateInput",
+      "LandmarkWhereInput",
+      "UserWhereInput",
+      "User",
+      "UserCreateInput",
+      "UserUpdateInput",
+    ];
+    let mut actual_graphql_str = crud_api
+      .iter()
+      .filter(|graphql_ty| match graphql_ty {
+        TypeSystemDefinition::Type(ty) => type_names.contains(&ty.name.as_str()),
+        _ => false,
+      })
+      .fold("".to_string(), |acc, graphql_ty| {
+        format!("{}{}", acc, graphql_ty)
+      });
+    actual_graphql_str.retain(|c| !c.is_whitespace());
+    assert_eq!(expected_graphql_str, actual_graphql_str);
+  }
 }
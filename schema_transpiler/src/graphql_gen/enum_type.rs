@@ -8,7 +8,7 @@ pub fn enum_def(r#enum: &sdml_ast::EnumDecl) -> GraphQLGenResult<TypeDefinition>
     .map_err(ErrorGraphQLGen::new_sdml_error)?;
   Ok(TypeDefinition {
     extend: false,
-    description: None,
+    description: r#enum.docs.as_ref().map(ToString::to_string),
     name: enum_name,
     directives: vec![],
     kind: TypeKind::Enum(EnumType {
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use sdml_parser::types::Span;
 
 /// Errors during SDML to GraphQL transpilation.
@@ -20,3 +22,15 @@ impl ErrorGraphQLGen {
     }
   }
 }
+
+impl fmt::Display for ErrorGraphQLGen {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::SDMLError { error, pos } => {
+        write!(f, "{} at {}..{}", error, pos.start, pos.end)
+      }
+    }
+  }
+}
+
+impl std::error::Error for ErrorGraphQLGen {}
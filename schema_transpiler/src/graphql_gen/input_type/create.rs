@@ -23,6 +23,7 @@ fn create_input_def(model: &sdml_ast::ModelDecl) -> GraphQLGenResult<TypeDefinit
   // 1. Relation scalar fields will be populated with the content of *CreateInlineInput fields.
   // 2. Autogenerated ids will be auto-generated by DB engine module, not inputted by the user.
   // 3. CreateInput type can include input-arg for unique fields.
+  // 4. @updatedAt fields are assigned by DB engine module on every mutation.
   let model_fields = model.get_fields();
   let mut non_relation_fields = Vec::new();
   model_fields.id.iter().for_each(|(id_field, is_auto_gen)| {
@@ -31,8 +32,7 @@ fn create_input_def(model: &sdml_ast::ModelDecl) -> GraphQLGenResult<TypeDefinit
     }
   });
   non_relation_fields.extend(&model_fields.unique);
-  non_relation_fields
-    .extend(model_fields.get_rest(sdml_ast::ModelIndexedFieldsFilter::All));
+  non_relation_fields.extend(rest_input_fields(&model_fields));
 
   let mut input_field_defs = non_relation_fields
     .into_iter()
@@ -80,6 +80,15 @@ fn non_relation_field_input_def(
         .map_err(ErrorGraphQLGen::new_sdml_error)?
         .to_string(),
     ),
+    sdml_ast::Type::Composite { composite_ty_name } => Ok(
+      open_crud_name::types::CreateInput::Create
+        .name(
+          composite_ty_name
+            .try_get_ident_name()
+            .map_err(ErrorGraphQLGen::new_sdml_error)?,
+        )
+        .to_string(),
+    ),
   }?;
   let mut description = None;
   let default_attribute = field.default_attribute();
@@ -105,7 +114,9 @@ fn non_relation_field_input_def(
       .try_get_graphql_name()
       .map_err(ErrorGraphQLGen::new_sdml_error)?,
     ty: Type::new(&ty_str, type_mod),
-    default_value: None, // Note: The default value is set to None. The appropriate default
+    // Note: Only the constant defaults are set, the generated ones (Ex. `now()`) are
+    // assigned by the storage backend.
+    default_value: field.default_value(),
     directives: vec![],
   })
 }
@@ -148,6 +159,31 @@ fn relation_field_input_def(
   }
 }
 
+/// Input type used to create the embedded value of the composite type.
+/// Ex. AddressCreateInput is used inside UserCreateInput to set the user's address.
+pub fn composite_create_input_def(
+  r#type: &sdml_ast::TypeDecl,
+) -> GraphQLGenResult<TypeDefinition> {
+  let input_field_defs = r#type
+    .fields
+    .iter()
+    .map(non_relation_field_input_def)
+    .collect::<GraphQLGenResult<Vec<InputValueDefinition>>>()?;
+  let type_name = r#type
+    .name
+    .try_get_ident_name()
+    .map_err(ErrorGraphQLGen::new_sdml_error)?;
+  Ok(TypeDefinition {
+    extend: false,
+    description: None,
+    name: open_crud_name::types::CreateInput::Create.name(type_name),
+    directives: vec![],
+    kind: TypeKind::InputObject(InputObjectType {
+      fields: input_field_defs,
+    }),
+  })
+}
+
 /// Input type used to create one object in one side of the relation
 /// in a nested create.
 /// Ex. ProfileCreateOneInlineInput will be used inside UserCreateInput
@@ -281,8 +317,8 @@ mod tests {
     let data_model = sdml_parser::parse(&sdml_str)
       .expect("A valid SDML file shouldn't fail in parsing.");
     let create_input_types_def_graphql_ast = data_model
-      .models_sorted()
-      .iter()
+      .models()
+      .values()
       .flat_map(|model| {
         create_input_types_def(model).expect(
           "create_input_types_def should return with all input types for the model.",
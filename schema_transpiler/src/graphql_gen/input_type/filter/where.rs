@@ -3,20 +3,35 @@ use super::*;
 
 /// Generates where input type for the given model.
 pub fn where_input_def(model: &sdml_ast::ModelDecl) -> GraphQLGenResult<TypeDefinition> {
-  let mut filters = logical_operations_def(&model.name)?;
-  let model_field_filters = model.fields.iter().map(field_to_filters).try_fold(
-    Vec::new(),
-    |mut acc, filters| match filters {
-      Ok(filters) => {
-        acc.extend(filters.into_iter());
-        Ok(acc)
-      }
-      Err(e) => Err(e),
-    },
-  )?;
+  fields_where_input_def(&model.name, &model.fields)
+}
+
+/// Generates where input type for the given composite type, which filters the
+/// embedded values. Ex. `AddressWhereInput`.
+pub fn composite_where_input_def(
+  r#type: &sdml_ast::TypeDecl,
+) -> GraphQLGenResult<TypeDefinition> {
+  fields_where_input_def(&r#type.name, &r#type.fields)
+}
+
+fn fields_where_input_def(
+  name: &sdml_ast::Token,
+  fields: &[sdml_ast::FieldDecl],
+) -> GraphQLGenResult<TypeDefinition> {
+  let mut filters = logical_operations_def(name)?;
+  let model_field_filters =
+    fields
+      .iter()
+      .map(field_to_filters)
+      .try_fold(Vec::new(), |mut acc, filters| match filters {
+        Ok(filters) => {
+          acc.extend(filters);
+          Ok(acc)
+        }
+        Err(e) => Err(e),
+      })?;
   filters.extend(model_field_filters.into_iter());
-  let model_name = model
-    .name
+  let model_name = name
     .try_get_ident_name()
     .map_err(ErrorGraphQLGen::new_sdml_error)?;
   Ok(TypeDefinition {
@@ -35,7 +50,7 @@ fn field_to_filters(
   field: &sdml_ast::FieldDecl,
 ) -> GraphQLGenResult<Vec<InputValueDefinition>> {
   let field_type = &*field.field_type.r#type();
-  match field_type {
+  let mut filters = match field_type {
     sdml_ast::Type::Unknown(_) => {
       let field_name = field
         .name
@@ -46,7 +61,22 @@ fn field_to_filters(
         pos: field.name.span(),
       })
     }
-    sdml_ast::Type::Relation(_) => relation_field_def(&field.name, &field.field_type),
+    // Note: Embedded values of the composite type are filtered like the related objects.
+    sdml_ast::Type::Relation(_) | sdml_ast::Type::Composite { .. } => {
+      relation_field_def(&field.name, &field.field_type)
+    }
+    // Note: Scalar lists are filtered on their items, rather than like a single value.
+    sdml_ast::Type::Primitive { r#type, .. } if field.field_type.is_array() => {
+      scalar_list_field_def(&field.name, &Type::map_sdml_type_to_graphql_ty_name(r#type))
+    }
+    sdml_ast::Type::Enum { enum_ty_name } if field.field_type.is_array() => {
+      scalar_list_field_def(
+        &field.name,
+        enum_ty_name
+          .try_get_ident_name()
+          .map_err(ErrorGraphQLGen::new_sdml_error)?,
+      )
+    }
     sdml_ast::Type::Enum { .. } => enum_field_def(&field.name, field_type),
     sdml_ast::Type::Primitive {
       r#type: primitive_type,
@@ -56,18 +86,45 @@ fn field_to_filters(
         id_field_def(&field.name)
       }
       sdml_ast::PrimitiveType::ShortStr | sdml_ast::PrimitiveType::LongStr => {
-        string_field_def(&field.name)
+        let mut filters = string_field_def(&field.name)?;
+        filters.extend(case_insensitive_field_def(&field.name)?);
+        Ok(filters)
       }
       sdml_ast::PrimitiveType::Boolean => boolean_field_def(&field.name),
       sdml_ast::PrimitiveType::DateTime => datetime_field_def(&field.name),
-      sdml_ast::PrimitiveType::Int32 | sdml_ast::PrimitiveType::Int64 => {
+      sdml_ast::PrimitiveType::Int32 => {
         number_field_def(&field.name, NumberType::Integer)
       }
+      sdml_ast::PrimitiveType::Int64 => number_field_def(&field.name, NumberType::Long),
       sdml_ast::PrimitiveType::Float64 => {
         number_field_def(&field.name, NumberType::Float)
       }
+      sdml_ast::PrimitiveType::Decimal => {
+        comparable_field_def(&field.name, graphql_gen::FIELD_TYPE_SCALAR_DECIMAL)
+      }
+      sdml_ast::PrimitiveType::Date => {
+        comparable_field_def(&field.name, graphql_gen::FIELD_TYPE_SCALAR_DATE)
+      }
+      sdml_ast::PrimitiveType::Uuid => uuid_field_def(&field.name),
+      sdml_ast::PrimitiveType::Bytes => bytes_field_def(&field.name),
+      sdml_ast::PrimitiveType::Json => json_field_def(&field.name),
     },
+  }?;
+  // Note: Optional relation & composite fields get `_is_null` along with their filters.
+  if field.field_type.is_optional()
+    && matches!(
+      field_type,
+      sdml_ast::Type::Primitive { .. } | sdml_ast::Type::Enum { .. }
+    )
+  {
+    filters.extend(generate_where_input_filters(
+      &field.name,
+      FIELD_TYPE_NAME_BOOL,
+      &[],
+      &[("{}_is_null", "is the field null")],
+    )?);
   }
+  Ok(filters)
 }
 
 /// Generates necessary filter arguments for id field.
@@ -107,8 +164,28 @@ fn string_field_def(
   )
 }
 
+/// Generates the filter arguments of a string field, which ignore the case.
+fn case_insensitive_field_def(
+  field_name: &sdml_ast::Token,
+) -> GraphQLGenResult<Vec<InputValueDefinition>> {
+  let non_list_field_names_fmt = [
+    ("{}_equals_i", "equals, ignoring the case"),
+    ("{}_contains_i", "contains substring, ignoring the case"),
+    ("{}_starts_with_i", "starts with, ignoring the case"),
+    ("{}_ends_with_i", "ends with, ignoring the case"),
+  ];
+  generate_where_input_filters(
+    field_name,
+    graphql_gen::FIELD_TYPE_NAME_STRING,
+    &[],
+    &non_list_field_names_fmt,
+  )
+}
+
 enum NumberType {
   Integer,
+  /// 64-bit integer.
+  Long,
   Float,
 }
 fn number_field_def(
@@ -128,6 +205,7 @@ fn number_field_def(
 
   let num_type = match number_type {
     NumberType::Integer => graphql_gen::FIELD_TYPE_NAME_INT,
+    NumberType::Long => graphql_gen::FIELD_TYPE_SCALAR_LONG,
     NumberType::Float => graphql_gen::FIELD_TYPE_NAME_FLOAT,
   };
   generate_where_input_filters(
@@ -152,6 +230,15 @@ fn boolean_field_def(
 
 fn datetime_field_def(
   field_name: &sdml_ast::Token,
+) -> GraphQLGenResult<Vec<InputValueDefinition>> {
+  comparable_field_def(field_name, graphql_gen::FIELD_TYPE_SCALAR_DATETIME)
+}
+
+/// Generates necessary filter arguments for a field, whose values are ordered.
+/// Ex. DateTime, Decimal & Date fields.
+fn comparable_field_def(
+  field_name: &sdml_ast::Token,
+  field_type_name: &str,
 ) -> GraphQLGenResult<Vec<InputValueDefinition>> {
   let list_field_names_fmt = [("{}_in", "in list"), ("{}_not_in", "not in list")];
   let non_list_field_names_fmt = [
@@ -164,12 +251,120 @@ fn datetime_field_def(
   ];
   generate_where_input_filters(
     field_name,
-    graphql_gen::FIELD_TYPE_SCALAR_DATETIME,
+    field_type_name,
     &list_field_names_fmt,
     &non_list_field_names_fmt,
   )
 }
 
+fn uuid_field_def(
+  field_name: &sdml_ast::Token,
+) -> GraphQLGenResult<Vec<InputValueDefinition>> {
+  let list_field_names_fmt = [("{}_in", "in list"), ("{}_not_in", "not in list")];
+  let non_list_field_names_fmt = [("{}", "equals"), ("{}_not", "not equals")];
+  generate_where_input_filters(
+    field_name,
+    graphql_gen::FIELD_TYPE_SCALAR_UUID,
+    &list_field_names_fmt,
+    &non_list_field_names_fmt,
+  )
+}
+
+fn bytes_field_def(
+  field_name: &sdml_ast::Token,
+) -> GraphQLGenResult<Vec<InputValueDefinition>> {
+  let non_list_field_names_fmt = [("{}", "equals"), ("{}_not", "not equals")];
+  generate_where_input_filters(
+    field_name,
+    graphql_gen::FIELD_TYPE_SCALAR_BYTES,
+    &[],
+    &non_list_field_names_fmt,
+  )
+}
+
+/// Generates necessary filter arguments for a json field, along with the filter
+/// on the value found at a path inside the json value.
+fn json_field_def(
+  field_name: &sdml_ast::Token,
+) -> GraphQLGenResult<Vec<InputValueDefinition>> {
+  let non_list_field_names_fmt = [("{}", "equals"), ("{}_not", "not equals")];
+  let mut filters = generate_where_input_filters(
+    field_name,
+    graphql_gen::FIELD_TYPE_SCALAR_JSON,
+    &[],
+    &non_list_field_names_fmt,
+  )?;
+  let field_name = field_name
+    .try_get_ident_name()
+    .map_err(ErrorGraphQLGen::new_sdml_error)?;
+  filters.push(InputValueDefinition {
+    description: Some("value at the path matches the filter".to_string()),
+    name: Name::new(format!("{field_name}_path")),
+    ty: open_crud_name::types::FilterInput::JsonPath.common_ty(TypeMod::Optional),
+    default_value: None,
+    directives: vec![],
+  });
+  Ok(filters)
+}
+
+/// Generates the filter input type on the value found at a path inside a json field,
+/// it is common for all the json fields.
+/// Ex. `{settings_path: {path: ["theme", "color"], equals: "dark"}}`.
+pub fn json_path_filter_input_def() -> GraphQLGenResult<TypeDefinition> {
+  let mut fields = vec![InputValueDefinition {
+    description: Some(
+      "Keys of the nested objects (or the indexes of the nested lists) leading to the value."
+        .to_string(),
+    ),
+    name: Name::new("path"),
+    ty: Type::new_from_str(&format!("[{FIELD_TYPE_NAME_STRING}!]!")).unwrap(),
+    default_value: None,
+    directives: vec![],
+  }];
+  fields.extend(
+    [
+      ("equals", "equals", graphql_gen::FIELD_TYPE_SCALAR_JSON),
+      ("not", "not equals", graphql_gen::FIELD_TYPE_SCALAR_JSON),
+      (
+        "string_contains",
+        "contains substring",
+        FIELD_TYPE_NAME_STRING,
+      ),
+      ("string_starts_with", "starts with", FIELD_TYPE_NAME_STRING),
+      ("string_ends_with", "ends with", FIELD_TYPE_NAME_STRING),
+      ("lt", "less than", graphql_gen::FIELD_TYPE_SCALAR_JSON),
+      (
+        "lte",
+        "less than or equals",
+        graphql_gen::FIELD_TYPE_SCALAR_JSON,
+      ),
+      ("gt", "greater than", graphql_gen::FIELD_TYPE_SCALAR_JSON),
+      (
+        "gte",
+        "greater than or equals",
+        graphql_gen::FIELD_TYPE_SCALAR_JSON,
+      ),
+    ]
+    .into_iter()
+    .map(|(name, description, ty)| InputValueDefinition {
+      description: Some(description.to_string()),
+      name: Name::new(name),
+      ty: Type::new(ty, TypeMod::Optional),
+      default_value: None,
+      directives: vec![],
+    }),
+  );
+  Ok(TypeDefinition {
+    extend: false,
+    description: Some(
+      "The filter on the value found at the path inside a json field, a list value matches if any of its items match.".to_string(),
+    ),
+    name: open_crud_name::types::FilterInput::JsonPath.common_name(),
+    directives: vec![],
+    kind: TypeKind::InputObject(InputObjectType { fields }),
+  })
+}
+
 fn enum_field_def(
   field_name: &sdml_ast::Token,
   r#type: &sdml_ast::Type,
@@ -194,6 +389,48 @@ fn enum_field_def(
   )
 }
 
+/// Generates necessary filter arguments for a scalar list field, which filter on
+/// its items & its length. Ex. `nickNames_has`, `nickNames_length_gt`.
+fn scalar_list_field_def(
+  field_name: &sdml_ast::Token,
+  item_type_name: &str,
+) -> GraphQLGenResult<Vec<InputValueDefinition>> {
+  let mut filters = generate_where_input_filters(
+    field_name,
+    item_type_name,
+    &[("{}", "equals"), ("{}_not", "not equals")],
+    &[],
+  )?;
+  filters.extend(generate_where_input_filters(
+    field_name,
+    item_type_name,
+    &[
+      ("{}_has_every", "contains all the items"),
+      ("{}_has_some", "contains at least one of the items"),
+    ],
+    &[("{}_has", "contains the item")],
+  )?);
+  filters.extend(generate_where_input_filters(
+    field_name,
+    FIELD_TYPE_NAME_BOOL,
+    &[],
+    &[("{}_is_empty", "is the list empty")],
+  )?);
+  filters.extend(generate_where_input_filters(
+    field_name,
+    graphql_gen::FIELD_TYPE_NAME_INT,
+    &[],
+    &[
+      ("{}_length", "length equals"),
+      ("{}_length_lt", "length less than"),
+      ("{}_length_lte", "length less than or equals"),
+      ("{}_length_gt", "length greater than"),
+      ("{}_length_gte", "length greater than or equals"),
+    ],
+  )?);
+  Ok(filters)
+}
+
 fn relation_field_def(
   field_name: &sdml_ast::Token,
   target_relation: &sdml_ast::FieldType,
@@ -374,6 +611,32 @@ mod tests {
     assert_eq!(expected_graphql_str, user_where_input_graphql)
   }
 
+  #[test]
+  fn test_primitive_types_where_input_def() {
+    let mut expected_graphql_str = fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/input_type/account_where_input.graphql"
+    ))
+    .unwrap();
+    expected_graphql_str.retain(|c| !c.is_whitespace());
+    let sdml_str = fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/input_type/account_where_input.sdml"
+    ))
+    .unwrap();
+    let data_model = sdml_parser::parse(&sdml_str)
+      .expect("A valid SDML file shouldn't fail in parsing.");
+    let account_model_sdml_ast = data_model
+      .models()
+      .get("Account")
+      .expect("Account model should exist in the SDML.");
+    let account_where_input_graphql_ast = where_input_def(account_model_sdml_ast)
+      .expect("It should return AccountWhereInput");
+    let mut account_where_input_graphql = account_where_input_graphql_ast.to_string();
+    account_where_input_graphql.retain(|c| !c.is_whitespace());
+    assert_eq!(expected_graphql_str, account_where_input_graphql)
+  }
+
   #[test]
   fn test_input_filters_str_field_def() {
     let expected_str = r#"
@@ -486,6 +749,26 @@ field_not: Boolean"#;
     assert_eq!(expected_str, actual_str);
   }
 
+  #[test]
+  fn test_input_filters_json_field_def() {
+    let expected_str = r#"
+"""equals"""
+settings: Json
+"""not equals"""
+settings_not: Json
+"""value at the path matches the filter"""
+settings_path: JsonPathFilterInput"#;
+    let json_field_input_filters = json_field_def(&sdml_ast::Token::Ident(
+      Str::new("settings"),
+      Span::new(0, 0),
+    ))
+    .expect("It should be a valid output");
+    let actual_str = json_field_input_filters
+      .into_iter()
+      .fold("".to_string(), |acc, x| format!("{}{}", acc, x));
+    assert_eq!(expected_str, actual_str);
+  }
+
   #[test]
   fn test_input_filters_enum_field_def() {
     let expected_str = r#"
@@ -1,6 +1,9 @@
 //! Impelments code-gen functions for generating WhereUniqueInput filter type.
 use super::*;
 
+/// Suffix of the input type capturing the fields of a compound unique key.
+const COMPOUND_UNIQUE_INPUT_SUFFIX: &str = "CompoundUniqueInput";
+
 /// Generates WhereUniqueInput filter type for the given model.
 /// When this fitler gets passed as an argument,
 /// it will exactly match *at-most* 1 record in the graphQL response.
@@ -13,14 +16,38 @@ pub fn where_unique_unique_input_def(
     .fields
     .iter()
     .filter(|fld| (fld.has_id_attrib() | fld.has_unique_attrib()));
-  let unique_field_filters = unique_scalar_fields
-    .map(unique_scalar_field_to_filter)
-    .collect::<Result<Vec<InputValueDefinition>, ErrorGraphQLGen>>(
-  )?;
   let model_name = model
     .name
     .try_get_ident_name()
     .map_err(ErrorGraphQLGen::new_sdml_error)?; // Note: Model should have a valid identifier, otherwise it will be caught by SDML parser! So we can just use unwrap()..
+  let mut unique_field_filters = Vec::new();
+  // Note: Model with compound ID gets the `id` field, which identifies its objects.
+  if model.compound_id().is_some() {
+    unique_field_filters.push(InputValueDefinition {
+      description: None,
+      name: open_crud_name::fields::Field::Id.common_name(),
+      ty: open_crud_name::types::OpenCRUDType::IdType.common_ty(TypeMod::Optional),
+      default_value: None,
+      directives: vec![],
+    });
+  }
+  unique_field_filters.extend(
+    unique_scalar_fields
+      .map(unique_scalar_field_to_filter)
+      .collect::<Result<Vec<InputValueDefinition>, ErrorGraphQLGen>>()?,
+  );
+  unique_field_filters.extend(compound_keys(model).map(|compound_key| {
+    InputValueDefinition {
+      description: None,
+      name: Name::new(compound_key.compound_name()),
+      ty: Type::new(
+        &compound_unique_input_name(model_name, compound_key),
+        TypeMod::Optional,
+      ),
+      default_value: None,
+      directives: vec![],
+    }
+  }));
   Ok(TypeDefinition {
     extend: false,
     description: Some(
@@ -34,6 +61,84 @@ pub fn where_unique_unique_input_def(
   })
 }
 
+/// Generates the input types of the compound unique keys (`@@id` & `@@unique`) of the
+/// given model. Ex. `UserTenantIdEmailCompoundUniqueInput` for `@@unique([tenantId, email])`,
+/// which is used as `tenantId_email` field in `UserWhereUniqueInput`.
+pub fn where_unique_compound_input_defs(
+  model: &sdml_ast::ModelDecl,
+) -> GraphQLGenResult<Vec<TypeDefinition>> {
+  let model_name = model
+    .name
+    .try_get_ident_name()
+    .map_err(ErrorGraphQLGen::new_sdml_error)?;
+  compound_keys(model)
+    .map(|compound_key| {
+      let fields = compound_key
+        .fields
+        .iter()
+        .map(|field_name| {
+          let field = model
+            .field_by_name(
+              field_name
+                .try_get_ident_name()
+                .map_err(ErrorGraphQLGen::new_sdml_error)?,
+            )
+            .ok_or_else(|| ErrorGraphQLGen::SDMLError {
+              error: "Field of the compound key is not found in the model".to_string(),
+              pos: field_name.span(),
+            })?;
+          Ok(InputValueDefinition {
+            description: None,
+            name: field
+              .name
+              .try_get_graphql_name()
+              .map_err(ErrorGraphQLGen::new_sdml_error)?,
+            ty: unique_field_ty(field, TypeMod::NonOptional)?,
+            default_value: None,
+            directives: vec![],
+          })
+        })
+        .collect::<GraphQLGenResult<Vec<InputValueDefinition>>>()?;
+      Ok(TypeDefinition {
+        extend: false,
+        description: None,
+        name: Name::new(compound_unique_input_name(model_name, compound_key)),
+        directives: vec![],
+        kind: TypeKind::InputObject(InputObjectType { fields }),
+      })
+    })
+    .collect()
+}
+
+/// Compound unique keys of the model, compound ID comes first.
+fn compound_keys(
+  model: &sdml_ast::ModelDecl,
+) -> impl Iterator<Item = &sdml_ast::ModelAttribute> {
+  model
+    .compound_id()
+    .into_iter()
+    .chain(model.compound_uniques())
+}
+
+/// Name of the input type of the compound unique key.
+/// Ex. `UserTenantIdEmailCompoundUniqueInput` for `@@unique([tenantId, email])`.
+fn compound_unique_input_name(
+  model_name: &str,
+  compound_key: &sdml_ast::ModelAttribute,
+) -> String {
+  let fields_pc = compound_key
+    .field_names()
+    .iter()
+    .map(|field_name| {
+      let mut chars = field_name.chars();
+      chars.next().map_or(String::new(), |first| {
+        first.to_uppercase().chain(chars).collect()
+      })
+    })
+    .collect::<String>();
+  format!("{model_name}{fields_pc}{COMPOUND_UNIQUE_INPUT_SUFFIX}")
+}
+
 fn unique_scalar_field_to_filter(
   field: &sdml_ast::FieldDecl,
 ) -> GraphQLGenResult<InputValueDefinition> {
@@ -42,23 +147,47 @@ fn unique_scalar_field_to_filter(
     "Only scalar fields with @id or @unique attribute should passed to this function"
   );
   let is_id_field = field.has_id_attrib();
-  let ty = match &*field.field_type.r#type() {
+  let ty = unique_field_ty(field, TypeMod::Optional)?;
+  Ok(InputValueDefinition {
+    description: None,
+    name: if is_id_field {
+      open_crud_name::fields::Field::Id.common_name()
+    } else {
+      let field_name = field
+        .name
+        .try_get_graphql_name()
+        .map_err(ErrorGraphQLGen::new_sdml_error)?;
+      Name::new(field_name)
+    },
+    ty,
+    default_value: None,
+    directives: vec![],
+  })
+}
+
+/// Type of the unique field in the filter, `@id` field is typed as `ID`.
+fn unique_field_ty(
+  field: &sdml_ast::FieldDecl,
+  type_mod: TypeMod,
+) -> GraphQLGenResult<Type> {
+  let is_id_field = field.has_id_attrib();
+  match field.field_type.r#type() {
     sdml_ast::Type::Enum { enum_ty_name } => {
       let ty_name = enum_ty_name
         .try_get_ident_name()
         .map_err(ErrorGraphQLGen::new_sdml_error)?;
-      Ok(Type::new(ty_name, TypeMod::Optional))
+      Ok(Type::new(ty_name, type_mod))
     }
     sdml_ast::Type::Primitive {
       r#type: primitive_type,
       ..
     } => match primitive_type {
       sdml_ast::PrimitiveType::ShortStr if is_id_field => {
-        Ok(open_crud_name::types::OpenCRUDType::IdType.common_ty(TypeMod::Optional))
+        Ok(open_crud_name::types::OpenCRUDType::IdType.common_ty(type_mod))
       }
       sdml_prim_type => {
         let graphql_ty_name = Type::map_sdml_type_to_graphql_ty_name(sdml_prim_type);
-        Ok(Type::new(&graphql_ty_name, TypeMod::Optional))
+        Ok(Type::new(&graphql_ty_name, type_mod))
       }
     },
     other_type => {
@@ -77,22 +206,7 @@ fn unique_scalar_field_to_filter(
                 pos: field.name.span(),
             })
     }
-  }?;
-  Ok(InputValueDefinition {
-    description: None,
-    name: if is_id_field {
-      open_crud_name::fields::Field::Id.common_name()
-    } else {
-      let field_name = field
-        .name
-        .try_get_graphql_name()
-        .map_err(ErrorGraphQLGen::new_sdml_error)?;
-      Name::new(field_name)
-    },
-    ty,
-    default_value: None,
-    directives: vec![],
-  })
+  }
 }
 
 #[cfg(test)]
@@ -126,4 +240,42 @@ mod tests {
     user_where_input_graphql.retain(|c| !c.is_whitespace());
     assert_eq!(expected_graphql_str, user_where_input_graphql)
   }
+
+  #[test]
+  fn test_compound_where_unique_def() {
+    let mut expected_graphql_str = fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/input_type/compound_where_unique_input.graphql"
+    ))
+    .unwrap();
+    expected_graphql_str.retain(|c| !c.is_whitespace());
+    let sdml_str = fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/input_type/compound_where_unique_input.sdml"
+    ))
+    .unwrap();
+    let data_model = sdml_parser::parse(&sdml_str)
+      .expect("A valid SDML file shouldn't fail in parsing.");
+    let mut where_unique_graphql = ["User", "Membership"]
+      .into_iter()
+      .map(|model_name| {
+        let model = data_model
+          .models()
+          .get(model_name)
+          .expect("Model should exist in the SDML.");
+        let mut type_defs = vec![where_unique_unique_input_def(model)
+          .expect("It should return WhereUniqueInput")];
+        type_defs.extend(
+          where_unique_compound_input_defs(model)
+            .expect("It should return CompoundUniqueInputs"),
+        );
+        type_defs
+          .iter()
+          .map(ToString::to_string)
+          .collect::<String>()
+      })
+      .collect::<String>();
+    where_unique_graphql.retain(|c| !c.is_whitespace());
+    assert_eq!(expected_graphql_str, where_unique_graphql)
+  }
 }
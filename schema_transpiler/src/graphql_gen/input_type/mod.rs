@@ -4,3 +4,15 @@ pub mod create;
 pub mod filter;
 pub mod order_by_input;
 pub mod update;
+
+/// Non-unique scalar fields of the model, which are inputted by the user.
+/// **Note:** `@updatedAt` fields are filtered out, storage assigns them on every mutation.
+fn rest_input_fields<'a>(
+  model_fields: &'a sdml_ast::ModelFields,
+) -> Vec<&'a sdml_ast::FieldDecl> {
+  model_fields
+    .get_rest(sdml_ast::ModelIndexedFieldsFilter::All)
+    .into_iter()
+    .filter(|field| !field.has_updated_at_attrib())
+    .collect()
+}
@@ -31,14 +31,13 @@ pub fn update_input_types_def(
 /// the *complete data* to update a single user object including contained relations.
 fn update_input_def(model: &sdml_ast::ModelDecl) -> GraphQLGenResult<TypeDefinition> {
   let model_fields = model.get_fields();
-  // Note: Filter out relation_scalar fields & ids.
+  // Note: Filter out relation_scalar fields, ids & @updatedAt fields.
   // Because they are not updatable directly.
   // But UpdateInput can be used to update unique fields.
   // [see] update_many_input_def() where unique fields are filtered out
   let mut non_relation_fields = Vec::new();
   non_relation_fields.extend(&model_fields.unique);
-  non_relation_fields
-    .extend(model_fields.get_rest(sdml_ast::ModelIndexedFieldsFilter::All));
+  non_relation_fields.extend(rest_input_fields(&model_fields));
 
   let mut input_field_defs = non_relation_fields
     .into_iter()
@@ -102,10 +101,7 @@ fn upsert_input_def(model_name: &sdml_ast::Token) -> GraphQLGenResult<TypeDefini
 /// relevant for it.
 pub fn has_update_many_input(model: &ModelDecl) -> bool {
   let fields = model.get_fields();
-  fields
-    .get_rest(sdml_ast::ModelIndexedFieldsFilter::All)
-    .len()
-    > 0
+  !rest_input_fields(&fields).is_empty()
 }
 
 /// Code-gen the input type used to update many objects in one go..
@@ -120,11 +116,10 @@ fn update_many_input_def(
 ) -> GraphQLGenResult<Option<TypeDefinition>> {
   let model_fields = model.get_fields();
 
-  // Note: Filter out relation_scalar fields & ids.
+  // Note: Filter out relation_scalar fields, ids & @updatedAt fields.
   // and [important] also filter out unique fields.
   // Because they are not updatable directly in UpdateManyInput.
-  let non_unique_field_defs = model_fields
-    .get_rest(sdml_ast::ModelIndexedFieldsFilter::All)
+  let non_unique_field_defs = rest_input_fields(&model_fields)
     .into_iter()
     .map(non_relation_field_input_def)
     .collect::<GraphQLGenResult<Vec<InputValueDefinition>>>()?;
@@ -413,6 +408,32 @@ fn connect_input_def(model_name: &sdml_ast::Token) -> GraphQLGenResult<TypeDefin
   })
 }
 
+/// Code-gen the input type used to update the embedded value of the composite type,
+/// only the given fields are updated.
+/// Ex. AddressUpdateInput is used inside UserUpdateInput to update the user's address.
+pub fn composite_update_input_def(
+  r#type: &sdml_ast::TypeDecl,
+) -> GraphQLGenResult<TypeDefinition> {
+  let input_field_defs = r#type
+    .fields
+    .iter()
+    .map(non_relation_field_input_def)
+    .collect::<GraphQLGenResult<Vec<InputValueDefinition>>>()?;
+  let type_name = r#type
+    .name
+    .try_get_ident_name()
+    .map_err(ErrorGraphQLGen::new_sdml_error)?;
+  Ok(TypeDefinition {
+    extend: false,
+    description: None,
+    name: open_crud_name::types::UpdateInput::Update.name(type_name),
+    directives: vec![],
+    kind: TypeKind::InputObject(InputObjectType {
+      fields: input_field_defs,
+    }),
+  })
+}
+
 pub fn connect_position_input_def() -> GraphQLGenResult<TypeDefinition> {
   let fields = vec![
     // after
@@ -477,6 +498,19 @@ fn non_relation_field_input_def(
         .map_err(ErrorGraphQLGen::new_sdml_error)?
         .to_string(),
     ),
+    sdml_ast::Type::Composite { composite_ty_name } => {
+      let composite_ty_name = composite_ty_name
+        .try_get_ident_name()
+        .map_err(ErrorGraphQLGen::new_sdml_error)?;
+      // Note: The single embedded value is merged with the given fields,
+      // but the list of embedded values is replaced as a whole.
+      if field.field_type.is_array() {
+        Ok(open_crud_name::types::CreateInput::Create.name(composite_ty_name))
+      } else {
+        Ok(open_crud_name::types::UpdateInput::Update.name(composite_ty_name))
+      }
+      .map(|name| name.to_string())
+    }
   }?;
   // Note: All the input args for update are optional!!
   let type_mod: TypeMod = match field.field_type.type_mod {
@@ -591,8 +625,8 @@ mod tests {
       .expect("A valid SDML file shouldn't fail in parsing.");
 
     let update_input_types_graphql_ast = data_model
-      .models_sorted()
-      .iter()
+      .models()
+      .values()
       .flat_map(|model| {
         update_input_types_def(model).expect("update_input_types_def should succeed!")
       })
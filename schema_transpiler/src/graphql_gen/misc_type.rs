@@ -15,6 +15,72 @@ pub fn scalar_date_time_def() -> TypeDefinition {
     }
 }
 
+/// Long scalar definition.
+pub fn scalar_long_def() -> TypeDefinition {
+  TypeDefinition {
+    extend: false,
+    description: Some("A 64-bit signed integer, such as 1717171717171. It is inputted either as a number or as a numeric string, and returned as a string.".to_string()),
+    name: Name::new(FIELD_TYPE_SCALAR_LONG),
+    directives: vec![],
+    kind: TypeKind::Scalar,
+  }
+}
+
+/// Decimal scalar definition.
+pub fn scalar_decimal_def() -> TypeDefinition {
+  TypeDefinition {
+    extend: false,
+    description: Some("An exact decimal number, such as 1234.56. It is inputted either as a string or as a number, and returned as a string.".to_string()),
+    name: Name::new(FIELD_TYPE_SCALAR_DECIMAL),
+    directives: vec![],
+    kind: TypeKind::Scalar,
+  }
+}
+
+/// Json scalar definition.
+pub fn scalar_json_def() -> TypeDefinition {
+  TypeDefinition {
+    extend: false,
+    description: Some("A schemaless JSON value, such as an object, a list, a string, a number or a boolean.".to_string()),
+    name: Name::new(FIELD_TYPE_SCALAR_JSON),
+    directives: vec![],
+    kind: TypeKind::Scalar,
+  }
+}
+
+/// Bytes scalar definition.
+pub fn scalar_bytes_def() -> TypeDefinition {
+  TypeDefinition {
+    extend: false,
+    description: Some("An opaque binary blob, inputted & outputted as a list of bytes, such as [104, 105].".to_string()),
+    name: Name::new(FIELD_TYPE_SCALAR_BYTES),
+    directives: vec![],
+    kind: TypeKind::Scalar,
+  }
+}
+
+/// Date scalar definition.
+pub fn scalar_date_def() -> TypeDefinition {
+  TypeDefinition {
+    extend: false,
+    description: Some("A calendar date string without the time, such as 2007-12-03, compliant with the full-date format outlined in section 5.6 of the RFC 3339.".to_string()),
+    name: Name::new(FIELD_TYPE_SCALAR_DATE),
+    directives: vec![],
+    kind: TypeKind::Scalar,
+  }
+}
+
+/// UUID scalar definition.
+pub fn scalar_uuid_def() -> TypeDefinition {
+  TypeDefinition {
+    extend: false,
+    description: Some("A UUID string in the hyphenated format, such as 67e55044-10b1-426f-9247-bb680e5fe0c8.".to_string()),
+    name: Name::new(FIELD_TYPE_SCALAR_UUID),
+    directives: vec![],
+    kind: TypeKind::Scalar,
+  }
+}
+
 /// @map directive definition.
 pub fn directive_map_def() -> DirectiveDefinition {
   DirectiveDefinition {
@@ -92,7 +158,22 @@ mod tests {
 scalar DateTime
 "#;
     let date_time_scalar = scalar_date_time_def();
-    assert_eq!(expected_graph_ql, date_time_scalar.to_string())
+    assert_eq!(expected_graph_ql, date_time_scalar.to_string());
+
+    let expected_graph_ql = r#"
+"""A calendar date string without the time, such as 2007-12-03, compliant with the full-date format outlined in section 5.6 of the RFC 3339."""
+scalar Date
+"#;
+    assert_eq!(expected_graph_ql, scalar_date_def().to_string());
+    let scalar_names = [
+      scalar_long_def(),
+      scalar_decimal_def(),
+      scalar_json_def(),
+      scalar_bytes_def(),
+      scalar_uuid_def(),
+    ]
+    .map(|scalar| scalar.name.to_string());
+    assert_eq!(scalar_names, ["Long", "Decimal", "Json", "Bytes", "UUID"]);
   }
 
   #[test]
@@ -12,3 +12,9 @@ pub const FIELD_TYPE_NAME_INT: &str = "Int";
 pub const FIELD_TYPE_NAME_BOOL: &str = "Boolean";
 pub const FIELD_TYPE_NAME_FLOAT: &str = "Float";
 pub const FIELD_TYPE_SCALAR_DATETIME: &str = "DateTime";
+pub const FIELD_TYPE_SCALAR_LONG: &str = "Long";
+pub const FIELD_TYPE_SCALAR_DECIMAL: &str = "Decimal";
+pub const FIELD_TYPE_SCALAR_JSON: &str = "Json";
+pub const FIELD_TYPE_SCALAR_BYTES: &str = "Bytes";
+pub const FIELD_TYPE_SCALAR_DATE: &str = "Date";
+pub const FIELD_TYPE_SCALAR_UUID: &str = "UUID";
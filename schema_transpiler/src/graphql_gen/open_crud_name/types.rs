@@ -295,6 +295,9 @@ pub enum FilterInput {
   Where,
   /// Idenifies the where critrial where it can match at most one object.
   WhereUnique,
+  /// Identifies the filter on the value found at a path inside a `Json` field.
+  /// It is common for all the models.
+  JsonPath,
 }
 
 impl NamedUnformatted for FilterInput {
@@ -304,6 +307,16 @@ impl NamedUnformatted for FilterInput {
       FilterInput::WhereUnique => {
         format!("{model_name_pc}WhereUniqueInput")
       }
+      FilterInput::JsonPath => panic!(
+        "JsonPathFilterInput is not specific to model, it is common for all models."
+      ),
+    }
+  }
+
+  fn common_name_str(&self) -> String {
+    match self {
+      Self::JsonPath => "JsonPathFilterInput".to_string(),
+      _ => panic!("Common name for this abstraction is not available. This abstraction should be used in-conext of a specific model."),
     }
   }
 }
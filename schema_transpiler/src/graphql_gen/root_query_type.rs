@@ -118,7 +118,8 @@ mod test {
 
     let sdml_ast =
       sdml_parser::parse(&sdml_str).expect("Semantic analysis should succeed!");
-    let root_query_type = super::root_query_type_def(&sdml_ast.models_sorted()).unwrap();
+    let models = sdml_ast.models().values().collect::<Vec<_>>();
+    let root_query_type = super::root_query_type_def(&models).unwrap();
     let mut actual_graphql_str = root_query_type.to_string();
     actual_graphql_str.retain(|c| !c.is_whitespace());
     assert_eq!(expected_graphql_str, actual_graphql_str);
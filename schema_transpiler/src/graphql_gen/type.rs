@@ -17,14 +17,33 @@ fn type_def(model: &sdml_ast::ModelDecl) -> GraphQLGenResult<TypeDefinition> {
     .name
     .try_get_ident_name()
     .map_err(ErrorGraphQLGen::new_sdml_error)?;
-  let fields = model.fields.iter().try_fold(Vec::new(), |mut acc, fld| {
+  let mut fields = vec![];
+  // Note: Model with compound ID gets the `id` field, which identifies its objects.
+  if model.compound_id().is_some() {
+    fields.push(FieldDefinition {
+      description: None,
+      name: open_crud_name::fields::Field::Id.common_name(),
+      arguments: vec![],
+      ty: open_crud_name::types::OpenCRUDType::IdType.common_ty(TypeMod::NonOptional),
+      directives: vec![ConstDirective {
+        name: Name::new("unique"),
+        arguments: vec![],
+      }],
+    });
+  }
+  let fields = model.fields.iter().try_fold(fields, |mut acc, fld| {
     acc.extend(field_def(fld)?);
     Ok(acc)
   })?;
 
   Ok(TypeDefinition {
     extend: false,
-    description: Some(model_name.to_string()),
+    description: Some(
+      model
+        .docs
+        .as_ref()
+        .map_or(model_name.to_string(), ToString::to_string),
+    ),
     name: Name::new(model_name),
     directives: vec![],
     kind: TypeKind::Object(ObjectType {
@@ -34,6 +53,37 @@ fn type_def(model: &sdml_ast::ModelDecl) -> GraphQLGenResult<TypeDefinition> {
   })
 }
 
+/// Code-gen GraphQL object type for the given composite type, its values are embedded
+/// in the objects. Hence it doesn't implement the `Node` interface.
+pub fn composite_type_def(
+  r#type: &sdml_ast::TypeDecl,
+) -> GraphQLGenResult<TypeDefinition> {
+  let type_name = r#type
+    .name
+    .try_get_ident_name()
+    .map_err(ErrorGraphQLGen::new_sdml_error)?;
+  let fields = r#type
+    .fields
+    .iter()
+    .map(non_relation_field_def)
+    .collect::<GraphQLGenResult<Vec<FieldDefinition>>>()?;
+  Ok(TypeDefinition {
+    extend: false,
+    description: Some(
+      r#type
+        .docs
+        .as_ref()
+        .map_or(type_name.to_string(), ToString::to_string),
+    ),
+    name: Name::new(type_name),
+    directives: vec![],
+    kind: TypeKind::Object(ObjectType {
+      implements: vec![],
+      fields,
+    }),
+  })
+}
+
 #[inline(always)]
 fn field_def(field: &sdml_ast::FieldDecl) -> GraphQLGenResult<Vec<FieldDefinition>> {
   match &*field.field_type.r#type() {
@@ -73,8 +123,15 @@ fn non_relation_field_def(
         .expect("Enum name should be a valid identifier")
         .to_string(),
     ),
+    sdml_ast::Type::Composite { composite_ty_name } => Ok(
+      composite_ty_name
+        .ident_name()
+        .expect("Composite type name should be a valid identifier")
+        .to_string(),
+    ),
     _ => Err(ErrorGraphQLGen::SDMLError {
-      error: "Non-relational field should be either primitive type or enum".to_string(),
+      error: "Non-relational field should be either primitive, enum or composite type"
+        .to_string(),
       pos: field.name.span(),
     }),
   }?;
@@ -111,7 +168,7 @@ fn non_relation_field_def(
   }
 
   Ok(FieldDefinition {
-    description: None,
+    description: field.docs.as_ref().map(ToString::to_string),
     name: Name::new(field_name),
     arguments: vec![],
     ty: Type::new(&ty_name_str, field.field_type.type_mod.into()),
@@ -201,14 +258,14 @@ fn relation_field_def(
   if field.field_type.is_array() {
     Ok(vec![
       FieldDefinition {
-        description: None,
+        description: field.docs.as_ref().map(ToString::to_string),
         name: Name::new(field_name),
         arguments: array_field_args(referenced_model_name)?,
         ty: field_type,
         directives: vec![],
       },
       FieldDefinition {
-        description: None,
+        description: field.docs.as_ref().map(ToString::to_string),
         // Note: Here the field_name is called <field_name>Connection,
         // instead of using open_crud::QueryField::Connection.named(model_name).
         // This is because, model.field_name from sdml file should be the name of the field in GraphQL.
@@ -221,7 +278,7 @@ fn relation_field_def(
     ])
   } else {
     Ok(vec![FieldDefinition {
-      description: None,
+      description: field.docs.as_ref().map(ToString::to_string),
       name: Name::new(field_name),
       arguments: vec![],
       ty: field_type,
@@ -47,4 +47,41 @@ mod tests {
     actual_crud_api_graphql_str.retain(|c| !c.is_whitespace());
     assert_eq!(expected_graphql_str, actual_crud_api_graphql_str)
   }
+
+  #[test]
+  fn test_generate_crud_api_def_implicit_many_to_many() {
+    let crud_api = |file_name: &str| {
+      let sdml_str = fs::read_to_string(format!(
+        "{}/test_data/{file_name}",
+        env!("CARGO_MANIFEST_DIR")
+      ))
+      .unwrap();
+      generate_crud_api(&sdml_parser::parse(&sdml_str).unwrap()).unwrap()
+    };
+    let input_def = |crud_api: &str, input_name: &str| {
+      let start = crud_api
+        .find(&format!("input {input_name} {{"))
+        .unwrap_or_else(|| panic!("{input_name} should be generated"));
+      let end = start + crud_api[start..].find('}').unwrap();
+      crud_api[start..=end].to_string()
+    };
+
+    let explicit_crud_api = crud_api("test_generate_crud_api_def.sdml");
+    let implicit_crud_api =
+      crud_api("test_generate_crud_api_def_implicit_many_to_many.sdml");
+    // Relation inputs (connect, disconnect, set etc.) shouldn't change.
+    for input_name in [
+      "PostCreateManyInlineInput",
+      "PostUpdateManyInlineInput",
+      "PostConnectInput",
+      "CategoryCreateManyInlineInput",
+      "CategoryUpdateManyInlineInput",
+      "CategoryConnectInput",
+    ] {
+      assert_eq!(
+        input_def(&explicit_crud_api, input_name),
+        input_def(&implicit_crud_api, input_name)
+      );
+    }
+  }
 }
@@ -0,0 +1,218 @@
+//! Renders the SDML errors as diagnostics, pointing at the offending source.
+//!
+//! Diagnostics are either rendered in the style of the rustc diagnostics, Ex.
+//!
+//! ```text
+//! error[ModelIdFieldMissing]: Model `User` has no ID field
+//!  --> schema.sdml:5:7
+//!   |
+//! 5 | model User {
+//!   |       ^^^^
+//!   = hint: mark a field with `@id` attribute, or list the ID fields in `@@id([..])`
+//! ```
+//!
+//! or serialized as JSON for the editor integrations.
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::Error;
+
+/// Position in the source, both line & column are 1 based.
+/// Note: Column counts the characters, not the bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Position {
+  pub line: usize,
+  pub column: usize,
+}
+
+/// Diagnostic of an SDML error, with the positions resolved in the source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+  /// Stable code of the error, Ex. `ModelIdFieldMissing`.
+  pub code: &'static str,
+  pub message: String,
+  pub hint: Option<String>,
+  /// Position of the first character of the span.
+  pub start: Position,
+  /// Position just past the last character of the span.
+  pub end: Position,
+  /// Byte offsets of the span in the source.
+  pub offset: (usize, usize),
+}
+
+impl Diagnostic {
+  /// Diagnostic of the error found in the given source.
+  pub fn new(src: &str, error: &Error) -> Self {
+    let span = error.span();
+    let start = span.start.min(src.len());
+    let end = span.end.clamp(start, src.len());
+    Diagnostic {
+      code: error.code(),
+      message: error.message(),
+      hint: error.hint(),
+      start: position(src, start),
+      end: position(src, end),
+      offset: (start, end),
+    }
+  }
+
+  /// Renders the diagnostic in the style of the rustc diagnostics.
+  /// `path` is the name of the source shown in the location line.
+  pub fn render(&self, src: &str, path: &str) -> String {
+    let line_no = self.start.line.to_string();
+    let gutter = " ".repeat(line_no.len());
+    let line = src.lines().nth(self.start.line - 1).unwrap_or_default();
+    // Note: Span running past the line is underlined till the end of the line.
+    let line_len = line.chars().count();
+    let end_column = if self.end.line == self.start.line {
+      self.end.column
+    } else {
+      line_len + 1
+    };
+    let carets = end_column.saturating_sub(self.start.column).max(1);
+
+    let mut rendered = String::new();
+    let _ = writeln!(rendered, "error[{}]: {}", self.code, self.message);
+    let _ = writeln!(
+      rendered,
+      "{gutter}--> {path}:{}:{}",
+      self.start.line, self.start.column
+    );
+    let _ = writeln!(rendered, "{gutter} |");
+    let _ = writeln!(rendered, "{line_no} | {line}");
+    let _ = writeln!(
+      rendered,
+      "{gutter} | {}{}",
+      " ".repeat(self.start.column - 1),
+      "^".repeat(carets)
+    );
+    if let Some(hint) = &self.hint {
+      let _ = writeln!(rendered, "{gutter} = hint: {hint}");
+    }
+    rendered
+  }
+}
+
+/// Diagnostics of the errors found in the given source.
+pub fn diagnostics(src: &str, errors: &[Error]) -> Vec<Diagnostic> {
+  errors
+    .iter()
+    .map(|error| Diagnostic::new(src, error))
+    .collect()
+}
+
+/// Renders the errors found in the given source, in the style of the rustc diagnostics.
+pub fn render(src: &str, path: &str, errors: &[Error]) -> String {
+  diagnostics(src, errors)
+    .iter()
+    .map(|diagnostic| diagnostic.render(src, path))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Serializes the diagnostics of the errors found in the given source, as a JSON array.
+pub fn to_json(src: &str, errors: &[Error]) -> String {
+  serde_json::to_string_pretty(&diagnostics(src, errors))
+    .expect("Diagnostics should serialize to JSON.")
+}
+
+/// Position of the byte offset in the source.
+fn position(src: &str, offset: usize) -> Position {
+  // Note: Offset within a multi-byte character points at the character.
+  let offset = (0..=offset)
+    .rev()
+    .find(|offset| src.is_char_boundary(*offset))
+    .unwrap_or_default();
+  let before = &src[..offset];
+  let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+  Position {
+    line: before.matches('\n').count() + 1,
+    column: before[line_start..].chars().count() + 1,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::parse;
+
+  const SRC: &str = r#"
+config db {
+  provider = "foundationDB"
+}
+
+model User {
+  email ShortStr @id
+  role  Rol
+}
+"#;
+
+  #[test]
+  fn test_position() {
+    let src = "ab\ncdé\nf";
+    assert_eq!(position(src, 0), Position { line: 1, column: 1 });
+    assert_eq!(position(src, 3), Position { line: 2, column: 1 });
+    assert_eq!(position(src, 7), Position { line: 2, column: 4 });
+    assert_eq!(position(src, 8), Position { line: 3, column: 1 });
+  }
+
+  #[test]
+  fn test_render() {
+    let errors = parse(SRC).expect_err("Role type is undefined.");
+    let expected = r#"error[TypeUndefined]: Type `Rol` of the field `User.role` is undefined
+ --> schema.sdml:8:9
+  |
+8 |   role  Rol
+  |         ^^^
+  = hint: use a primitive type, or define a model or an enum of this name
+"#;
+    assert_eq!(expected, render(SRC, "schema.sdml", &errors));
+  }
+
+  #[test]
+  fn test_render_parser_errors() {
+    let src = r#"
+model User {
+  email ShortStr @id
+  age   Int64    @default(99999999999999999999)
+}
+
+model Post {
+  title ShortStr @id
+  %
+}
+"#;
+    let errors = parse(src).expect_err("Age & post are invalid.");
+    let expected = r#"error[ParserError]: Integer is out of the 64 bit range
+ --> schema.sdml:4:27
+  |
+4 |   age   Int64    @default(99999999999999999999)
+  |                           ^^^^^^^^^^^^^^^^^^^^
+
+error[ParserError]: found % expected '}'
+ --> schema.sdml:9:3
+  |
+9 |   %
+  |   ^
+"#;
+    assert_eq!(expected, render(src, "schema.sdml", &errors));
+  }
+
+  #[test]
+  fn test_to_json() {
+    let errors = parse(SRC).expect_err("Role type is undefined.");
+    let json: serde_json::Value = serde_json::from_str(&to_json(SRC, &errors)).unwrap();
+    assert_eq!(
+      json,
+      serde_json::json!([{
+        "code": "TypeUndefined",
+        "message": "Type `Rol` of the field `User.role` is undefined",
+        "hint": "use a primitive type, or define a model or an enum of this name",
+        "start": {"line": 8, "column": 9},
+        "end": {"line": 8, "column": 12},
+        "offset": [86, 89],
+      }])
+    );
+  }
+}
@@ -0,0 +1,701 @@
+//! Formats the SDML declarations back into the SDML text, in the canonical style.
+//!
+//! * Declarations are separated by a blank line, their bodies are indented by 4 spaces.
+//! * Field names, types & attributes of a model (or a composite type) are aligned into
+//!   columns, so are the names of the config pairs.
+//! * Model attributes (`@@id`, `@@unique` & `@@index`) follow the fields, after a
+//!   blank line.
+//! * Attribute arguments are spaced as `@relation(name: "X", field: y)`.
+//! * Literals are kept as written in the source, Ex. `1e-3` & `0x2A`.
+//!
+//! Formatting the source ([`format`]) keeps the comments & a single blank line where
+//! the source has one or more. Parsing the formatted output gives an equal AST.
+use chumsky::Parser;
+
+use crate::{
+  parser,
+  types::{
+    AttribArg, Attribute, ConfigDecl, ConfigValue, DataModel, Declaration, EnumDecl,
+    FieldDecl, FieldTypeMod, ModelAttribute, ModelDecl, Str, Token, Type, TypeDecl,
+  },
+  Error,
+};
+
+/// Indentation of the declaration's body.
+const INDENT: &str = "    ";
+
+/// Formats the SDML source, keeping its comments.
+/// Note: Only the syntax is checked, semantic errors don't stop the formatting.
+pub fn format(src: &str) -> Result<String, Vec<Error>> {
+  let decls = parser::delcarations()
+    .parse(src)
+    .into_result()
+    .map_err(|errors| errors.into_iter().map(Error::from).collect::<Vec<_>>())?;
+  let mut formatter = Formatter::new(Some(Source::new(src)));
+  formatter.declarations(decls.iter());
+  Ok(formatter.finish())
+}
+
+/// Formats the declarations, in the given order.
+pub fn format_declarations(decls: &[Declaration]) -> String {
+  let mut formatter = Formatter::new(None);
+  formatter.declarations(decls.iter());
+  formatter.finish()
+}
+
+/// Formats the data model, its declarations are in the source order.
+pub fn format_data_model(data_model: &DataModel) -> String {
+  let mut formatter = Formatter::new(None);
+  formatter.declarations(data_model.declarations().iter());
+  formatter.finish()
+}
+
+/// Line comment (`#` or `//`) found in the source.
+struct Comment {
+  /// Source line (0 based) of the comment.
+  line: usize,
+  text: String,
+}
+
+/// Source being formatted, to place its comments & blank lines.
+struct Source<'src> {
+  text: &'src str,
+  /// Byte offset of the start of each line.
+  line_starts: Vec<usize>,
+  /// Comments yet to be written, in the source order.
+  comments: Vec<Comment>,
+  /// Byte offsets of the braces (`{` & `}`), outside of the strings & comments.
+  braces: Vec<(usize, char)>,
+}
+
+impl<'src> Source<'src> {
+  fn new(text: &'src str) -> Self {
+    let line_starts = std::iter::once(0)
+      .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+      .collect::<Vec<_>>();
+    let mut source = Source {
+      text,
+      line_starts,
+      comments: Vec::new(),
+      braces: Vec::new(),
+    };
+    source.scan();
+    source
+  }
+
+  /// Finds the comments & the braces in the source.
+  fn scan(&mut self) {
+    let mut chars = self.text.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+      match c {
+        '"' => {
+          while let Some((_, c)) = chars.next() {
+            match c {
+              '\\' => {
+                chars.next();
+              }
+              '"' => break,
+              _ => {}
+            }
+          }
+        }
+        '{' | '}' => self.braces.push((offset, c)),
+        '#' | '/' if c == '#' || self.text[offset..].starts_with("//") => {
+          let end = self.text[offset..]
+            .find('\n')
+            .map_or(self.text.len(), |end| offset + end);
+          let text = self.text[offset..end].trim_end();
+          // Note: Doc comments (`///` but not `////`) are part of the declarations.
+          let is_doc = text.starts_with("///") && !text.starts_with("////");
+          if !is_doc {
+            self.comments.push(Comment {
+              line: self.line(offset),
+              text: text.to_string(),
+            });
+          }
+          while chars.next_if(|(offset, _)| *offset < end).is_some() {}
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn line(&self, offset: usize) -> usize {
+    self.line_starts.partition_point(|start| *start <= offset) - 1
+  }
+
+  /// Source line of the code starting at the offset.
+  /// Note: Spans may start with the whitespaces & the comments before the code.
+  fn code_line(&self, offset: usize) -> usize {
+    let mut rest = &self.text[offset..];
+    loop {
+      rest = rest.trim_start();
+      let is_comment = rest.starts_with('#')
+        || (rest.starts_with("//")
+          && (!rest.starts_with("///") || rest.starts_with("////")));
+      if !is_comment {
+        break;
+      }
+      rest = rest.find('\n').map_or("", |end| &rest[end..]);
+    }
+    self.line(self.text.len() - rest.len())
+  }
+
+  /// Source line of the first brace found at or after the offset.
+  fn brace_line(&self, brace: char, offset: usize) -> Option<usize> {
+    self
+      .braces
+      .iter()
+      .find(|(brace_offset, c)| *c == brace && *brace_offset >= offset)
+      .map(|(brace_offset, _)| self.line(*brace_offset))
+  }
+
+  /// Is there a blank line in between the source lines ?
+  fn has_blank_line(&self, from_line: usize, to_line: usize) -> bool {
+    ((from_line + 1)..to_line).any(|line| {
+      let start = self.line_starts[line];
+      let end = self
+        .line_starts
+        .get(line + 1)
+        .copied()
+        .unwrap_or(self.text.len());
+      self.text[start..end].trim().is_empty()
+    })
+  }
+
+  /// Is the comment the only thing on its line ?
+  fn is_own_line(&self, comment: &Comment) -> bool {
+    let start = self.line_starts[comment.line];
+    self.text[start..]
+      .trim_start()
+      .starts_with(comment.text.as_str())
+  }
+}
+
+/// Line of a declaration's body.
+struct BodyLine<'a> {
+  /// Byte offset in the source, where the line starts.
+  offset: usize,
+  docs: Option<&'a Str>,
+  code: String,
+}
+
+struct Formatter<'src> {
+  out: String,
+  source: Option<Source<'src>>,
+  /// Source line last written, `None` if a blank line shouldn't be kept before
+  /// the next line. Ex. Right after the opening brace.
+  last_line: Option<usize>,
+}
+
+impl<'src> Formatter<'src> {
+  fn new(source: Option<Source<'src>>) -> Self {
+    Formatter {
+      out: String::new(),
+      source,
+      last_line: None,
+    }
+  }
+
+  fn finish(mut self) -> String {
+    // Note: Comments after the last declaration.
+    self.leading_comments(usize::MAX, "");
+    let formatted = self.out.trim_end();
+    if formatted.is_empty() {
+      String::new()
+    } else {
+      format!("{formatted}\n")
+    }
+  }
+
+  fn declarations<'a>(&mut self, decls: impl Iterator<Item = &'a Declaration>) {
+    for (index, decl) in decls.enumerate() {
+      if index > 0 {
+        self.out.push('\n');
+        self.last_line = None;
+      }
+      match decl {
+        Declaration::Config(config) => self.config(config),
+        Declaration::Enum(r#enum) => self.r#enum(r#enum),
+        Declaration::Type(r#type) => self.r#type(r#type),
+        Declaration::Model(model) => self.model(model),
+      }
+    }
+  }
+
+  fn config(&mut self, config: &ConfigDecl) {
+    let name_width = config
+      .config_pairs
+      .iter()
+      .map(|pair| token(&pair.name).len())
+      .max()
+      .unwrap_or_default();
+    let lines = config
+      .config_pairs
+      .iter()
+      .map(|pair| BodyLine {
+        offset: pair.name.span().start,
+        docs: None,
+        code: format!(
+          "{:name_width$} = {}",
+          token(&pair.name),
+          config_value(&pair.value, self.text())
+        ),
+      })
+      .collect();
+    self.block(
+      None,
+      format!("config {}", token(&config.name)),
+      config.name.span().start,
+      vec![lines],
+    );
+  }
+
+  fn r#enum(&mut self, r#enum: &EnumDecl) {
+    let lines = r#enum
+      .elements
+      .iter()
+      .map(|element| BodyLine {
+        offset: element.span().start,
+        docs: None,
+        code: token(element),
+      })
+      .collect();
+    self.block(
+      r#enum.docs.as_ref(),
+      format!("enum {}", token(&r#enum.name)),
+      r#enum.name.span().start,
+      vec![lines],
+    );
+  }
+
+  fn r#type(&mut self, r#type: &TypeDecl) {
+    self.block(
+      r#type.docs.as_ref(),
+      format!("type {}", token(&r#type.name)),
+      r#type.name.span().start,
+      vec![field_lines(&r#type.fields, self.text())],
+    );
+  }
+
+  fn model(&mut self, model: &ModelDecl) {
+    let fields = field_lines(&model.fields, self.text());
+    let attributes = model
+      .attributes
+      .iter()
+      .map(|attribute| BodyLine {
+        offset: attribute.name.span().start,
+        docs: None,
+        code: model_attribute(attribute),
+      })
+      .collect::<Vec<_>>();
+    self.block(
+      model.docs.as_ref(),
+      format!("model {}", token(&model.name)),
+      model.name.span().start,
+      vec![fields, attributes],
+    );
+  }
+
+  /// Writes the declaration's block, its sections (Ex. fields & model attributes) are
+  /// separated by a blank line.
+  fn block(
+    &mut self,
+    docs: Option<&Str>,
+    header: String,
+    offset: usize,
+    sections: Vec<Vec<BodyLine<'_>>>,
+  ) {
+    let open_line = self.source_line(|source| source.brace_line('{', offset));
+    self.leading_comments(open_line.unwrap_or_default(), "");
+    if let Some(line) = self.source_line(|source| Some(source.code_line(offset))) {
+      self.blank_line(line);
+    }
+    self.docs(docs, "");
+    self.out.push_str(&header);
+    self.out.push_str(" {");
+    self.trailing_comment(open_line);
+    self.out.push('\n');
+    self.last_line = None;
+
+    let mut last_offset = offset;
+    let mut sections = sections.into_iter().filter(|lines| !lines.is_empty());
+    if let Some(lines) = sections.next() {
+      last_offset = last_offset.max(self.body_lines(lines));
+    }
+    for lines in sections {
+      self.out.push('\n');
+      self.last_line = None;
+      last_offset = last_offset.max(self.body_lines(lines));
+    }
+
+    let close_line = self.source_line(|source| source.brace_line('}', last_offset));
+    self.leading_comments(close_line.unwrap_or_default(), INDENT);
+    self.out.push('}');
+    self.trailing_comment(close_line);
+    self.out.push('\n');
+  }
+
+  /// Writes the lines, returns the source offset of the last line.
+  fn body_lines(&mut self, lines: Vec<BodyLine<'_>>) -> usize {
+    let mut last_offset = 0;
+    for line in lines {
+      let source_line = self.source_line(|source| Some(source.code_line(line.offset)));
+      if let Some(source_line) = source_line {
+        self.leading_comments(source_line, INDENT);
+        self.blank_line(source_line);
+      }
+      self.docs(line.docs, INDENT);
+      self.out.push_str(INDENT);
+      self.out.push_str(&line.code);
+      self.trailing_comment(source_line);
+      self.out.push('\n');
+      self.last_line = source_line;
+      last_offset = line.offset;
+    }
+    last_offset
+  }
+
+  /// Text of the source being formatted, if there is one.
+  fn text(&self) -> Option<&'src str> {
+    self.source.as_ref().map(|source| source.text)
+  }
+
+  fn source_line(
+    &self,
+    line: impl FnOnce(&Source<'src>) -> Option<usize>,
+  ) -> Option<usize> {
+    self.source.as_ref().and_then(line)
+  }
+
+  /// Keeps a single blank line, if the source has one before the line.
+  fn blank_line(&mut self, line: usize) {
+    let (Some(source), Some(last_line)) = (&self.source, self.last_line) else {
+      return;
+    };
+    if source.has_blank_line(last_line, line) {
+      self.out.push('\n');
+    }
+  }
+
+  /// Writes the comments found before the source line, each on its own line.
+  fn leading_comments(&mut self, line: usize, indent: &str) {
+    loop {
+      let Some(source) = &mut self.source else {
+        return;
+      };
+      if source
+        .comments
+        .first()
+        .is_none_or(|comment| comment.line >= line)
+      {
+        return;
+      }
+      let comment = source.comments.remove(0);
+      self.blank_line(comment.line);
+      self.out.push_str(indent);
+      self.out.push_str(&comment.text);
+      self.out.push('\n');
+      self.last_line = Some(comment.line);
+    }
+  }
+
+  /// Writes the comment following the code on the source line, if there is one.
+  fn trailing_comment(&mut self, line: Option<usize>) {
+    let (Some(source), Some(line)) = (&mut self.source, line) else {
+      return;
+    };
+    let Some(comment) = source.comments.first() else {
+      return;
+    };
+    if comment.line == line && !source.is_own_line(comment) {
+      let comment = source.comments.remove(0);
+      self.out.push(' ');
+      self.out.push_str(&comment.text);
+    }
+  }
+
+  fn docs(&mut self, docs: Option<&Str>, indent: &str) {
+    for line in docs.iter().flat_map(|docs| docs.lines()) {
+      self.out.push_str(indent);
+      self.out.push_str("///");
+      if !line.is_empty() {
+        self.out.push(' ');
+        self.out.push_str(line);
+      }
+      self.out.push('\n');
+    }
+  }
+}
+
+/// Lines of the fields, their names, types & attributes aligned into columns.
+fn field_lines<'a>(fields: &'a [FieldDecl], src: Option<&str>) -> Vec<BodyLine<'a>> {
+  let columns = fields
+    .iter()
+    .map(|field| {
+      (
+        token(&field.name),
+        field_type(field),
+        attributes(&field.attributes, src),
+      )
+    })
+    .collect::<Vec<_>>();
+  let name_width = columns.iter().map(|(name, ..)| name.len()).max();
+  let type_width = columns.iter().map(|(_, ty, _)| ty.len()).max();
+  fields
+    .iter()
+    .zip(columns)
+    .map(|(field, (name, ty, attributes))| {
+      let name_width = name_width.unwrap_or_default();
+      let type_width = type_width.unwrap_or_default();
+      BodyLine {
+        offset: field.name.span().start,
+        docs: field.docs.as_ref(),
+        code: format!("{name:name_width$} {ty:type_width$} {attributes}")
+          .trim_end()
+          .to_string(),
+      }
+    })
+    .collect()
+}
+
+fn field_type(field: &FieldDecl) -> String {
+  let type_name = match field.field_type.r#type() {
+    Type::Relation(edge) => token(edge.referenced_model_name()),
+    r#type => token(r#type.token()),
+  };
+  match field.field_type.type_mod {
+    FieldTypeMod::Optional => format!("{type_name}?"),
+    FieldTypeMod::Array => format!("{type_name}[]"),
+    FieldTypeMod::NonOptional => type_name,
+  }
+}
+
+fn attributes(attributes: &[Attribute], src: Option<&str>) -> String {
+  attributes
+    .iter()
+    .map(|attribute| {
+      let name = token(&attribute.name);
+      match &attribute.arg {
+        None => format!("@{name}"),
+        Some(AttribArg::Function(function, args)) => {
+          let args = args.iter().map(|arg| lexeme(arg, src)).collect::<Vec<_>>();
+          format!("@{name}({}({}))", token(function), args.join(", "))
+        }
+        Some(AttribArg::Ident(ident)) => format!("@{name}({})", token(ident)),
+        Some(AttribArg::Literal(literal)) => {
+          format!("@{name}({})", lexeme(literal, src))
+        }
+        Some(AttribArg::Args(args)) => {
+          let args = args
+            .iter()
+            .map(|arg| {
+              format!("{}: {}", token(&arg.arg_name), lexeme(&arg.arg_value, src))
+            })
+            .collect::<Vec<_>>();
+          format!("@{name}({})", args.join(", "))
+        }
+      }
+    })
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+fn model_attribute(attribute: &ModelAttribute) -> String {
+  let fields = attribute.fields.iter().map(token).collect::<Vec<_>>();
+  format!("@@{}([{}])", token(&attribute.name), fields.join(", "))
+}
+
+fn config_value(value: &ConfigValue, src: Option<&str>) -> String {
+  let (ConfigValue::String(_, span)
+  | ConfigValue::Int(_, span)
+  | ConfigValue::Float(_, span)
+  | ConfigValue::Bool(_, span)) = value;
+  if let Some(lexeme) = src.and_then(|src| src.get(span.start..span.end)) {
+    return lexeme.to_string();
+  }
+  match value {
+    ConfigValue::String(str, _) => quote(str),
+    ConfigValue::Int(int, _) => int.to_string(),
+    ConfigValue::Float(float, _) => float_str(*float),
+    ConfigValue::Bool(bool, _) => bool.to_string(),
+  }
+}
+
+/// Literal as written in the source, if there is one. Ex. `1e-3` isn't turned into
+/// `0.001`, nor `0x2A` into `42`.
+fn lexeme(literal: &Token, src: Option<&str>) -> String {
+  match literal {
+    Token::String(_, span) | Token::Int(_, span) | Token::Float(_, span) => src
+      .and_then(|src| src.get(span.start..span.end))
+      .map_or_else(|| token(literal), str::to_string),
+    _ => token(literal),
+  }
+}
+
+/// Source text of the token.
+fn token(token: &Token) -> String {
+  match token {
+    Token::Ident(str, _) => str.to_string(),
+    Token::String(str, _) => quote(str),
+    Token::Int(int, _) => int.to_string(),
+    Token::Float(float, _) => float_str(*float),
+    Token::Bool(bool, _) => bool.to_string(),
+  }
+}
+
+/// String literal of the value, quoted & escaped.
+fn quote(str: &str) -> String {
+  let mut quoted = String::with_capacity(str.len() + 2);
+  quoted.push('"');
+  for c in str.chars() {
+    match c {
+      '"' => quoted.push_str("\\\""),
+      '\\' => quoted.push_str("\\\\"),
+      '\n' => quoted.push_str("\\n"),
+      '\r' => quoted.push_str("\\r"),
+      '\t' => quoted.push_str("\\t"),
+      '\x08' => quoted.push_str("\\b"),
+      '\x0C' => quoted.push_str("\\f"),
+      c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+      c => quoted.push(c),
+    }
+  }
+  quoted.push('"');
+  quoted
+}
+
+/// Float with the fractional part, so that it isn't parsed back as an integer.
+fn float_str(float: f64) -> String {
+  let str = float.to_string();
+  if str.contains('.') {
+    str
+  } else {
+    format!("{str}.0")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+
+  use super::*;
+
+  fn declarations(src: &str) -> Vec<Declaration> {
+    parser::delcarations().parse(src).into_result().unwrap()
+  }
+
+  #[test]
+  fn test_format() {
+    let src = r#"
+# Data model of the blog.
+config db {
+  provider="foundationDB" # FDB is the only provider.
+    version = 7
+  title = "The \"blog\"\tC:\\"
+  retries = -0x3
+  ratio = 25e-3
+}
+# Models of the blog.
+
+/// A user of the blog.
+model User {   // Users sign up with the email.
+  userId ShortStr @id @default(auto()) # Generated.
+  // Email is unique.
+  email ShortStr   @unique
+  /// Name shown in the posts.
+  name ShortStr?
+
+
+  posts Post[] @relation(name:"user_posts")
+  role Role @default(USER)
+  @@index([role, name])
+  # End of the users.
+}
+
+model Post {
+  postId ShortStr @id @default(auto())
+  author User @relation(name:"user_posts",field:authorId,references:userId)
+  authorId ShortStr
+}
+enum Role { USER ADMIN }
+// End of the data model.
+"#;
+    let expected = r#"# Data model of the blog.
+config db {
+    provider = "foundationDB" # FDB is the only provider.
+    version  = 7
+    title    = "The \"blog\"\tC:\\"
+    retries  = -0x3
+    ratio    = 25e-3
+}
+
+# Models of the blog.
+
+/// A user of the blog.
+model User { // Users sign up with the email.
+    userId ShortStr  @id @default(auto()) # Generated.
+    // Email is unique.
+    email  ShortStr  @unique
+    /// Name shown in the posts.
+    name   ShortStr?
+
+    posts  Post[]    @relation(name: "user_posts")
+    role   Role      @default(USER)
+
+    @@index([role, name])
+    # End of the users.
+}
+
+model Post {
+    postId   ShortStr @id @default(auto())
+    author   User     @relation(name: "user_posts", field: authorId, references: userId)
+    authorId ShortStr
+}
+
+enum Role {
+    USER
+    ADMIN
+}
+// End of the data model.
+"#;
+    let formatted = format(src).unwrap();
+    assert_eq!(expected, formatted);
+    assert_eq!(formatted, format(&formatted).unwrap());
+    assert_eq!(declarations(src), declarations(&formatted));
+  }
+
+  #[test]
+  fn test_format_sample_model() {
+    let src = fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/sample_model_with_comments.sdml"
+    ))
+    .unwrap();
+    assert_eq!(src, format(&src).unwrap());
+  }
+
+  #[test]
+  fn test_format_round_trip() {
+    for path in [
+      "test_data/sample_model_with_comments.sdml",
+      "test_data/semantic_analysis/model_attribute/model_attribute_valid_usage.sdml",
+      "test_data/semantic_analysis/composite_type/composite_type_valid_usage.sdml",
+      "../schema_transpiler/test_data/test_crud_api_def.sdml",
+      "../schema_transpiler/test_data/test_type_def.sdml",
+    ] {
+      let src =
+        fs::read_to_string(format!("{}/{path}", env!("CARGO_MANIFEST_DIR"))).unwrap();
+      let formatted = format(&src).unwrap();
+      assert_eq!(formatted, format(&formatted).unwrap(), "{path}");
+      assert_eq!(declarations(&src), declarations(&formatted), "{path}");
+      if let Ok(data_model) = crate::parse(&src) {
+        let formatted = format_data_model(&data_model);
+        let reparsed = crate::parse(&formatted).unwrap();
+        assert_eq!(data_model.models, reparsed.models, "{path}");
+        assert_eq!(data_model.types, reparsed.types, "{path}");
+        assert_eq!(formatted, format_data_model(&reparsed), "{path}");
+      }
+    }
+  }
+}
@@ -1,4 +1,7 @@
 mod parser;
 
+pub mod diagnostic;
+pub mod formatter;
 pub mod types;
 pub use parser::parse;
+pub use parser::semantic_analysis::err::Error;
@@ -1,16 +1,25 @@
-use sdml_parser::parse;
+use sdml_parser::{diagnostic, parse};
 
 fn main() {
-  let usage = "Run `sdml_parser <data_model_file.sdml>`";
-  let path = std::env::args().nth(1).expect(usage);
-  let src =
-    std::fs::read_to_string(&path).expect(&format!("File not found at path {path}"));
+  let usage = "Run `sdml_parser [--json] <data_model_file.sdml>`";
+  let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+  let json = args.iter().any(|arg| arg == "--json");
+  args.retain(|arg| arg != "--json");
+  let path = args.first().expect(usage);
+  let src = std::fs::read_to_string(path)
+    .unwrap_or_else(|_| panic!("File not found at path {path}"));
 
   // Parse the source file.
-  let parse_result = parse(&src);
-  if parse_result.is_err() {
-    eprintln!("Parser errors : {:#?}", parse_result);
-  } else {
-    println!("AST: {:#?}", parse_result.unwrap());
+  match parse(&src) {
+    Err(errors) if json => {
+      println!("{}", diagnostic::to_json(&src, &errors));
+      std::process::exit(1);
+    }
+    Err(errors) => {
+      eprintln!("{}", diagnostic::render(&src, path, &errors));
+      std::process::exit(1);
+    }
+    Ok(_) if json => println!("[]"),
+    Ok(data_model) => println!("AST: {data_model:#?}"),
   }
 }
@@ -1,9 +1,7 @@
-use std::ops::Div;
-
 use crate::types::{
   AttribArg, Attribute, ConfigDecl, ConfigPair, DataModel, Declaration, EnumDecl,
-  FieldDecl, FieldType, FieldTypeMod, ModelDecl, NamedArg, PrimitiveType, Str, Token,
-  Type,
+  FieldDecl, FieldType, FieldTypeMod, ModelAttribute, ModelDecl, NamedArg, PrimitiveType,
+  Str, Token, Type, TypeDecl,
 };
 use chumsky::text::{self, ascii};
 use chumsky::{extra::Err, prelude::*};
@@ -19,29 +17,123 @@ pub fn parse(src: &str) -> Result<DataModel, Vec<Error>> {
   semantic_analysis::semantic_update(decls)
 }
 
+/// Declarations of the data model.
+/// Note: Invalid declaration is skipped till the start of the next declaration, so
+/// its innermost error is reported instead of the top level one.
+/// The declaration parsers are boxed to keep the stack frames of the parser small.
 #[inline(always)]
 pub(crate) fn delcarations<'src>(
 ) -> impl Parser<'src, &'src str, Vec<Declaration>, Err<Rich<'src, char>>> {
   config_decl()
-    .or(enum_decl())
-    .or(model_decl())
+    .boxed()
+    .or(enum_decl().boxed())
+    .or(type_decl().boxed())
+    .or(model_decl().boxed())
+    .map(Some)
+    .recover_with(via_parser(skip_declaration().to(None)))
+    .repeated()
+    .collect::<Vec<Option<Declaration>>>()
+    .map(|decls| decls.into_iter().flatten().collect())
+    .then_ignore(ws())
+}
+
+/// Skips the invalid declaration, till the line starting the next declaration.
+/// Note: Only a declaration starting with a keyword (or a doc comment) is skipped, for
+/// anything else the error of the declarations is kept, ex. for a misspelled keyword.
+#[inline(always)]
+fn skip_declaration<'src>(
+) -> impl Parser<'src, &'src str, (), Err<Rich<'src, char>>> + Clone {
+  let decl_start = choice((
+    text::keyword("config").ignored(),
+    text::keyword("enum").ignored(),
+    text::keyword("type").ignored(),
+    text::keyword("model").ignored(),
+    just("//").then(doc_comment_start()).ignored(),
+  ));
+  let next_decl = text::newline()
+    .then(text::inline_whitespace())
+    .then(decl_start.clone());
+  ws()
+    .then(decl_start)
+    .rewind()
+    .then(any().and_is(next_decl.not()).repeated())
+    .ignored()
+}
+
+/// Whitespaces & line comments (`#` or `//`), which are allowed anywhere a whitespace is.
+/// Note: Doc comments (`///`) followed by a name are left to be attached to the
+/// declaration or the field, the other doc comments are consumed as the comments.
+#[inline(always)]
+fn ws<'src>() -> impl Parser<'src, &'src str, (), Err<Rich<'src, char>>> + Clone {
+  let line_comment = just('#')
+    .ignored()
+    .or(just("//").then(doc_comment_start().not()).ignored())
+    .then(any().and_is(text::newline().not()).repeated());
+  let line_ws = text::whitespace()
+    .at_least(1)
+    .ignored()
+    .or(line_comment.clone().ignored())
+    .repeated();
+  let doc_comment = just("//")
+    .then(doc_comment_start())
+    .then(any().and_is(text::newline().not()).repeated());
+  let attached = doc_comment
+    .clone()
+    .then(line_ws.clone())
+    .repeated()
+    .at_least(1)
+    .then(ascii::ident());
+  let unattached = doc_comment.and_is(attached.not());
+  text::whitespace()
+    .at_least(1)
+    .ignored()
+    .or(line_comment.ignored())
+    .or(unattached.ignored())
+    .repeated()
+    .boxed()
+}
+
+/// Start of a doc comment, `///` but not `////`.
+#[inline(always)]
+fn doc_comment_start<'src>(
+) -> impl Parser<'src, &'src str, (), Err<Rich<'src, char>>> + Clone {
+  just('/').then(just('/').not()).ignored()
+}
+
+/// Doc comments (`///`) of a declaration, their lines are joined by new-lines.
+#[inline(always)]
+fn doc_comments<'src>(
+) -> impl Parser<'src, &'src str, Option<Str>, Err<Rich<'src, char>>> + Clone {
+  just("//")
+    .then(doc_comment_start())
+    .ignore_then(any().and_is(text::newline().not()).repeated().to_slice())
+    .padded_by(ws())
     .repeated()
-    .collect::<Vec<Declaration>>()
+    .collect::<Vec<&str>>()
+    .map(|lines| {
+      (!lines.is_empty()).then(|| {
+        let lines = lines
+          .iter()
+          .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+          .collect::<Vec<_>>();
+        Str::new(lines.join("\n"))
+      })
+    })
 }
 
 #[inline(always)]
 fn config_decl<'src>() -> impl Parser<'src, &'src str, Declaration, Err<Rich<'src, char>>>
 {
   text::keyword("config")
-    .padded()
+    .padded_by(ws())
     .then(
       ascii::ident()
         .map_with(|tok, e| Token::Ident(Str::new(tok), e.span()))
-        .padded(),
+        .padded_by(ws()),
     )
     .then(just('{'))
     .then(config_pair().repeated().collect::<Vec<ConfigPair>>())
-    .then(just('}').padded())
+    .then(just('}').padded_by(ws()))
     .map(|((((_, config_name), _), config_pairs), _)| {
       Declaration::Config(ConfigDecl {
         name: config_name,
@@ -53,41 +145,96 @@ fn config_decl<'src>() -> impl Parser<'src, &'src str, Declaration, Err<Rich<'sr
 #[inline(always)]
 fn config_pair<'src>() -> impl Parser<'src, &'src str, ConfigPair, Err<Rich<'src, char>>>
 {
-  ascii::ident()
+  doc_comments()
+    .ignore_then(ascii::ident())
     .map_with(|ident, e| Token::Ident(Str::new(ident), e.span()))
-    .padded()
-    .then(just('=').padded())
-    .then(bool().or(number()).or(string()).padded())
+    .padded_by(ws())
+    .then(just('=').padded_by(ws()))
+    .then(bool().or(number()).or(string()).padded_by(ws()))
     .map(|((key, _), value): ((Token, char), Token)| ConfigPair {
       name: key,
       value: value.try_into().unwrap(),
     })
 }
 
+/// String literal, with the escapes (`\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` &
+/// `\uXXXX`) decoded.
 #[inline(always)]
 fn string<'src>() -> impl Parser<'src, &'src str, Token, Err<Rich<'src, char>>> {
-  just('"')
-    .then(any().filter(|c: &char| *c != '"').repeated())
-    .then(just('"'))
-    .to_slice()
+  let escape = just('\\').ignore_then(choice((
+    just('\\'),
+    just('/'),
+    just('"'),
+    just('b').to('\x08'),
+    just('f').to('\x0C'),
+    just('n').to('\n'),
+    just('r').to('\r'),
+    just('t').to('\t'),
+    just('u').ignore_then(text::digits(16).exactly(4).to_slice().try_map(
+      |digits: &str, span| {
+        u32::from_str_radix(digits, 16)
+          .ok()
+          .and_then(char::from_u32)
+          .ok_or_else(|| Rich::custom(span, "Invalid unicode escape"))
+      },
+    )),
+  )));
+  none_of("\\\"")
+    .or(escape)
+    .repeated()
+    .collect::<String>()
+    .delimited_by(just('"'), just('"'))
     .map_with(|str, e| Token::String(Str::new(str), e.span()))
 }
 
+/// Numeric literal, an integer (ex. `-42`, `0x2A`) or a float with the fraction and / or
+/// the exponent (ex. `-1.5`, `1e-3`).
+/// Note: Out of range number is reported without failing the parser, so the parsing
+/// doesn't backtrack over it & its error isn't replaced by a later one.
 #[inline(always)]
 fn number<'src>() -> impl Parser<'src, &'src str, Token, Err<Rich<'src, char>>> {
-  text::int(10)
-    .then(just('.').then(text::int(10)).or_not())
-    .map_with(|(int_part, fract_part): (&str, Option<(char, &str)>), e| {
-      let i: i64 = int_part.parse().unwrap();
-      if fract_part.is_none() {
-        Token::Int(i, e.span())
+  let sign = one_of("+-").or_not();
+  let hex_int = sign
+    .then_ignore(just("0x").or(just("0X")))
+    .then(text::digits(16).to_slice())
+    .validate(|(sign, digits): (Option<char>, &str), e, emitter| {
+      let sign = sign.map_or(String::new(), String::from);
+      let int =
+        i64::from_str_radix(&format!("{sign}{digits}"), 16).unwrap_or_else(|_| {
+          emitter.emit(Rich::custom(e.span(), "Integer is out of the 64 bit range"));
+          0
+        });
+      Token::Int(int, e.span())
+    });
+  let digits = text::digits(10);
+  let fract_part = just('.').then(digits);
+  let exponent = one_of("eE").then(one_of("+-").or_not()).then(digits);
+  let decimal = sign
+    .then(digits)
+    .then(fract_part.or_not())
+    .then(exponent.or_not())
+    .to_slice()
+    .validate(|number: &str, e, emitter| {
+      let span = e.span();
+      if number.contains(['.', 'e', 'E']) {
+        let float = number
+          .parse::<f64>()
+          .ok()
+          .filter(|float| float.is_finite())
+          .unwrap_or_else(|| {
+            emitter.emit(Rich::custom(span, "Float is out of the 64 bit range"));
+            0.0
+          });
+        Token::Float(float, span)
       } else {
-        let number_of_digits = fract_part.unwrap().1.chars().count() as u32; // IMPORTANT: Note that we are counting characters, not counting grapheme clusters. In this use case it's ok.
-        let thousands = (10 as i64).pow(number_of_digits) as f64;
-        let fract_part = fract_part.unwrap().1.parse::<i64>().unwrap() as f64;
-        Token::Float(i as f64 + fract_part.div(thousands), e.span())
+        let int = number.parse::<i64>().unwrap_or_else(|_| {
+          emitter.emit(Rich::custom(span, "Integer is out of the 64 bit range"));
+          0
+        });
+        Token::Int(int, span)
       }
-    })
+    });
+  hex_int.or(decimal)
 }
 
 #[inline(always)]
@@ -102,55 +249,123 @@ fn bool<'src>() -> impl Parser<'src, &'src str, Token, Err<Rich<'src, char>>> {
 fn enum_decl<'src>() -> impl Parser<'src, &'src str, Declaration, Err<Rich<'src, char>>> {
   let identifier =
     ascii::ident().map_with(|tok, e| Token::Ident(Str::new(tok), e.span()));
-  text::keyword("enum")
-    .padded()
-    .then(identifier.padded())
-    .then(just('{'))
+  doc_comments()
+    .then_ignore(text::keyword("enum").padded_by(ws()))
+    .then(identifier.padded_by(ws()))
     .then(
-      identifier
-        .padded()
+      doc_comments()
+        .ignore_then(identifier.padded_by(ws()))
         .repeated()
         .at_least(1)
-        .collect::<Vec<Token>>(),
-    )
-    .then(just('}').padded())
-    .map(
-      |((((_, enum_name), _open_brace), enum_elements), _close_brace)| {
-        Declaration::Enum(EnumDecl {
-          name: enum_name,
-          elements: enum_elements,
-        })
-      },
+        .collect::<Vec<Token>>()
+        .delimited_by(just('{'), just('}'))
+        // Note: Invalid element is skipped till the closing brace, so its own error
+        // is reported instead of the one at the enum keyword.
+        .recover_with(via_parser(nested_delimiters('{', '}', [], |_| vec![]))),
     )
+    .then_ignore(ws())
+    .map(|((docs, enum_name), enum_elements)| {
+      Declaration::Enum(EnumDecl {
+        docs,
+        name: enum_name,
+        elements: enum_elements,
+      })
+    })
+}
+
+/// Composite type, ex. `type Address { street ShortStr city ShortStr }`.
+#[inline(always)]
+fn type_decl<'src>() -> impl Parser<'src, &'src str, Declaration, Err<Rich<'src, char>>> {
+  doc_comments()
+    .then_ignore(text::keyword("type").padded_by(ws()))
+    .then(ascii::ident().padded_by(ws()))
+    .then(just('{'))
+    .then(field_decl().repeated().collect::<Vec<FieldDecl>>())
+    .then(just('}').padded_by(ws()))
+    .map_with(|((((docs, name), _open_brace), fields), _close_brace), e| {
+      Declaration::Type(TypeDecl {
+        docs,
+        name: Token::Ident(Str::new(name), e.span()),
+        fields,
+      })
+    })
 }
 
 #[inline(always)]
 fn model_decl<'src>() -> impl Parser<'src, &'src str, Declaration, Err<Rich<'src, char>>>
 {
-  text::keyword("model")
-    .padded()
-    .then(ascii::ident().padded())
+  doc_comments()
+    .then_ignore(text::keyword("model").padded_by(ws()))
+    .then(ascii::ident().padded_by(ws()))
     .then(just('{'))
-    .then(field_decl().repeated().collect::<Vec<FieldDecl>>())
-    .then(just('}').padded())
-    .map_with(
-      |((((_model, name), _open_brace), fields), _close_brace), e| {
-        Declaration::Model(ModelDecl {
-          name: Token::Ident(Str::new(name), e.span()),
-          fields,
-        })
-      },
+    .then(
+      field_decl()
+        .map(ModelItem::Field)
+        .or(model_attribute().map(ModelItem::Attribute))
+        .repeated()
+        .collect::<Vec<ModelItem>>(),
+    )
+    .then(just('}').padded_by(ws()))
+    .map_with(|((((docs, name), _open_brace), items), _close_brace), e| {
+      let mut fields = Vec::new();
+      let mut attributes = Vec::new();
+      items.into_iter().for_each(|item| match item {
+        ModelItem::Field(field) => fields.push(field),
+        ModelItem::Attribute(attribute) => attributes.push(attribute),
+      });
+      Declaration::Model(ModelDecl {
+        docs,
+        name: Token::Ident(Str::new(name), e.span()),
+        fields,
+        attributes,
+      })
+    })
+}
+
+/// Item declared in the model's body.
+enum ModelItem {
+  Field(FieldDecl),
+  Attribute(ModelAttribute),
+}
+
+/// Model level attribute, ex. `@@unique([tenantId, email])`.
+#[inline(always)]
+fn model_attribute<'src>(
+) -> impl Parser<'src, &'src str, ModelAttribute, Err<Rich<'src, char>>> {
+  let identifier =
+    ascii::ident().map_with(|tok, e| Token::Ident(Str::new(tok), e.span()));
+  just("@@")
+    .ignore_then(identifier)
+    .then(
+      identifier
+        .padded_by(ws())
+        .separated_by(just(','))
+        .collect::<Vec<Token>>()
+        .delimited_by(just('['), just(']'))
+        .padded_by(ws())
+        .delimited_by(just('('), just(')')),
     )
+    .padded_by(ws())
+    .map(|(name, fields)| ModelAttribute { name, fields })
 }
 
 #[inline(always)]
 fn field_decl<'src>() -> impl Parser<'src, &'src str, FieldDecl, Err<Rich<'src, char>>> {
-  ascii::ident()
-    .padded()
-    .map_with(|tok, e| Token::Ident(Str::new(tok), e.span()))
-    .then(field_type().padded())
-    .then(attribute().padded().repeated().collect::<Vec<Attribute>>())
-    .map(|((name, field_type), attributes)| FieldDecl {
+  doc_comments()
+    .then(
+      ascii::ident()
+        .padded_by(ws())
+        .map_with(|tok, e| Token::Ident(Str::new(tok), e.span())),
+    )
+    .then(field_type().padded_by(ws()))
+    .then(
+      attribute()
+        .padded_by(ws())
+        .repeated()
+        .collect::<Vec<Attribute>>(),
+    )
+    .map(|(((docs, name), field_type), attributes)| FieldDecl {
+      docs,
       name,
       field_type,
       attributes,
@@ -165,7 +380,12 @@ fn field_type<'src>() -> impl Parser<'src, &'src str, FieldType, Err<Rich<'src,
     .or(text::keyword("Boolean"))
     .or(text::keyword("Int32"))
     .or(text::keyword("Int64"))
-    .or(text::keyword("Float"));
+    .or(text::keyword("Float"))
+    .or(text::keyword("Decimal"))
+    .or(text::keyword("Json"))
+    .or(text::keyword("Bytes"))
+    .or(text::keyword("Date"))
+    .or(text::keyword("Uuid"));
 
   primitive_type
     .or(text::ascii::ident())
@@ -179,6 +399,11 @@ fn field_type<'src>() -> impl Parser<'src, &'src str, FieldType, Err<Rich<'src,
         "Int32" => Some(PrimitiveType::Int32),
         "Int64" => Some(PrimitiveType::Int64),
         "Float64" => Some(PrimitiveType::Float64),
+        "Decimal" => Some(PrimitiveType::Decimal),
+        "Json" => Some(PrimitiveType::Json),
+        "Bytes" => Some(PrimitiveType::Bytes),
+        "Date" => Some(PrimitiveType::Date),
+        "Uuid" => Some(PrimitiveType::Uuid),
         _ => None,
       }
       .map_or(
@@ -203,9 +428,9 @@ fn named_arg<'src>() -> impl Parser<'src, &'src str, NamedArg, Err<Rich<'src, ch
   let identifier =
     ascii::ident().map_with(|tok, e| Token::Ident(Str::new(tok), e.span()));
   identifier
-    .padded()
+    .padded_by(ws())
     .then(just(":"))
-    .then(identifier.or(string()).padded())
+    .then(identifier.or(string()).or(number()).padded_by(ws()))
     .map_with(|((arg_name, _colon), arg_value), _e| NamedArg {
       arg_name,
       arg_value,
@@ -226,39 +451,58 @@ fn attribute<'src>() -> impl Parser<'src, &'src str, Attribute, Err<Rich<'src, c
       AttribArg::Args(more_args)
     });
   let function = ascii::ident()
-    .then(just("()"))
-    .map_with(|(func_name, _parans), e| {
-      AttribArg::Function(Token::Ident(Str::new(func_name), e.span()))
+    .then(
+      number()
+        .padded_by(ws())
+        .separated_by(just(','))
+        .collect::<Vec<Token>>()
+        .delimited_by(just('('), just(')')),
+    )
+    .map_with(|(func_name, func_args), e| {
+      AttribArg::Function(Token::Ident(Str::new(func_name), e.span()), func_args)
     });
 
   let identifier = ascii::ident()
     .map_with(|tok, e| AttribArg::Ident(Token::Ident(Str::new(tok), e.span())));
 
-  let attribute_arg = arg_list.or(function).or(identifier);
+  let literal = string().or(number()).map(AttribArg::Literal);
+
+  // Note: Invalid argument is skipped till the closing paranthesis, so its own error
+  // is reported instead of the one after the attribute name.
+  let attribute_arg = arg_list
+    .or(function)
+    .or(literal)
+    .or(identifier)
+    .delimited_by(just('('), just(')'))
+    .recover_with(via_parser(nested_delimiters(
+      '(',
+      ')',
+      [('[', ']')],
+      |_| AttribArg::Args(vec![]),
+    )));
   just('@')
     .then(ascii::ident())
-    .then(just('(').then(attribute_arg).then(just(')')).or_not())
-    .map_with(|((_at, name), arg), e| {
-      let arg = arg.map(|((_open_paran, attrib_arg), _close_paran)| attrib_arg);
-      Attribute {
-        name: Token::Ident(Str::new(name), e.span()),
-        arg,
-      }
+    .then(attribute_arg.or_not())
+    .map_with(|((_at, name), arg), e| Attribute {
+      name: Token::Ident(Str::new(name), e.span()),
+      arg,
     })
 }
 
 #[cfg(test)]
 mod tests {
-  use std::{collections::HashMap, vec};
+  use std::vec;
+
+  use indexmap::IndexMap;
 
   use super::*;
-  use crate::types::{ConfigValue, RelationEdge, Span};
+  use crate::types::{ConfigValue, ReferentialActions, RelationEdge, Span};
 
   #[test]
   fn test_string() {
     assert_eq!(
       string().parse("\"Valid String\"").into_result(),
-      Ok(Token::String(Str::new("\"Valid String\""), Span::new(0, 0)))
+      Ok(Token::String(Str::new("Valid String"), Span::new(0, 0)))
     );
     assert!(string()
       .parse(" \"Invalid string because whitespaces\" ")
@@ -272,6 +516,20 @@ mod tests {
       .parse("\"Invalid string because no end quotes")
       .into_result()
       .is_err());
+
+    assert_eq!(
+      string()
+        .parse(r#""Say \"Hi\"\t\\o/\n\u00e9""#)
+        .into_result(),
+      Ok(Token::String(
+        Str::new("Say \"Hi\"\t\\o/\n\u{e9}"),
+        Span::new(0, 0)
+      ))
+    );
+    assert!(string().parse(r#""\q""#).into_result().is_err());
+    assert!(string().parse(r#""\u00""#).into_result().is_err());
+    assert!(string().parse(r#""\uD800""#).into_result().is_err());
+    assert!(string().parse(r#""\""#).into_result().is_err());
   }
 
   #[test]
@@ -294,6 +552,33 @@ mod tests {
     assert!(number().parse("12345678.as").into_result().is_err(),);
     assert!(number().parse("1A.123").into_result().is_err(),);
     assert!(number().parse(" 12345678.123 ").into_result().is_err(),);
+
+    assert_eq!(
+      number().parse("-42").into_result(),
+      Ok(Token::Int(-42, Span::new(0, 0)))
+    );
+    assert_eq!(
+      number().parse("+0x2A").into_result(),
+      Ok(Token::Int(42, Span::new(0, 0)))
+    );
+    assert_eq!(
+      number().parse("-0x8000000000000000").into_result(),
+      Ok(Token::Int(i64::MIN, Span::new(0, 0)))
+    );
+    assert_eq!(
+      number().parse("1e-3").into_result(),
+      Ok(Token::Float(0.001, Span::new(0, 0)))
+    );
+    assert_eq!(
+      number().parse("-2.5E+2").into_result(),
+      Ok(Token::Float(-250.0, Span::new(0, 0)))
+    );
+    assert!(number().parse("9223372036854775808").into_result().is_err());
+    assert!(number().parse("0x8000000000000000").into_result().is_err());
+    assert!(number().parse("1e400").into_result().is_err());
+    assert!(number().parse("1e").into_result().is_err());
+    assert!(number().parse("0x").into_result().is_err());
+    assert!(number().parse("- 1").into_result().is_err());
   }
 
   #[test]
@@ -318,7 +603,7 @@ mod tests {
         .into_result(),
       Ok(ConfigPair {
         name: Token::Ident(Str::new("provider"), Span::new(0, 0)),
-        value: ConfigValue::String(Str::new("\"foundationDB\""), Span::new(0, 0))
+        value: ConfigValue::String(Str::new("foundationDB"), Span::new(0, 0))
       })
     );
 
@@ -328,7 +613,7 @@ mod tests {
         .into_result(),
       Ok(ConfigPair {
         name: Token::Ident(Str::new("provider"), Span::new(0, 0)),
-        value: ConfigValue::String(Str::new("\"foundationDB\""), Span::new(0, 0))
+        value: ConfigValue::String(Str::new("foundationDB"), Span::new(0, 0))
       })
     );
 
@@ -393,7 +678,7 @@ mod tests {
         config_pairs: vec![
           ConfigPair {
             name: Token::Ident(Str::new("provider"), Span::new(0, 0)),
-            value: ConfigValue::String(Str::new("\"foundationDB\""), Span::new(0, 0))
+            value: ConfigValue::String(Str::new("foundationDB"), Span::new(0, 0))
           },
           ConfigPair {
             name: Token::Ident(Str::new("port"), Span::new(0, 0)),
@@ -421,6 +706,7 @@ mod tests {
     assert_eq!(
       enum_decl().parse(enum_str).into_result(),
       Ok(Declaration::Enum(EnumDecl {
+        docs: None,
         name: Token::Ident(Str::new("Role"), Span::new(0, 0)),
         elements: vec![
           Token::Ident(Str::new("USER"), Span::new(0, 0)),
@@ -495,6 +781,28 @@ mod tests {
       ))
     );
 
+    assert_eq!(
+      field_type().parse("Decimal?").into_result(),
+      Ok(FieldType::new(
+        Type::Primitive {
+          r#type: PrimitiveType::Decimal,
+          token: Token::Ident(Str::new("Decimal"), Span::new(0, 0))
+        },
+        FieldTypeMod::Optional
+      ))
+    );
+
+    assert_eq!(
+      field_type().parse("Date[]").into_result(),
+      Ok(FieldType::new(
+        Type::Primitive {
+          r#type: PrimitiveType::Date,
+          token: Token::Ident(Str::new("Date"), Span::new(0, 0))
+        },
+        FieldTypeMod::Array
+      ))
+    );
+
     assert_eq!(
       field_type().parse("MyEnum?").into_result(),
       Ok(FieldType::new(
@@ -520,10 +828,21 @@ mod tests {
       attribute().parse("@default(now())").into_result(),
       Ok(Attribute {
         name: Token::Ident(Str::new("default"), Span::new(0, 0)),
-        arg: Some(AttribArg::Function(Token::Ident(
-          Str::new("now"),
-          Span::new(0, 0)
-        )))
+        arg: Some(AttribArg::Function(
+          Token::Ident(Str::new("now"), Span::new(0, 0)),
+          vec![]
+        ))
+      })
+    );
+
+    assert_eq!(
+      attribute().parse("@default(nanoid( 16 ))").into_result(),
+      Ok(Attribute {
+        name: Token::Ident(Str::new("default"), Span::new(0, 0)),
+        arg: Some(AttribArg::Function(
+          Token::Ident(Str::new("nanoid"), Span::new(0, 0)),
+          vec![Token::Int(16, Span::new(0, 0))]
+        ))
       })
     );
 
@@ -538,6 +857,33 @@ mod tests {
       })
     );
 
+    assert_eq!(
+      attribute().parse(r#"@default("en")"#).into_result(),
+      Ok(Attribute {
+        name: Token::Ident(Str::new("default"), Span::new(0, 0)),
+        arg: Some(AttribArg::Literal(Token::String(
+          Str::new("en"),
+          Span::new(0, 0)
+        )))
+      })
+    );
+
+    assert_eq!(
+      attribute().parse("@default(-3)").into_result(),
+      Ok(Attribute {
+        name: Token::Ident(Str::new("default"), Span::new(0, 0)),
+        arg: Some(AttribArg::Literal(Token::Int(-3, Span::new(0, 0))))
+      })
+    );
+
+    assert_eq!(
+      attribute().parse("@default(2.5)").into_result(),
+      Ok(Attribute {
+        name: Token::Ident(Str::new("default"), Span::new(0, 0)),
+        arg: Some(AttribArg::Literal(Token::Float(2.5, Span::new(0, 0))))
+      })
+    );
+
     assert!(attribute().parse("unique").into_result().is_err());
     assert!(attribute().parse("@default()").into_result().is_err());
     assert!(attribute().parse("@default(@some)").into_result().is_err());
@@ -557,7 +903,7 @@ mod tests {
         name: Token::Ident(Str::new("relation"), Span::new(0, 0)),
         arg: Some(AttribArg::Args(vec![NamedArg {
           arg_name: Token::Ident(Str::new("name"), Span::new(0, 0)),
-          arg_value: Token::String(Str::new("\"MyRelation\""), Span::new(0, 0))
+          arg_value: Token::String(Str::new("MyRelation"), Span::new(0, 0))
         }]))
       })
     );
@@ -571,7 +917,7 @@ mod tests {
         arg: Some(AttribArg::Args(vec![
           NamedArg {
             arg_name: Token::Ident(Str::new("name"), Span::new(0, 0)),
-            arg_value: Token::String(Str::new("\"MyRelation\""), Span::new(0, 0))
+            arg_value: Token::String(Str::new("MyRelation"), Span::new(0, 0))
           },
           NamedArg {
             arg_name: Token::Ident(Str::new("field"), Span::new(0, 0)),
@@ -588,7 +934,7 @@ mod tests {
     assert!(attribute()
       .parse(r#"@attribute_with_out_arg_name("my_relation")"#)
       .into_result()
-      .is_err());
+      .is_ok_and(|attribute| matches!(attribute.arg, Some(AttribArg::Literal(_)))));
     assert!(attribute()
             .parse(r#"@attribute_with_out_arg_name("my_relation", field: scalar_field1, references: references_id1)"#)
             .into_result()
@@ -608,6 +954,7 @@ mod tests {
         .parse("   id          ShortStr?       @unique_id @default(auto_generate())\n")
         .into_result(),
       Ok(FieldDecl {
+        docs: None,
         name: Token::Ident(Str::new("id"), Span::new(0, 0)),
         field_type: FieldType::new(
           Type::Primitive {
@@ -623,10 +970,10 @@ mod tests {
           },
           Attribute {
             name: Token::Ident(Str::new("default"), Span::new(0, 0)),
-            arg: Some(AttribArg::Function(Token::Ident(
-              Str::new("auto_generate"),
-              Span::new(0, 0)
-            )))
+            arg: Some(AttribArg::Function(
+              Token::Ident(Str::new("auto_generate"), Span::new(0, 0)),
+              vec![]
+            ))
           }
         ]
       })
@@ -637,6 +984,7 @@ mod tests {
         .parse("   id          ShortStr?       \n")
         .into_result(),
       Ok(FieldDecl {
+        docs: None,
         name: Token::Ident(Str::new("id"), Span::new(0, 0)),
         field_type: FieldType::new(
           Type::Primitive {
@@ -665,9 +1013,12 @@ mod tests {
     assert_eq!(
       model_decl().parse(model_str).into_result(),
       Ok(Declaration::Model(ModelDecl {
+        docs: None,
+        attributes: vec![],
         name: Token::Ident(Str::new("User"), Span::new(0, 0)),
         fields: vec![
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("email"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Primitive {
@@ -682,6 +1033,7 @@ mod tests {
             }]
           },
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("name"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Primitive {
@@ -693,6 +1045,7 @@ mod tests {
             attributes: vec![]
           },
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("nickNames"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Primitive {
@@ -704,6 +1057,7 @@ mod tests {
             attributes: vec![]
           },
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("role"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Unknown(Token::Ident(Str::new("Role"), Span::new(0, 0))),
@@ -729,6 +1083,8 @@ mod tests {
     assert_eq!(
       model_decl().parse(empty_model_str).into_result(),
       Ok(Declaration::Model(ModelDecl {
+        docs: None,
+        attributes: vec![],
         name: Token::Ident(Str::new("EmptyModel"), Span::new(0, 0)),
         fields: vec![]
       }))
@@ -745,6 +1101,107 @@ mod tests {
     assert!(model_decl().parse(err_model_str).into_result().is_err());
   }
 
+  #[test]
+  fn test_type_decl() {
+    let type_str = r#"
+        /// Postal address.
+        type Address {
+            street  ShortStr
+            zip     ShortStr?
+            geo     Geo[]
+        }
+        "#;
+    assert_eq!(
+      type_decl().parse(type_str).into_result(),
+      Ok(Declaration::Type(TypeDecl {
+        docs: Some(Str::new("Postal address.")),
+        name: Token::Ident(Str::new("Address"), Span::new(0, 0)),
+        fields: vec![
+          FieldDecl {
+            docs: None,
+            name: Token::Ident(Str::new("street"), Span::new(0, 0)),
+            field_type: FieldType::new(
+              Type::Primitive {
+                r#type: PrimitiveType::ShortStr,
+                token: Token::Ident(Str::new("ShortStr"), Span::new(0, 0))
+              },
+              FieldTypeMod::NonOptional
+            ),
+            attributes: vec![]
+          },
+          FieldDecl {
+            docs: None,
+            name: Token::Ident(Str::new("zip"), Span::new(0, 0)),
+            field_type: FieldType::new(
+              Type::Primitive {
+                r#type: PrimitiveType::ShortStr,
+                token: Token::Ident(Str::new("ShortStr"), Span::new(0, 0))
+              },
+              FieldTypeMod::Optional
+            ),
+            attributes: vec![]
+          },
+          FieldDecl {
+            docs: None,
+            name: Token::Ident(Str::new("geo"), Span::new(0, 0)),
+            field_type: FieldType::new(
+              Type::Unknown(Token::Ident(Str::new("Geo"), Span::new(0, 0))),
+              FieldTypeMod::Array
+            ),
+            attributes: vec![]
+          }
+        ]
+      }))
+    );
+  }
+
+  #[test]
+  fn test_model_attribute() {
+    assert_eq!(
+      model_attribute()
+        .parse("  @@unique( [ tenantId , email ] )  \n")
+        .into_result(),
+      Ok(ModelAttribute {
+        name: Token::Ident(Str::new("unique"), Span::new(0, 0)),
+        fields: vec![
+          Token::Ident(Str::new("tenantId"), Span::new(0, 0)),
+          Token::Ident(Str::new("email"), Span::new(0, 0)),
+        ]
+      })
+    );
+    assert!(model_attribute()
+      .parse("@unique([tenantId, email])")
+      .into_result()
+      .is_err());
+    assert!(model_attribute()
+      .parse("@@unique(tenantId, email)")
+      .into_result()
+      .is_err());
+
+    let model_str = r#"
+        model User {
+            @@index([tenantId, role])
+            tenantId    ShortStr
+            email       ShortStr
+            role        Role
+            @@unique([tenantId, email])
+        }
+        "#;
+    let Ok(Declaration::Model(model)) = model_decl().parse(model_str).into_result()
+    else {
+      panic!("User should be a model");
+    };
+    assert_eq!(model.fields.len(), 3);
+    assert_eq!(
+      model
+        .attributes
+        .iter()
+        .map(ModelAttribute::compound_name)
+        .collect::<Vec<_>>(),
+      vec!["tenantId_role", "tenantId_email"]
+    );
+  }
+
   #[test]
   fn test_parse() {
     let sdml_str = r#"
@@ -783,16 +1240,19 @@ mod tests {
             time_out_in_secs = 12.10
         }
         "#.to_string();
-    let mut configs = HashMap::new();
-    let mut enums = HashMap::new();
-    let mut models = HashMap::new();
-    let mut relations = HashMap::new();
+    let mut configs = IndexMap::new();
+    let mut enums = IndexMap::new();
+    let mut models = IndexMap::new();
+    let mut relations = IndexMap::new();
     models.insert(
       "User".to_string(),
       ModelDecl {
+        docs: None,
+        attributes: vec![],
         name: Token::Ident(Str::new("User"), Span::new(0, 0)),
         fields: vec![
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("id"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Primitive {
@@ -808,14 +1268,15 @@ mod tests {
               },
               Attribute {
                 name: Token::Ident(Str::new("default"), Span::new(0, 0)),
-                arg: Some(AttribArg::Function(Token::Ident(
-                  Str::new("auto"),
-                  Span::new(0, 0),
-                ))),
+                arg: Some(AttribArg::Function(
+                  Token::Ident(Str::new("auto"), Span::new(0, 0)),
+                  vec![],
+                )),
               },
             ],
           },
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("email"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Primitive {
@@ -830,6 +1291,7 @@ mod tests {
             }],
           },
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("name"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Primitive {
@@ -841,6 +1303,7 @@ mod tests {
             attributes: vec![],
           },
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("nickNames"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Primitive {
@@ -852,6 +1315,7 @@ mod tests {
             attributes: vec![],
           },
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("role"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Enum {
@@ -868,16 +1332,15 @@ mod tests {
             }],
           },
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("mentor"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Relation(RelationEdge::ManySideRelation {
-                relation_name: Token::String(
-                  Str::new(r#""UserMentor""#),
-                  Span::new(0, 0),
-                ),
+                relation_name: Token::String(Str::new("UserMentor"), Span::new(0, 0)),
                 scalar_field_name: Token::Ident(Str::new("mentorEmail"), Span::new(0, 0)),
                 referenced_model_name: Token::Ident(Str::new("User"), Span::new(0, 0)),
                 referenced_field_name: Token::Ident(Str::new("email"), Span::new(0, 0)),
+                referential_actions: ReferentialActions::default(),
               }),
               FieldTypeMod::Optional,
             ),
@@ -886,7 +1349,7 @@ mod tests {
               arg: Some(AttribArg::Args(vec![
                 NamedArg {
                   arg_name: Token::Ident(Str::new("name"), Span::new(0, 0)),
-                  arg_value: Token::String(Str::new("\"UserMentor\""), Span::new(0, 0)),
+                  arg_value: Token::String(Str::new("UserMentor"), Span::new(0, 0)),
                 },
                 NamedArg {
                   arg_name: Token::Ident(Str::new("field"), Span::new(0, 0)),
@@ -900,6 +1363,7 @@ mod tests {
             }],
           },
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("mentorEmail"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Primitive {
@@ -911,10 +1375,11 @@ mod tests {
             attributes: vec![],
           },
           FieldDecl {
+            docs: None,
             name: Token::Ident(Str::new("mentees"), Span::new(0, 0)),
             field_type: FieldType::new(
               Type::Relation(RelationEdge::OneSideRelation {
-                relation_name: Token::String(Str::new("\"UserMentor\""), Span::new(0, 0)),
+                relation_name: Token::String(Str::new("UserMentor"), Span::new(0, 0)),
                 referenced_model_name: Token::Ident(Str::new("User"), Span::new(0, 0)),
               }),
               FieldTypeMod::Array,
@@ -923,7 +1388,7 @@ mod tests {
               name: Token::Ident(Str::new("relation"), Span::new(0, 0)),
               arg: Some(AttribArg::Args(vec![NamedArg {
                 arg_name: Token::Ident(Str::new("name"), Span::new(0, 0)),
-                arg_value: Token::String(Str::new("\"UserMentor\""), Span::new(0, 0)),
+                arg_value: Token::String(Str::new("UserMentor"), Span::new(0, 0)),
               }])),
             }],
           },
@@ -933,8 +1398,11 @@ mod tests {
     models.insert(
       "EmptyModel".to_string(),
       ModelDecl {
+        docs: None,
+        attributes: vec![],
         name: Token::Ident(Str::new("EmptyModel"), Span::new(0, 0)),
         fields: vec![FieldDecl {
+          docs: None,
           name: Token::Ident(Str::new("id"), Span::new(0, 0)),
           field_type: FieldType::new(
             Type::Primitive {
@@ -954,20 +1422,22 @@ mod tests {
       "UserMentor".to_string(),
       (
         RelationEdge::OneSideRelation {
-          relation_name: Token::String(Str::new(r#""UserMentor""#), Span::new(0, 0)),
+          relation_name: Token::String(Str::new("UserMentor"), Span::new(0, 0)),
           referenced_model_name: Token::Ident(Str::new("User"), Span::new(0, 0)),
         },
         Some(RelationEdge::ManySideRelation {
-          relation_name: Token::String(Str::new(r#""UserMentor""#), Span::new(0, 0)),
+          relation_name: Token::String(Str::new("UserMentor"), Span::new(0, 0)),
           scalar_field_name: Token::Ident(Str::new("mentorEmail"), Span::new(0, 0)),
           referenced_model_name: Token::Ident(Str::new("User"), Span::new(0, 0)),
           referenced_field_name: Token::Ident(Str::new("email"), Span::new(0, 0)),
+          referential_actions: ReferentialActions::default(),
         }),
       ),
     );
     enums.insert(
       "Role".to_string(),
       EnumDecl {
+        docs: None,
         name: Token::Ident(Str::new("Role"), Span::new(0, 0)),
         elements: vec![
           Token::Ident(Str::new("USER"), Span::new(0, 0)),
@@ -979,6 +1449,7 @@ mod tests {
     enums.insert(
       "Role1".to_string(),
       EnumDecl {
+        docs: None,
         name: Token::Ident(Str::new("Role1"), Span::new(0, 0)),
         elements: vec![
           Token::Ident(Str::new("USER1"), Span::new(0, 0)),
@@ -994,7 +1465,7 @@ mod tests {
         config_pairs: vec![
           ConfigPair {
             name: Token::Ident(Str::new("provider"), Span::new(0, 0)),
-            value: ConfigValue::String(Str::new("\"foundationDB\""), Span::new(0, 0)),
+            value: ConfigValue::String(Str::new("foundationDB"), Span::new(0, 0)),
           },
           ConfigPair {
             name: Token::Ident(Str::new("port"), Span::new(0, 0)),
@@ -1014,7 +1485,7 @@ mod tests {
         config_pairs: vec![
           ConfigPair {
             name: Token::Ident(Str::new("provider"), Span::new(0, 0)),
-            value: ConfigValue::String(Str::new("\"foundationDB\""), Span::new(0, 0)),
+            value: ConfigValue::String(Str::new("foundationDB"), Span::new(0, 0)),
           },
           ConfigPair {
             name: Token::Ident(Str::new("port"), Span::new(0, 0)),
@@ -1027,7 +1498,7 @@ mod tests {
         ],
       },
     );
-    let ast = DataModel::new(configs, enums, models, relations);
+    let ast = DataModel::new(configs, enums, IndexMap::new(), models, relations);
     let actual_ast = parse(&sdml_str).unwrap();
     assert_eq!(ast.enums(), actual_ast.enums());
     assert_eq!(ast.relations(), actual_ast.relations());
@@ -1036,6 +1507,147 @@ mod tests {
     assert_eq!(ast, actual_ast);
   }
 
+  #[test]
+  fn test_comments() {
+    let sdml_str = r#"
+        // Configuration.
+        config db { # Comment after a brace.
+            provider = "foundationDB" // Trailing comment.
+        }
+        #
+        model User {
+            email       ShortStr      @unique # Comment after an attribute.
+            //// Not a doc comment.
+            name        ShortStr? // Comment after a type.
+        }
+        # Trailing comment."#;
+    let decls = delcarations().parse(sdml_str).into_result().unwrap();
+    assert_eq!(decls.len(), 2);
+    let Declaration::Model(model) = &decls[1] else {
+      panic!("User should be a model");
+    };
+    assert_eq!(model.fields.len(), 2);
+    assert!(model.fields.iter().all(|field| field.docs.is_none()));
+
+    let sample_model_with_comments = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/sample_model_with_comments.sdml"
+    ))
+    .unwrap();
+    let decls = delcarations()
+      .parse(&sample_model_with_comments)
+      .into_result()
+      .unwrap();
+    assert_eq!(decls.len(), 6);
+  }
+
+  #[test]
+  fn test_doc_comments() {
+    let sdml_str = r#"
+        /// User of the blog.
+        ///   Can author many posts.
+        model User {
+            /// Email of the user.
+            email       ShortStr      @unique
+            name        ShortStr?
+        }
+
+        # Not a doc comment.
+        ///Role of the user.
+        enum Role {
+            USER
+        }"#;
+    let decls = delcarations().parse(sdml_str).into_result().unwrap();
+    let Declaration::Model(model) = &decls[0] else {
+      panic!("User should be a model");
+    };
+    assert_eq!(
+      model.docs,
+      Some(Str::new("User of the blog.\n  Can author many posts."))
+    );
+    assert_eq!(model.fields[0].docs, Some(Str::new("Email of the user.")));
+    assert_eq!(model.fields[1].docs, None);
+    let Declaration::Enum(r#enum) = &decls[1] else {
+      panic!("Role should be an enum");
+    };
+    assert_eq!(r#enum.docs, Some(Str::new("Role of the user.")));
+  }
+
+  #[test]
+  fn test_dangling_doc_comments() {
+    // Doc comments which aren't followed by a declaration or a field are ignored.
+    let decls = delcarations()
+      .parse("model User {\n email ShortStr @unique\n /// Dangling doc comment.\n}")
+      .into_result()
+      .unwrap();
+    let Declaration::Model(model) = &decls[0] else {
+      panic!("User should be a model");
+    };
+    assert_eq!(model.fields.len(), 1);
+    let decls = delcarations()
+      .parse(
+        "enum Role {\n /// Default role.\n USER\n ADMIN\n /// Dangling doc comment.\n}",
+      )
+      .into_result()
+      .unwrap();
+    let Declaration::Enum(r#enum) = &decls[0] else {
+      panic!("Role should be an enum");
+    };
+    assert_eq!(r#enum.elements.len(), 2);
+    let decls = delcarations()
+      .parse("enum Role {\n USER\n}\n/// Dangling doc comment.")
+      .into_result()
+      .unwrap();
+    assert_eq!(decls.len(), 1);
+  }
+
+  #[test]
+  fn test_parse_literal_errs() {
+    let sdml_str = r#"
+        model User {
+            id      ShortStr    @id
+            name    ShortStr    @default("\uD800")
+        }
+
+        model Post {
+            id      ShortStr    @id
+            views   Int64       @default(-9223372036854775809)
+        }
+        "#;
+    let expected_errs = vec![
+      Error::ParserError {
+        span: SimpleSpan::new(102, 106),
+        message: "Invalid unicode escape".to_string(),
+      },
+      Error::ParserError {
+        span: SimpleSpan::new(218, 238),
+        message: "Integer is out of the 64 bit range".to_string(),
+      },
+    ];
+    assert_eq!(parse(sdml_str).unwrap_err(), expected_errs);
+  }
+
+  #[test]
+  fn test_parse_declaration_errs() {
+    // Misspelled keyword isn't skipped, even when it's the last declaration.
+    let sdml_str = "model A {\n id ShortStr @id\n}\nmodle B {\n id ShortStr @id\n}\n";
+    let expected_errs = vec![Error::ParserError {
+      span: SimpleSpan::new(29, 30),
+      message: "found m expected end of input".to_string(),
+    }];
+    assert_eq!(parse(sdml_str).unwrap_err(), expected_errs);
+
+    // Invalid enum element is reported at the element, not at the enum keyword.
+    let expected_errs = vec![Error::ParserError {
+      span: SimpleSpan::new(24, 25),
+      message: "found - expected '}'".to_string(),
+    }];
+    let sdml_str = "enum Role {\n USER\n ADMIN-X\n}\n";
+    assert_eq!(parse(sdml_str).unwrap_err(), expected_errs);
+    let sdml_str = "enum Role {\n USER\n ADMIN-X\n}\nmodel A {\n id ShortStr @id\n}\n";
+    assert_eq!(parse(sdml_str).unwrap_err(), expected_errs);
+  }
+
   #[test]
   fn test_happy_path_parse() {
     let happy_path_model_sdml = std::fs::read_to_string(concat!(
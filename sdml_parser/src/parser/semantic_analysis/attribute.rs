@@ -7,25 +7,77 @@ pub const ATTRIB_NAME_ID: &str = "id";
 pub const ATTRIB_NAME_RELATION: &str = "relation";
 pub const ATTRIB_NAME_UNIQUE: &str = "unique";
 pub const ATTRIB_NAME_INDEXED: &str = "indexed";
+pub const ATTRIB_NAME_UPDATED_AT: &str = "updatedAt";
+/// `@length(min: m, max: n)` limits the number of characters of the string.
+pub const ATTRIB_NAME_LENGTH: &str = "length";
+/// `@range(min: m, max: n)` limits the number, bounds are inclusive.
+pub const ATTRIB_NAME_RANGE: &str = "range";
+/// `@pattern("regex")` requires the string to match the regular expression.
+pub const ATTRIB_NAME_PATTERN: &str = "pattern";
+/// `@email` requires the string to be an email address.
+pub const ATTRIB_NAME_EMAIL: &str = "email";
+/// Attributes validating the field values, checked on every create & update.
+pub const ATTRIB_NAME_VALIDATIONS: [&str; 4] = [
+  ATTRIB_NAME_LENGTH,
+  ATTRIB_NAME_RANGE,
+  ATTRIB_NAME_PATTERN,
+  ATTRIB_NAME_EMAIL,
+];
+// Valid model (block) attribute names.
+pub const ATTRIB_NAME_MODEL_ID: &str = "id";
+pub const ATTRIB_NAME_MODEL_UNIQUE: &str = "unique";
+pub const ATTRIB_NAME_MODEL_INDEX: &str = "index";
 // Valid attribute arg functions
 pub const ATTRIB_ARG_FN_NOW: &str = "now";
 pub const ATTRIB_ARG_FN_AUTO: &str = "auto";
+/// `uuid()` or `uuid(4)` generates UUID v4, `uuid(7)` generates the time-sortable UUID v7.
+pub const ATTRIB_ARG_FN_UUID: &str = "uuid";
+pub const ATTRIB_ARG_FN_CUID: &str = "cuid";
+pub const ATTRIB_ARG_FN_ULID: &str = "ulid";
+/// `nanoid(n)` generates the Nano ID of `n` characters, 21 if `n` isn't given.
+pub const ATTRIB_ARG_FN_NANOID: &str = "nanoid";
+/// Monotonically increasing integer, counted per model field.
+pub const ATTRIB_ARG_FN_SEQUENCE: &str = "sequence";
+/// Functions generating the ID values.
+pub const ATTRIB_ARG_FN_ID_GENERATORS: [&str; 6] = [
+  ATTRIB_ARG_FN_AUTO,
+  ATTRIB_ARG_FN_UUID,
+  ATTRIB_ARG_FN_CUID,
+  ATTRIB_ARG_FN_ULID,
+  ATTRIB_ARG_FN_NANOID,
+  ATTRIB_ARG_FN_SEQUENCE,
+];
 
 // Valid attribute arg values
 pub const ATTRIB_ARG_VALUE_TRUE: &str = "true";
 pub const ATTRIB_ARG_VALUE_FALSE: &str = "false";
 /// Allow valid enum value if attribute is present on field whos type is an enum.
 pub const ATTRIB_ARG_VALUE_ENUM: &str = "enum";
+/// Allow string, integer & float literals which fit the type of the field.
+pub const ATTRIB_ARG_VALUE_LITERAL: &str = "literal";
 
 // Valid attribute named args
 pub const ATTRIB_NAMED_ARG_NAME: &str = "name";
 pub const ATTRIB_NAMED_ARG_FIELD: &str = "field";
 pub const ATTRIB_NAMED_ARG_REFERENCES: &str = "references";
+/// Referential action when the referenced object is deleted, Ex. `onDelete: Cascade`.
+pub const ATTRIB_NAMED_ARG_ON_DELETE: &str = "onDelete";
+/// Referential action when the referenced field is updated, Ex. `onUpdate: Cascade`.
+pub const ATTRIB_NAMED_ARG_ON_UPDATE: &str = "onUpdate";
+/// Lower & upper bounds of the `@length` & `@range` attributes, at least one is given.
+pub const ATTRIB_NAMED_ARG_MIN: &str = "min";
+pub const ATTRIB_NAMED_ARG_MAX: &str = "max";
 
 #[derive(Debug, PartialEq)]
 pub(crate) enum AllowedFieldType {
   /// Attribute is allowed only on short (i.e. shouldn't be an array) string field.
   ScalarShortStrField { can_be_optional: bool },
+  /// Attribute is allowed only on date time (i.e. shouldn't be an array) field.
+  ScalarDateTimeField { can_be_optional: bool },
+  /// Attribute is allowed only on string field, or on the list of strings.
+  StrField { can_be_optional: bool },
+  /// Attribute is allowed only on number field, or on the list of numbers.
+  NumberField { can_be_optional: bool },
   /// Attribute is allowed on only scalar field.
   ScalarField { can_be_optional: bool },
   /// Attribute is allowed only on non-scalar field.
@@ -50,6 +102,21 @@ impl fmt::Display for AllowedFieldType {
         "{} Scalar Short String field is allowed",
         optionality_prefix(*can_be_optional)
       ),
+      AllowedFieldType::ScalarDateTimeField { can_be_optional } => write!(
+        f,
+        "{} Scalar DateTime field is allowed",
+        optionality_prefix(*can_be_optional)
+      ),
+      AllowedFieldType::StrField { can_be_optional } => write!(
+        f,
+        "{} String field is allowed",
+        optionality_prefix(*can_be_optional)
+      ),
+      AllowedFieldType::NumberField { can_be_optional } => write!(
+        f,
+        "{} Number field is allowed",
+        optionality_prefix(*can_be_optional)
+      ),
       AllowedFieldType::ScalarField { can_be_optional } => write!(
         f,
         "{} Scalar field is allowed",
@@ -99,6 +166,14 @@ impl AttributeDetails {
     attributes_map.insert(ATTRIB_NAME_RELATION, AttributeDetails::relation_attribute());
     attributes_map.insert(ATTRIB_NAME_UNIQUE, AttributeDetails::unique_attribute());
     attributes_map.insert(ATTRIB_NAME_INDEXED, AttributeDetails::indexed_attribute());
+    attributes_map.insert(
+      ATTRIB_NAME_UPDATED_AT,
+      AttributeDetails::updated_at_attribute(),
+    );
+    attributes_map.insert(ATTRIB_NAME_LENGTH, AttributeDetails::length_attribute());
+    attributes_map.insert(ATTRIB_NAME_RANGE, AttributeDetails::range_attribute());
+    attributes_map.insert(ATTRIB_NAME_PATTERN, AttributeDetails::pattern_attribute());
+    attributes_map.insert(ATTRIB_NAME_EMAIL, AttributeDetails::email_attribute());
     attributes_map
   }
   /// Does this attribute shouldn't have any args ?
@@ -111,12 +186,24 @@ impl AttributeDetails {
   fn default_attribute() -> Self {
     Self {
       name: ATTRIB_NAME_DEFAULT,
-      compatible_attribute_names: vec![ATTRIB_NAME_ID, ATTRIB_NAME_INDEXED],
-      allowed_arg_fns: vec![ATTRIB_ARG_FN_AUTO, ATTRIB_ARG_FN_NOW],
+      compatible_attribute_names: with_validations(&[
+        ATTRIB_NAME_ID,
+        ATTRIB_NAME_INDEXED,
+      ]),
+      allowed_arg_fns: vec![
+        ATTRIB_ARG_FN_AUTO,
+        ATTRIB_ARG_FN_NOW,
+        ATTRIB_ARG_FN_UUID,
+        ATTRIB_ARG_FN_CUID,
+        ATTRIB_ARG_FN_ULID,
+        ATTRIB_ARG_FN_NANOID,
+        ATTRIB_ARG_FN_SEQUENCE,
+      ],
       allowed_arg_values: vec![
         ATTRIB_ARG_VALUE_TRUE,
         ATTRIB_ARG_VALUE_FALSE,
         ATTRIB_ARG_VALUE_ENUM,
+        ATTRIB_ARG_VALUE_LITERAL,
       ],
       allowed_named_args: vec![],
       allowed_field_type: AllowedFieldType::ScalarField {
@@ -128,7 +215,7 @@ impl AttributeDetails {
   fn id_attribute() -> Self {
     Self {
       name: ATTRIB_NAME_ID,
-      compatible_attribute_names: vec![ATTRIB_NAME_DEFAULT],
+      compatible_attribute_names: with_validations(&[ATTRIB_NAME_DEFAULT]),
       allowed_arg_fns: vec![],
       allowed_arg_values: vec![],
       allowed_named_args: vec![],
@@ -150,6 +237,8 @@ impl AttributeDetails {
         ATTRIB_NAMED_ARG_NAME,
         ATTRIB_NAMED_ARG_FIELD,
         ATTRIB_NAMED_ARG_REFERENCES,
+        ATTRIB_NAMED_ARG_ON_DELETE,
+        ATTRIB_NAMED_ARG_ON_UPDATE,
       ],
       allowed_field_type: AllowedFieldType::NonScalarField {
         can_be_optional: true,
@@ -160,7 +249,7 @@ impl AttributeDetails {
   fn unique_attribute() -> Self {
     Self {
       name: ATTRIB_NAME_UNIQUE,
-      compatible_attribute_names: vec![],
+      compatible_attribute_names: with_validations(&[]),
       allowed_arg_fns: vec![],
       allowed_arg_values: vec![],
       allowed_named_args: vec![],
@@ -173,7 +262,10 @@ impl AttributeDetails {
   fn indexed_attribute() -> Self {
     Self {
       name: ATTRIB_NAME_INDEXED,
-      compatible_attribute_names: vec![ATTRIB_NAME_DEFAULT],
+      compatible_attribute_names: with_validations(&[
+        ATTRIB_NAME_DEFAULT,
+        ATTRIB_NAME_UPDATED_AT,
+      ]),
       allowed_arg_fns: vec![],
       allowed_arg_values: vec![],
       allowed_named_args: vec![],
@@ -182,6 +274,90 @@ impl AttributeDetails {
       },
     }
   }
+  #[inline]
+  fn updated_at_attribute() -> Self {
+    Self {
+      name: ATTRIB_NAME_UPDATED_AT,
+      compatible_attribute_names: vec![ATTRIB_NAME_INDEXED],
+      allowed_arg_fns: vec![],
+      allowed_arg_values: vec![],
+      allowed_named_args: vec![],
+      // Value is set by the storage on every create & update.
+      allowed_field_type: AllowedFieldType::ScalarDateTimeField {
+        can_be_optional: true,
+      },
+    }
+  }
+  #[inline]
+  fn length_attribute() -> Self {
+    Self {
+      name: ATTRIB_NAME_LENGTH,
+      compatible_attribute_names: validation_compatible_attribute_names(),
+      allowed_arg_fns: vec![],
+      allowed_arg_values: vec![],
+      allowed_named_args: vec![ATTRIB_NAMED_ARG_MIN, ATTRIB_NAMED_ARG_MAX],
+      allowed_field_type: AllowedFieldType::StrField {
+        can_be_optional: true,
+      },
+    }
+  }
+  #[inline]
+  fn range_attribute() -> Self {
+    Self {
+      name: ATTRIB_NAME_RANGE,
+      compatible_attribute_names: validation_compatible_attribute_names(),
+      allowed_arg_fns: vec![],
+      allowed_arg_values: vec![],
+      allowed_named_args: vec![ATTRIB_NAMED_ARG_MIN, ATTRIB_NAMED_ARG_MAX],
+      allowed_field_type: AllowedFieldType::NumberField {
+        can_be_optional: true,
+      },
+    }
+  }
+  #[inline]
+  fn pattern_attribute() -> Self {
+    Self {
+      name: ATTRIB_NAME_PATTERN,
+      compatible_attribute_names: validation_compatible_attribute_names(),
+      allowed_arg_fns: vec![],
+      allowed_arg_values: vec![ATTRIB_ARG_VALUE_LITERAL],
+      allowed_named_args: vec![],
+      allowed_field_type: AllowedFieldType::StrField {
+        can_be_optional: true,
+      },
+    }
+  }
+  #[inline]
+  fn email_attribute() -> Self {
+    Self {
+      name: ATTRIB_NAME_EMAIL,
+      compatible_attribute_names: validation_compatible_attribute_names(),
+      allowed_arg_fns: vec![],
+      allowed_arg_values: vec![],
+      allowed_named_args: vec![],
+      allowed_field_type: AllowedFieldType::StrField {
+        can_be_optional: true,
+      },
+    }
+  }
+}
+
+/// Validation attributes can be applied along with the given attributes.
+fn with_validations(attribute_names: &[&'static str]) -> Vec<&'static str> {
+  let mut attribute_names = attribute_names.to_vec();
+  attribute_names.extend(ATTRIB_NAME_VALIDATIONS);
+  attribute_names
+}
+
+/// Validation attributes can be applied along with each other, and along with the
+/// attributes which don't decide the field value.
+fn validation_compatible_attribute_names() -> Vec<&'static str> {
+  with_validations(&[
+    ATTRIB_NAME_DEFAULT,
+    ATTRIB_NAME_ID,
+    ATTRIB_NAME_UNIQUE,
+    ATTRIB_NAME_INDEXED,
+  ])
 }
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
 use chumsky::error::Rich as ChumskyError;
 use core::fmt;
 
-use crate::types::Span;
+use crate::types::{ReferentialAction, Span};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Error {
@@ -37,6 +37,14 @@ pub enum Error {
     field_name: String,
     model_name: String,
   },
+  /// This error is thrown if the field of the composite type (`type` block) is invalid,
+  /// Ex. it references a model or has attributes.
+  TypeFieldInvalid {
+    span: Span,
+    reason: String,
+    field_name: String,
+    type_name: String,
+  },
   /// This error is returned if undefined enum value is used.
   EnumValueUndefined {
     span: Span,
@@ -69,6 +77,28 @@ pub enum Error {
     field_name: String,
     model_name: String,
   },
+  /// This error is returned for unknown model (block) attribute usage, ex. `@@foo([a, b])`.
+  ModelAttributeUnknown {
+    span: Span,
+    attrib_name: String,
+    model_name: String,
+  },
+  /// This error is thrown if the model (block) attribute is invalid.
+  ModelAttributeInvalid {
+    span: Span,
+    reason: String,
+    attrib_name: String,
+    model_name: String,
+  },
+  /// This error is thrown if a field listed in the model (block) attribute can't be
+  /// part of it.
+  ModelAttributeFieldInvalid {
+    span: Span,
+    reason: String,
+    attrib_name: String,
+    field_name: String,
+    model_name: String,
+  },
   /// This error is thrown if the argment passed to attribute is invalid.
   AttributeArgInvalid {
     span: Span,
@@ -77,6 +107,16 @@ pub enum Error {
     field_name: String,
     model_name: String,
   },
+  /// This error is thrown if the value of the attribute argument doesn't fit the type of the field.
+  /// Ex. `@default("en")` on an `Int32` field.
+  /// Note: The value itself is pointed by the span.
+  AttributeArgTypeMismatch {
+    span: Span,
+    field_type: String,
+    attrib_name: String,
+    field_name: String,
+    model_name: String,
+  },
   /// Invalid Relation - This error is thrown for invalid relation.
   RelationInvalid {
     span: Span,
@@ -192,6 +232,380 @@ pub enum Error {
     field_name: Option<String>,
     model_name: Option<String>,
   },
+  /// This error is thrown if the referential action can't be applied to the relation scalar field.
+  /// Ex. `onDelete: SetNull` on a relation with the required scalar field.
+  RelationReferentialActionInvalid {
+    span: Span,
+    action: String,
+    /// relation scalar field name.
+    field_name: String,
+    model_name: String,
+  },
+}
+
+impl Error {
+  /// Span of the source, where the error is found.
+  pub fn span(&self) -> Span {
+    match self {
+      Error::ParserError { span, .. }
+      | Error::ModelIdFieldMissing { span, .. }
+      | Error::ModelIdFieldDuplicate { span, .. }
+      | Error::ModelEmpty { span, .. }
+      | Error::TypeDuplicateDefinition { span, .. }
+      | Error::TypeUndefined { span, .. }
+      | Error::TypeFieldInvalid { span, .. }
+      | Error::EnumValueUndefined { span, .. }
+      | Error::AttributeInvalid { span, .. }
+      | Error::AttributeIncompatible { span, .. }
+      | Error::AttributeUnknown { span, .. }
+      | Error::ModelAttributeUnknown { span, .. }
+      | Error::ModelAttributeInvalid { span, .. }
+      | Error::ModelAttributeFieldInvalid { span, .. }
+      | Error::AttributeArgInvalid { span, .. }
+      | Error::AttributeArgTypeMismatch { span, .. }
+      | Error::RelationInvalid { span, .. }
+      | Error::RelationDuplicate { span, .. }
+      | Error::RelationPartial { span, .. }
+      | Error::RelationAttributeMissing { span, .. }
+      | Error::RelationScalarFieldNotFound { span, .. }
+      | Error::RelationScalarFieldIsNotPrimitive { span, .. }
+      | Error::RelationReferencedFieldNotFound { span, .. }
+      | Error::RelationReferencedFieldNotScalar { span, .. }
+      | Error::RelationReferencedFieldNotUnique { span, .. }
+      | Error::RelationScalarFieldNotUnique { span, .. }
+      | Error::RelationScalarFieldIsUnique { span, .. }
+      | Error::RelationScalarAndReferencedFieldsTypeMismatch { span, .. }
+      | Error::RelationInvalidAttribute { span, .. }
+      | Error::RelationInvalidAttributeArg { span, .. }
+      | Error::RelationReferentialActionInvalid { span, .. } => *span,
+    }
+  }
+
+  /// Stable code of the error, Ex. `ModelIdFieldMissing`.
+  pub fn code(&self) -> &'static str {
+    match self {
+      Error::ParserError { .. } => "ParserError",
+      Error::ModelIdFieldMissing { .. } => "ModelIdFieldMissing",
+      Error::ModelIdFieldDuplicate { .. } => "ModelIdFieldDuplicate",
+      Error::ModelEmpty { .. } => "ModelEmpty",
+      Error::TypeDuplicateDefinition { .. } => "TypeDuplicateDefinition",
+      Error::TypeUndefined { .. } => "TypeUndefined",
+      Error::TypeFieldInvalid { .. } => "TypeFieldInvalid",
+      Error::EnumValueUndefined { .. } => "EnumValueUndefined",
+      Error::AttributeInvalid { .. } => "AttributeInvalid",
+      Error::AttributeIncompatible { .. } => "AttributeIncompatible",
+      Error::AttributeUnknown { .. } => "AttributeUnknown",
+      Error::ModelAttributeUnknown { .. } => "ModelAttributeUnknown",
+      Error::ModelAttributeInvalid { .. } => "ModelAttributeInvalid",
+      Error::ModelAttributeFieldInvalid { .. } => "ModelAttributeFieldInvalid",
+      Error::AttributeArgInvalid { .. } => "AttributeArgInvalid",
+      Error::AttributeArgTypeMismatch { .. } => "AttributeArgTypeMismatch",
+      Error::RelationInvalid { .. } => "RelationInvalid",
+      Error::RelationDuplicate { .. } => "RelationDuplicate",
+      Error::RelationPartial { .. } => "RelationPartial",
+      Error::RelationAttributeMissing { .. } => "RelationAttributeMissing",
+      Error::RelationScalarFieldNotFound { .. } => "RelationScalarFieldNotFound",
+      Error::RelationScalarFieldIsNotPrimitive { .. } => {
+        "RelationScalarFieldIsNotPrimitive"
+      }
+      Error::RelationReferencedFieldNotFound { .. } => "RelationReferencedFieldNotFound",
+      Error::RelationReferencedFieldNotScalar { .. } => {
+        "RelationReferencedFieldNotScalar"
+      }
+      Error::RelationReferencedFieldNotUnique { .. } => {
+        "RelationReferencedFieldNotUnique"
+      }
+      Error::RelationScalarFieldNotUnique { .. } => "RelationScalarFieldNotUnique",
+      Error::RelationScalarFieldIsUnique { .. } => "RelationScalarFieldIsUnique",
+      Error::RelationScalarAndReferencedFieldsTypeMismatch { .. } => {
+        "RelationScalarAndReferencedFieldsTypeMismatch"
+      }
+      Error::RelationInvalidAttribute { .. } => "RelationInvalidAttribute",
+      Error::RelationInvalidAttributeArg { .. } => "RelationInvalidAttributeArg",
+      Error::RelationReferentialActionInvalid { .. } => {
+        "RelationReferentialActionInvalid"
+      }
+    }
+  }
+
+  /// Human readable message of the error.
+  pub fn message(&self) -> String {
+    match self {
+      Error::ParserError { message, .. } => message.clone(),
+      Error::ModelIdFieldMissing { model_name, .. } => {
+        format!("Model `{model_name}` has no ID field")
+      }
+      Error::ModelIdFieldDuplicate {
+        field_name,
+        model_name,
+        ..
+      } => format!("Model `{model_name}` has more than one ID field, `{field_name}` is one of them"),
+      Error::ModelEmpty { model_name, .. } => {
+        format!("Model `{model_name}` has no fields to store")
+      }
+      Error::TypeDuplicateDefinition { type_name, .. } => {
+        format!("Type `{type_name}` is defined more than once")
+      }
+      Error::TypeUndefined {
+        type_name,
+        field_name,
+        model_name,
+        ..
+      } => format!("Type `{type_name}` of the field `{model_name}.{field_name}` is undefined"),
+      Error::TypeFieldInvalid {
+        reason,
+        field_name,
+        type_name,
+        ..
+      } => format!("Field `{type_name}.{field_name}` of the composite type is invalid: {reason}"),
+      Error::EnumValueUndefined {
+        enum_value,
+        attrib_name,
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Enum value `{enum_value}` used in `@{attrib_name}` of the field `{model_name}.{field_name}` is undefined"
+      ),
+      Error::AttributeInvalid {
+        reason,
+        attrib_name,
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Attribute `@{attrib_name}` of the field `{model_name}.{field_name}` is invalid: {reason}"
+      ),
+      Error::AttributeIncompatible {
+        attrib_name,
+        first_attrib_name,
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Attribute `@{attrib_name}` can't be used along with `@{first_attrib_name}` on the field `{model_name}.{field_name}`"
+      ),
+      Error::AttributeUnknown {
+        attrib_name,
+        field_name,
+        model_name,
+        ..
+      } => format!("Unknown attribute `@{attrib_name}` on the field `{model_name}.{field_name}`"),
+      Error::ModelAttributeUnknown {
+        attrib_name,
+        model_name,
+        ..
+      } => format!("Unknown attribute `@@{attrib_name}` on the model `{model_name}`"),
+      Error::ModelAttributeInvalid {
+        reason,
+        attrib_name,
+        model_name,
+        ..
+      } => format!(
+        "Attribute `@@{attrib_name}` of the model `{model_name}` is invalid: {reason}"
+      ),
+      Error::ModelAttributeFieldInvalid {
+        reason,
+        attrib_name,
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Field `{field_name}` can't be part of `@@{attrib_name}` of the model `{model_name}`: {reason}"
+      ),
+      Error::AttributeArgInvalid {
+        attrib_arg_name,
+        attrib_name,
+        field_name,
+        model_name,
+        ..
+      } => match attrib_arg_name {
+        Some(arg_name) => format!(
+          "Argument `{arg_name}` of the attribute `@{attrib_name}` on the field `{model_name}.{field_name}` is invalid"
+        ),
+        None => format!(
+          "Argument of the attribute `@{attrib_name}` on the field `{model_name}.{field_name}` is invalid"
+        ),
+      },
+      Error::AttributeArgTypeMismatch {
+        field_type,
+        attrib_name,
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Value of the attribute `@{attrib_name}` doesn't fit the type `{field_type}` of the field `{model_name}.{field_name}`"
+      ),
+      Error::RelationInvalid { relation_name, .. } => {
+        format!("Relation `{relation_name}` is invalid")
+      }
+      Error::RelationDuplicate {
+        relation_name,
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Relation `{relation_name}` of the field `{model_name}.{field_name}` is already used by another relation"
+      ),
+      Error::RelationPartial { relation_name, .. } => {
+        format!("Relation `{relation_name}` has only one edge")
+      }
+      Error::RelationAttributeMissing {
+        field_name,
+        model_name,
+        ..
+      } => format!("Relation field `{model_name}.{field_name}` has no `@relation` attribute"),
+      Error::RelationScalarFieldNotFound {
+        scalar_field_name,
+        field_name,
+        model_name,
+        ..
+      } => match scalar_field_name {
+        Some(scalar_field_name) => format!(
+          "Relation scalar field `{scalar_field_name}` of the relation `{model_name}.{field_name}` is not found"
+        ),
+        None => format!(
+          "Relation scalar field of the relation `{model_name}.{field_name}` is not found"
+        ),
+      },
+      Error::RelationScalarFieldIsNotPrimitive {
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Relation scalar field `{model_name}.{field_name}` should be of a primitive type"
+      ),
+      Error::RelationReferencedFieldNotFound {
+        field_name,
+        model_name,
+        referenced_field_name,
+        referenced_model_name,
+        ..
+      } => format!(
+        "Field `{referenced_model_name}.{referenced_field_name}` referenced by the relation `{model_name}.{field_name}` is not found"
+      ),
+      Error::RelationReferencedFieldNotScalar {
+        field_name,
+        model_name,
+        referenced_field_name,
+        referenced_model_name,
+        ..
+      } => format!(
+        "Field `{referenced_model_name}.{referenced_field_name}` referenced by the relation `{model_name}.{field_name}` is not a scalar field"
+      ),
+      Error::RelationReferencedFieldNotUnique {
+        field_name,
+        model_name,
+        referenced_field_name,
+        referenced_model_name,
+        ..
+      } => format!(
+        "Field `{referenced_model_name}.{referenced_field_name}` referenced by the relation `{model_name}.{field_name}` is neither an ID nor a unique field"
+      ),
+      Error::RelationScalarFieldNotUnique {
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Relation scalar field `{model_name}.{field_name}` of the 1-to-1 relation should be unique"
+      ),
+      Error::RelationScalarFieldIsUnique {
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Relation scalar field `{model_name}.{field_name}` of the 1-to-many relation shouldn't be unique"
+      ),
+      Error::RelationScalarAndReferencedFieldsTypeMismatch {
+        field_name,
+        model_name,
+        referenced_field_name,
+        referenced_model_name,
+        ..
+      } => format!(
+        "Type of the relation scalar field `{model_name}.{field_name}` doesn't match the type of the referenced field `{referenced_model_name}.{referenced_field_name}`"
+      ),
+      Error::RelationInvalidAttribute {
+        attrib_name,
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Attribute `@{attrib_name}` can't be used on the relation field `{model_name}.{field_name}`"
+      ),
+      Error::RelationInvalidAttributeArg { arg_name, .. } => match arg_name {
+        Some(arg_name) => format!("Argument `{arg_name}` of the `@relation` attribute is invalid"),
+        None => "Arguments of the `@relation` attribute are invalid".to_string(),
+      },
+      Error::RelationReferentialActionInvalid {
+        action,
+        field_name,
+        model_name,
+        ..
+      } => format!(
+        "Referential action `{action}` can't be applied to the relation scalar field `{model_name}.{field_name}`"
+      ),
+    }
+  }
+
+  /// Hint to fix the error, if there is one.
+  pub fn hint(&self) -> Option<String> {
+    let hint = match self {
+      Error::ModelIdFieldMissing { .. } => {
+        "mark a field with `@id` attribute, or list the ID fields in `@@id([..])`"
+      }
+      Error::ModelIdFieldDuplicate { .. } => {
+        "keep `@id` on a single field, use `@@id([..])` for a compound ID"
+      }
+      Error::ModelEmpty { .. } => "add a scalar field to the model",
+      Error::TypeDuplicateDefinition { .. } => "rename one of the definitions",
+      Error::TypeUndefined { .. } => {
+        "use a primitive type, or define a model or an enum of this name"
+      }
+      Error::TypeFieldInvalid { .. } => {
+        "use primitive, enum or composite types without attributes in the composite type"
+      }
+      Error::EnumValueUndefined { .. } => "use one of the values listed in the enum",
+      Error::AttributeArgTypeMismatch { .. } => {
+        "use a value of the field's type, or change the type of the field"
+      }
+      Error::AttributeIncompatible { .. } => "remove one of the attributes",
+      Error::AttributeUnknown { .. } => {
+"remove the attribute, or check its spelling"
+      }
+      Error::ModelAttributeUnknown { .. } => {
+"known model attributes are `@@id`, `@@unique` & `@@index`"
+      }
+      Error::RelationPartial { .. } => {
+        "add the relation field with the same relation name to the referenced model"
+      }
+      Error::RelationAttributeMissing { .. } => {
+        "add `@relation(name: \"..\", field: .., references: ..)` to one side of the relation"
+      }
+      Error::RelationReferencedFieldNotUnique { .. } => {
+        "reference a field marked with `@id` or `@unique` attribute"
+      }
+      Error::RelationScalarFieldNotUnique { .. } => {
+        "mark the relation scalar field with `@unique` attribute"
+      }
+      Error::RelationScalarFieldIsUnique { .. } => {
+        "remove `@unique` attribute from the relation scalar field"
+      }
+      Error::RelationScalarAndReferencedFieldsTypeMismatch { .. } => {
+        "use the same type for both the fields"
+      }
+      Error::RelationReferentialActionInvalid { action, .. }
+        if action == ReferentialAction::SetNull.name() =>
+      {
+        "make the relation scalar field optional"
+      }
+      Error::RelationReferentialActionInvalid { .. } => {
+        "add `@default(..)` attribute with a literal value to the relation scalar field"
+      }
+      _ => return None,
+    };
+    Some(hint.to_string())
+  }
 }
 
 impl fmt::Display for Error {
@@ -1,5 +1,6 @@
 use crate::types::{DataModel, Declaration, DeclarationsGrouped};
-use std::collections::{HashMap, HashSet};
+use indexmap::IndexMap;
+use std::collections::HashSet;
 
 mod attribute;
 /// Error Module
@@ -8,18 +9,40 @@ mod relation;
 mod visitor;
 mod visitors;
 
-pub use attribute::ATTRIB_ARG_FN_AUTO;
+pub use attribute::ATTRIB_ARG_FN_ID_GENERATORS;
+pub use attribute::ATTRIB_ARG_VALUE_FALSE;
+pub use attribute::ATTRIB_ARG_VALUE_TRUE;
+pub use attribute::ATTRIB_NAMED_ARG_MAX;
+pub use attribute::ATTRIB_NAMED_ARG_MIN;
 pub use attribute::ATTRIB_NAME_DEFAULT;
+pub use attribute::ATTRIB_NAME_EMAIL;
 pub use attribute::ATTRIB_NAME_ID;
 pub use attribute::ATTRIB_NAME_INDEXED;
+pub use attribute::ATTRIB_NAME_LENGTH;
+pub use attribute::ATTRIB_NAME_MODEL_ID;
+pub use attribute::ATTRIB_NAME_MODEL_INDEX;
+pub use attribute::ATTRIB_NAME_MODEL_UNIQUE;
+pub use attribute::ATTRIB_NAME_PATTERN;
+pub use attribute::ATTRIB_NAME_RANGE;
 pub use attribute::ATTRIB_NAME_UNIQUE;
+pub use attribute::ATTRIB_NAME_UPDATED_AT;
 
 use err::Error;
 use relation::RelationMap;
 
 /// This function performs semantic analysis, converts parsed declarations into `DataModel`
-/// if no errors found. In case errors are found during semantic analyis, it returns the errors.
+/// if no errors found. In case errors are found during semantic analyis, it returns the errors
+/// in the order they are found in the source.
 pub fn semantic_update(declarations: Vec<Declaration>) -> Result<DataModel, Vec<Error>> {
+  semantic_update_unordered(declarations).map_err(|mut errs| {
+    errs.sort_by_key(|err| err.span().start);
+    errs
+  })
+}
+
+fn semantic_update_unordered(
+  declarations: Vec<Declaration>,
+) -> Result<DataModel, Vec<Error>> {
   let declarations = categorise_declarations(declarations)?;
 
   let mut build_visitors =
@@ -30,6 +53,7 @@ pub fn semantic_update(declarations: Vec<Declaration>) -> Result<DataModel, Vec<
 
   let mut validate_visitors = visitor::VisitorNil
     .with(visitors::ValidateModelHasIdField)
+    .with(visitors::ValidateModelAttributes)
     .with(visitors::ValidateFieldAttributes)
     .with(visitors::ValidateFieldAttribute)
     .with(visitors::ValidateAttributeArgs);
@@ -46,9 +70,10 @@ fn categorise_declarations(
   let mut errs: Vec<Error> = Vec::new();
   let mut type_set: HashSet<String> = HashSet::new();
 
-  let mut configs = HashMap::new();
-  let mut enums = HashMap::new();
-  let mut models = HashMap::new();
+  let mut configs = IndexMap::new();
+  let mut enums = IndexMap::new();
+  let mut types = IndexMap::new();
+  let mut models = IndexMap::new();
 
   for decl in declarations.into_iter() {
     let (type_name, span) = match decl {
@@ -64,6 +89,12 @@ fn categorise_declarations(
         enums.insert(type_name.clone(), e);
         (type_name, span)
       }
+      Declaration::Type(t) => {
+        let type_name = t.name.ident_name().unwrap();
+        let span = t.name.span();
+        types.insert(type_name.clone(), t);
+        (type_name, span)
+      }
       Declaration::Model(m) => {
         let type_name = m.name.ident_name().unwrap();
         let span = m.name.span();
@@ -83,6 +114,7 @@ fn categorise_declarations(
     Ok(DeclarationsGrouped {
       configs,
       enums,
+      types,
       models,
     })
   } else {
@@ -92,7 +124,11 @@ fn categorise_declarations(
 
 #[cfg(test)]
 mod tests {
-  use crate::types::Span;
+  use crate::types::{
+    FieldConstraint, ReferentialAction, ReferentialActions, RelationEdge, Span, Token,
+    Type,
+  };
+  use graphql_value::{ConstValue, Name};
 
   use super::*;
   use chumsky::prelude::*;
@@ -133,6 +169,100 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_declaration_order() {
+    let sdml = r#"
+      config db { provider = "foundationDB" }
+      model User {
+        email ShortStr @id
+        role  Role
+        posts Post[] @relation(name: "UserOnPost")
+      }
+      enum Role { USER ADMIN }
+      config cache { provider = "memory" }
+      model Post {
+        postId ShortStr @id
+        author User @relation(name: "UserOnPost", field: authorEmail, references: email)
+        authorEmail ShortStr
+      }
+      enum Area { EAST WEST }
+    "#;
+    let decls = crate::parser::delcarations()
+      .parse(sdml)
+      .into_result()
+      .unwrap();
+    let data_model = semantic_update(decls).unwrap();
+
+    assert_eq!(
+      vec!["db", "cache"],
+      data_model.configs().keys().collect::<Vec<_>>()
+    );
+    assert_eq!(
+      vec!["User", "Post"],
+      data_model.models().keys().collect::<Vec<_>>()
+    );
+    assert_eq!(
+      vec!["Role", "Area"],
+      data_model.enums().keys().collect::<Vec<_>>()
+    );
+    let names = |names: Vec<&Token>| {
+      names
+        .into_iter()
+        .map(|name| name.ident_name().unwrap())
+        .collect::<Vec<_>>()
+    };
+    let configs = data_model.configs_sorted();
+    assert_eq!(
+      vec!["cache", "db"],
+      names(configs.iter().map(|c| &c.name).collect())
+    );
+    let models = data_model.models_sorted();
+    assert_eq!(
+      vec!["Post", "User"],
+      names(models.iter().map(|m| &m.name).collect())
+    );
+    let enums = data_model.enums_sorted();
+    assert_eq!(
+      vec!["Area", "Role"],
+      names(enums.iter().map(|e| &e.name).collect())
+    );
+    let decls = data_model.declarations();
+    assert_eq!(
+      vec!["db", "User", "Role", "cache", "Post", "Area"],
+      names(decls.iter().map(Declaration::name).collect())
+    );
+  }
+
+  #[test]
+  fn test_errs_order() {
+    let sdml = r#"
+      model User {
+        email ShortStr @id
+        role  Rol
+      }
+      enum Role { USER ADMIN }
+      model Post {
+        postId ShortStr @id
+        title  Text
+      }
+    "#;
+    let decls = crate::parser::delcarations()
+      .parse(sdml)
+      .into_result()
+      .unwrap();
+    let errs = semantic_update(decls).unwrap_err();
+    assert_eq!(
+      vec!["Rol", "Text"],
+      errs
+        .iter()
+        .map(|err| match err {
+          Error::TypeUndefined { type_name, .. } => type_name.as_str(),
+          _ => panic!("Unexpected error {err:?}"),
+        })
+        .collect::<Vec<_>>()
+    );
+  }
+
   #[test]
   fn test_model_errs() {
     let model_errs_sdml = std::fs::read_to_string(concat!(
@@ -582,6 +712,185 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_relation_referential_actions() {
+    let relation_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/relation_errs/referential_actions_valid.sdml"
+    ))
+    .unwrap();
+    let decls = crate::parser::delcarations()
+      .parse(&relation_sdml)
+      .into_result()
+      .unwrap();
+    let data_model = semantic_update(decls).unwrap();
+    // Note: Referential actions are on the edge having the relation scalar field.
+    let referential_actions = |relation_name: &str| {
+      let (left, right) = &data_model.relations[relation_name];
+      [Some(left), right.as_ref()]
+        .into_iter()
+        .flatten()
+        .find(|edge| edge.scalar_field_name().is_some())
+        .unwrap()
+        .referential_actions()
+    };
+    assert_eq!(
+      referential_actions("user_posts"),
+      ReferentialActions {
+        on_delete: Some(ReferentialAction::Cascade),
+        on_update: None,
+      }
+    );
+    assert_eq!(
+      referential_actions("user_reviews"),
+      ReferentialActions {
+        on_delete: Some(ReferentialAction::SetDefault),
+        on_update: Some(ReferentialAction::Restrict),
+      }
+    );
+    assert_eq!(
+      referential_actions("mentor_mentee"),
+      ReferentialActions {
+        on_delete: Some(ReferentialAction::SetNull),
+        on_update: Some(ReferentialAction::Cascade),
+      }
+    );
+  }
+
+  #[test]
+  fn test_relation_errs_referential_actions() {
+    let relation_errs_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/relation_errs/referential_actions.sdml"
+    ))
+    .unwrap();
+    let invalid_arg = |span: (usize, usize),
+                       arg_name: Option<&str>,
+                       field_name: &str,
+                       model_name: &str| {
+      Error::RelationInvalidAttributeArg {
+        span: Span::new(span.0, span.1),
+        relation_name: None,
+        arg_name: arg_name.map(str::to_string),
+        field_name: Some(field_name.to_string()),
+        model_name: Some(model_name.to_string()),
+      }
+    };
+    let partial = |span: (usize, usize), relation_name: &str| Error::RelationPartial {
+      span: Span::new(span.0, span.1),
+      relation_name: relation_name.to_string(),
+      field_name: None,
+      model_name: None,
+    };
+    let action_invalid = |span: (usize, usize), action: &str, field_name: &str| {
+      Error::RelationReferentialActionInvalid {
+        span: Span::new(span.0, span.1),
+        action: action.to_string(),
+        field_name: field_name.to_string(),
+        model_name: "Post".to_string(),
+      }
+    };
+    let expected_semantic_errs: Vec<Error> = vec![
+      // Referential action without the relation scalar field.
+      invalid_arg((110, 126), None, "posts", "User"),
+      partial((233, 246), "user_drafts"),
+      partial((295, 309), "user_reviews"),
+      partial((358, 370), "user_edits"),
+      // Unknown referential action.
+      invalid_arg((579, 585), Some("onDelete"), "author", "Post"),
+      action_invalid((727, 734), "SetNull", "drafterId"),
+      action_invalid((878, 888), "SetDefault", "reviewerId"),
+      // Duplicate referential action.
+      invalid_arg((1037, 1045), Some("onDelete"), "editor", "Post"),
+      // Default value of the relation scalar field is generated.
+      Error::RelationReferentialActionInvalid {
+        span: Span::new(1297, 1307),
+        action: "SetDefault".to_string(),
+        field_name: "parentId".to_string(),
+        model_name: "Category".to_string(),
+      },
+      partial((1357, 1374), "category_parent"),
+    ];
+
+    let decls = crate::parser::delcarations()
+      .parse(&relation_errs_sdml)
+      .into_result()
+      .unwrap();
+    let errs = semantic_update(decls).unwrap_err();
+    assert_eq!(errs, expected_semantic_errs);
+  }
+
+  #[test]
+  fn test_relation_implicit_many_to_many() {
+    let relation_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/relation_errs/implicit_many_to_many_valid.sdml"
+    ))
+    .unwrap();
+    let decls = crate::parser::delcarations()
+      .parse(&relation_sdml)
+      .into_result()
+      .unwrap();
+    let data_model = semantic_update(decls).unwrap();
+    let referenced_model_names = |relation_name: &str| {
+      let (left, right) = &data_model.relations[relation_name];
+      [Some(left), right.as_ref()]
+        .into_iter()
+        .map(|edge| match edge {
+          Some(RelationEdge::ImplicitManyToManyRelation {
+            referenced_model_name,
+            ..
+          }) => referenced_model_name.ident_name().unwrap(),
+          edge => panic!("Expected implicit many-to-many edge, found {edge:?}"),
+        })
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(referenced_model_names("user_follows"), vec!["User", "User"]);
+    assert_eq!(
+      referenced_model_names("post_categories"),
+      vec!["Category", "Post"]
+    );
+  }
+
+  #[test]
+  fn test_relation_errs_implicit_many_to_many() {
+    let relation_errs_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/relation_errs/implicit_many_to_many.sdml"
+    ))
+    .unwrap();
+    let expected_semantic_errs: Vec<Error> = vec![
+      // Both sides of the many-to-many relation should have the relation scalar fields.
+      Error::RelationInvalid {
+        span: Span::new(174, 185),
+        relation_name: "post_tags".to_string(),
+        field_name: None,
+        model_name: None,
+      },
+      // Referential action without the relation scalar field.
+      Error::RelationInvalidAttributeArg {
+        span: Span::new(225, 241),
+        relation_name: None,
+        arg_name: None,
+        field_name: Some("categories".to_string()),
+        model_name: Some("Post".to_string()),
+      },
+      Error::RelationPartial {
+        span: Span::new(557, 574),
+        relation_name: "post_categories".to_string(),
+        field_name: None,
+        model_name: None,
+      },
+    ];
+
+    let decls = crate::parser::delcarations()
+      .parse(&relation_errs_sdml)
+      .into_result()
+      .unwrap();
+    let errs = semantic_update(decls).unwrap_err();
+    assert_eq!(errs, expected_semantic_errs);
+  }
+
   #[test]
   fn test_indexed_attribute_valid_usage() {
     let indexed_attribute_valid_usage_sdml = std::fs::read_to_string(concat!(
@@ -667,4 +976,526 @@ mod tests {
       }
     }
   }
+
+  #[test]
+  fn test_default_attribute_valid_usage() {
+    let default_attribute_valid_usage_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/default_attribute/default_attribute_valid_usage.sdml"
+    ))
+    .unwrap();
+
+    let decls = crate::parser::delcarations()
+      .parse(&default_attribute_valid_usage_sdml)
+      .into_result()
+      .unwrap();
+    let data_model = semantic_update(decls).unwrap();
+    let user = &data_model.models()["User"];
+    let default_values = user
+      .fields
+      .iter()
+      .map(|field| (field.name.ident_name().unwrap(), field.default_value()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      default_values,
+      vec![
+        ("email".to_string(), None),
+        (
+          "locale".to_string(),
+          Some(ConstValue::String("en".to_string()))
+        ),
+        (
+          "bio".to_string(),
+          Some(ConstValue::String("Hello \"World\"".to_string()))
+        ),
+        ("retries".to_string(), Some(ConstValue::Number((-3).into()))),
+        ("visits".to_string(), Some(ConstValue::Number(16.into()))),
+        ("score".to_string(), Some(ConstValue::from(1.0))),
+        ("ratio".to_string(), Some(ConstValue::from(0.25))),
+        ("verified".to_string(), Some(ConstValue::Boolean(false))),
+        (
+          "role".to_string(),
+          Some(ConstValue::Enum(Name::new("USER")))
+        ),
+        ("createdAt".to_string(), None),
+        (
+          "balance".to_string(),
+          Some(ConstValue::String("10.25".to_string()))
+        ),
+        (
+          "birthday".to_string(),
+          Some(ConstValue::String("2000-01-01".to_string()))
+        ),
+      ]
+    );
+    let session = &data_model.models()["Session"];
+    assert_eq!(
+      session.get_fields().id,
+      vec![(&session.fields[0], true)],
+      "ID generated by `uuid(7)` should be auto generated."
+    );
+    assert!(session
+      .fields
+      .iter()
+      .all(|field| field.default_value().is_none()));
+  }
+
+  #[test]
+  fn test_default_attribute_invalid_usage() {
+    let default_attribute_invalid_usage_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/default_attribute/default_attribute_invalid_usage.sdml"
+    ))
+    .unwrap();
+    let mismatch = |span: (usize, usize), field_type: &str, field_name: &str| {
+      Error::AttributeArgTypeMismatch {
+        span: Span::new(span.0, span.1),
+        field_type: field_type.to_string(),
+        attrib_name: "default".to_string(),
+        field_name: field_name.to_string(),
+        model_name: "User".to_string(),
+      }
+    };
+    let invalid = |span: (usize, usize), attrib_arg: &str, field_name: &str| {
+      Error::AttributeArgInvalid {
+        span: Span::new(span.0, span.1),
+        attrib_arg_name: Some(attrib_arg.to_string()),
+        attrib_name: "default".to_string(),
+        field_name: field_name.to_string(),
+        model_name: "User".to_string(),
+      }
+    };
+    let expected_semantic_errs: Vec<Error> = vec![
+      mismatch((74, 75), "ShortStr", "locale"),
+      mismatch((110, 113), "Int32", "retries"),
+      mismatch((148, 158), "Int32", "visits"),
+      mismatch((193, 196), "Int64", "ratio"),
+      mismatch((231, 232), "Boolean", "verified"),
+      mismatch((267, 271), "ShortStr", "name"),
+      mismatch((306, 312), "Role", "role"),
+      invalid((347, 354), "uuid(6)", "token"),
+      invalid((389, 398), "nanoid(1)", "code"),
+      invalid((433, 440), "ulid(2)", "ulid"),
+      mismatch((475, 481), "Int32", "count"),
+      mismatch((516, 521), "ShortStr", "since"),
+      mismatch((556, 566), "Float64", "serial"),
+    ];
+
+    let decls = crate::parser::delcarations()
+      .parse(&default_attribute_invalid_usage_sdml)
+      .into_result()
+      .unwrap();
+    let errs = semantic_update(decls).unwrap_err();
+    assert_eq!(errs, expected_semantic_errs);
+  }
+
+  #[test]
+  fn test_updated_at_attribute_valid_usage() {
+    let updated_at_attribute_valid_usage_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/updated_at_attribute/updated_at_attribute_valid_usage.sdml"
+    ))
+    .unwrap();
+
+    let decls = crate::parser::delcarations()
+      .parse(&updated_at_attribute_valid_usage_sdml)
+      .into_result()
+      .unwrap();
+    if let Err(errs) = semantic_update(decls) {
+      panic!(
+        "There shouldn't any scemantic errors instead {:?} thrown",
+        errs
+      )
+    }
+  }
+
+  #[test]
+  fn test_updated_at_attribute_invalid_usage() {
+    let updated_at_attribute_invalid_usage_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/updated_at_attribute/updated_at_attribute_invalid_usage.sdml"
+    ))
+    .unwrap();
+    let invalid = |span: (usize, usize), field_name: &str| Error::AttributeInvalid {
+      span: Span::new(span.0, span.1),
+      reason: "Only Optional Scalar DateTime field is allowed".to_string(),
+      attrib_name: "updatedAt".to_string(),
+      field_name: field_name.to_string(),
+      model_name: "Post".to_string(),
+    };
+    let expected_semantic_errs: Vec<Error> = vec![
+      invalid((90, 100), "title"),
+      invalid((129, 139), "editedAt"),
+      Error::AttributeArgInvalid {
+        span: Span::new(168, 185),
+        attrib_arg_name: None,
+        attrib_name: "updatedAt".to_string(),
+        field_name: "updatedAt".to_string(),
+        model_name: "Post".to_string(),
+      },
+      Error::AttributeIncompatible {
+        span: Span::new(230, 240),
+        attrib_name: "updatedAt".to_string(),
+        first_attrib_name: "default".to_string(),
+        field_name: "createdAt".to_string(),
+        model_name: "Post".to_string(),
+      },
+    ];
+
+    let decls = crate::parser::delcarations()
+      .parse(&updated_at_attribute_invalid_usage_sdml)
+      .into_result()
+      .unwrap();
+    let errs = semantic_update(decls).unwrap_err();
+    assert_eq!(errs, expected_semantic_errs);
+  }
+
+  #[test]
+  fn test_validation_attribute_valid_usage() {
+    let validation_attribute_valid_usage_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/validation_attribute/validation_attribute_valid_usage.sdml"
+    ))
+    .unwrap();
+
+    let decls = crate::parser::delcarations()
+      .parse(&validation_attribute_valid_usage_sdml)
+      .into_result()
+      .unwrap();
+    let data_model = semantic_update(decls).unwrap();
+    let user = &data_model.models()["User"];
+    assert_eq!(
+      user.field_by_name("email").unwrap().constraints(),
+      vec![
+        FieldConstraint::Email,
+        FieldConstraint::Length {
+          min: None,
+          max: Some(254)
+        }
+      ]
+    );
+    assert_eq!(
+      user.field_by_name("score").unwrap().constraints(),
+      vec![FieldConstraint::Range {
+        min: Some(-1.5),
+        max: Some(1.5)
+      }]
+    );
+    assert_eq!(
+      user.field_by_name("handle").unwrap().constraints(),
+      vec![FieldConstraint::Pattern("^[a-z][a-z0-9_]*$".to_string())]
+    );
+  }
+
+  #[test]
+  fn test_validation_attribute_invalid_usage() {
+    let validation_attribute_invalid_usage_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/validation_attribute/validation_attribute_invalid_usage.sdml"
+    ))
+    .unwrap();
+    let invalid =
+      |span: (usize, usize), reason: &str, attrib_name: &str, field_name: &str| {
+        Error::AttributeInvalid {
+          span: Span::new(span.0, span.1),
+          reason: reason.to_string(),
+          attrib_name: attrib_name.to_string(),
+          field_name: field_name.to_string(),
+          model_name: "User".to_string(),
+        }
+      };
+    let arg_invalid = |span: (usize, usize),
+                       attrib_arg_name: Option<&str>,
+                       attrib_name: &str,
+                       field_name: &str| {
+      Error::AttributeArgInvalid {
+        span: Span::new(span.0, span.1),
+        attrib_arg_name: attrib_arg_name.map(ToString::to_string),
+        attrib_name: attrib_name.to_string(),
+        field_name: field_name.to_string(),
+        model_name: "User".to_string(),
+      }
+    };
+    let type_mismatch =
+      |span: (usize, usize), field_type: &str, attrib_name: &str, field_name: &str| {
+        Error::AttributeArgTypeMismatch {
+          span: Span::new(span.0, span.1),
+          field_type: field_type.to_string(),
+          attrib_name: attrib_name.to_string(),
+          field_name: field_name.to_string(),
+          model_name: "User".to_string(),
+        }
+      };
+    let expected_semantic_errs: Vec<Error> = vec![
+      invalid(
+        (90, 105),
+        "Only Optional String field is allowed",
+        "length",
+        "age",
+      ),
+      invalid(
+        (134, 148),
+        "Only Optional Number field is allowed",
+        "range",
+        "email",
+      ),
+      type_mismatch((146, 147), "ShortStr", "range", "email"),
+      arg_invalid((177, 184), None, "length", "name"),
+      arg_invalid((226, 228), Some("min"), "length", "nick"),
+      arg_invalid((280, 281), Some("max"), "length", "bio"),
+      type_mismatch((323, 326), "Int32", "range", "score"),
+      type_mismatch((368, 378), "Int32", "range", "rank"),
+      arg_invalid((417, 423), Some("\"[a-z\""), "pattern", "handle"),
+      type_mismatch((462, 464), "ShortStr", "pattern", "code"),
+    ];
+
+    let decls = crate::parser::delcarations()
+      .parse(&validation_attribute_invalid_usage_sdml)
+      .into_result()
+      .unwrap();
+    let errs = semantic_update(decls).unwrap_err();
+    assert_eq!(errs, expected_semantic_errs);
+  }
+
+  #[test]
+  fn test_composite_type_valid_usage() {
+    let composite_type_valid_usage_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/composite_type/composite_type_valid_usage.sdml"
+    ))
+    .unwrap();
+
+    let decls = crate::parser::delcarations()
+      .parse(&composite_type_valid_usage_sdml)
+      .into_result()
+      .unwrap();
+    let data_model = semantic_update(decls).unwrap();
+    assert_eq!(
+      vec!["Address", "GeoPoint"],
+      data_model.types().keys().collect::<Vec<_>>()
+    );
+    let address = &data_model.types()["Address"];
+    assert!(matches!(
+      address
+        .field_by_name("country")
+        .unwrap()
+        .field_type
+        .r#type(),
+      Type::Enum { .. }
+    ));
+    assert!(matches!(
+      address.field_by_name("geo").unwrap().field_type.r#type(),
+      Type::Composite { composite_ty_name } if composite_ty_name.ident_name().unwrap() == "GeoPoint"
+    ));
+    let user = &data_model.models()["User"];
+    for field_name in ["address", "billingAddress", "pastAddresses"] {
+      assert!(matches!(
+        user.field_by_name(field_name).unwrap().field_type.r#type(),
+        Type::Composite { composite_ty_name } if composite_ty_name.ident_name().unwrap() == "Address"
+      ));
+    }
+  }
+
+  #[test]
+  fn test_composite_type_invalid_usage() {
+    let composite_type_invalid_usage_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/composite_type/composite_type_invalid_usage.sdml"
+    ))
+    .unwrap();
+    let invalid =
+      |span: (usize, usize), reason: &str, field_name: &str| Error::TypeFieldInvalid {
+        span: Span::new(span.0, span.1),
+        reason: reason.to_string(),
+        field_name: field_name.to_string(),
+        type_name: "Address".to_string(),
+      };
+    let expected_semantic_errs: Vec<Error> = vec![
+      invalid(
+        (39, 46),
+        "Attributes aren't allowed on the composite type fields",
+        "street",
+      ),
+      invalid(
+        (59, 63),
+        "Relations aren't allowed in the composite types",
+        "owner",
+      ),
+      Error::TypeUndefined {
+        span: Span::new(76, 83),
+        type_name: "ZipCode".to_string(),
+        field_name: "zip".to_string(),
+        model_name: "Address".to_string(),
+      },
+      invalid(
+        (96, 103),
+        "Composite type can embed itself only through an optional or a list field",
+        "parent",
+      ),
+    ];
+
+    let decls = crate::parser::delcarations()
+      .parse(&composite_type_invalid_usage_sdml)
+      .into_result()
+      .unwrap();
+    let errs = semantic_update(decls).unwrap_err();
+    assert_eq!(errs, expected_semantic_errs);
+
+    // Note: Attributes of the composite type field are validated, once the types are valid.
+    let decls = crate::parser::delcarations()
+      .parse(
+        r#"
+        type Address { street ShortStr }
+        model User {
+          email    ShortStr  @id
+          address  Address   @unique
+        }
+        "#,
+      )
+      .into_result()
+      .unwrap();
+    let errs = semantic_update(decls).unwrap_err();
+    assert_eq!(
+      errs,
+      vec![Error::AttributeInvalid {
+        span: Span::new(125, 132),
+        reason: "Attributes aren't allowed on the composite type field".to_string(),
+        attrib_name: "unique".to_string(),
+        field_name: "address".to_string(),
+        model_name: "User".to_string(),
+      }]
+    );
+  }
+
+  #[test]
+  fn test_model_attribute_valid_usage() {
+    let model_attribute_valid_usage_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/model_attribute/model_attribute_valid_usage.sdml"
+    ))
+    .unwrap();
+
+    let decls = crate::parser::delcarations()
+      .parse(&model_attribute_valid_usage_sdml)
+      .into_result()
+      .unwrap();
+    let data_model = semantic_update(decls).unwrap();
+    let user = &data_model.models()["User"];
+    assert_eq!(
+      user
+        .compound_uniques()
+        .map(|attrib| attrib.compound_name())
+        .collect::<Vec<_>>(),
+      vec!["tenantId_email"]
+    );
+    assert_eq!(
+      user
+        .compound_indexes()
+        .map(|attrib| attrib.compound_name())
+        .collect::<Vec<_>>(),
+      vec!["tenantId_role"]
+    );
+    let membership = &data_model.models()["Membership"];
+    assert_eq!(
+      membership.compound_id().map(|attrib| attrib.field_names()),
+      Some(vec!["userId".to_string(), "groupName".to_string()])
+    );
+    assert_eq!(membership.get_fields().id.len(), 2);
+  }
+
+  #[test]
+  fn test_model_attribute_invalid_usage() {
+    let model_attribute_invalid_usage_sdml = std::fs::read_to_string(concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/test_data/semantic_analysis/model_attribute/model_attribute_invalid_usage.sdml"
+    ))
+    .unwrap();
+    let expected_semantic_errs: Vec<Error> = vec![
+      Error::ModelAttributeInvalid {
+        span: Span::new(199, 205),
+        reason: "At least two fields should be listed, use the field attribute for a single field".to_string(),
+        attrib_name: "unique".to_string(),
+        model_name: "User".to_string(),
+      },
+      Error::ModelAttributeFieldInvalid {
+        span: Span::new(242, 247),
+        reason: "Field is not found in the model".to_string(),
+        attrib_name: "unique".to_string(),
+        field_name: "phone".to_string(),
+        model_name: "User".to_string(),
+      },
+      Error::ModelAttributeFieldInvalid {
+        span: Span::new(274, 282),
+        reason: "Field is listed more than once".to_string(),
+        attrib_name: "unique".to_string(),
+        field_name: "tenantId".to_string(),
+        model_name: "User".to_string(),
+      },
+      Error::ModelAttributeFieldInvalid {
+        span: Span::new(308, 317),
+        reason: "Only Scalar field is allowed".to_string(),
+        attrib_name: "index".to_string(),
+        field_name: "nickNames".to_string(),
+        model_name: "User".to_string(),
+      },
+      Error::ModelAttributeInvalid {
+        span: Span::new(358, 363),
+        reason: "Compound key tenantId_email is declared more than once".to_string(),
+        attrib_name: "index".to_string(),
+        model_name: "User".to_string(),
+      },
+      Error::ModelAttributeInvalid {
+        span: Span::new(389, 395),
+        reason: "Compound name tenantId_name clashes with the field of the same name".to_string(),
+        attrib_name: "unique".to_string(),
+        model_name: "User".to_string(),
+      },
+      Error::ModelAttributeUnknown {
+        span: Span::new(420, 427),
+        attrib_name: "primary".to_string(),
+        model_name: "User".to_string(),
+      },
+      Error::ModelAttributeInvalid {
+        span: Span::new(453, 455),
+        reason: "Model already has a field with @id attribute".to_string(),
+        attrib_name: "id".to_string(),
+        model_name: "User".to_string(),
+      },
+      Error::ModelAttributeInvalid {
+        span: Span::new(575, 577),
+        reason: "Field id is reserved for the compound ID".to_string(),
+        attrib_name: "id".to_string(),
+        model_name: "Membership".to_string(),
+      },
+      Error::ModelAttributeFieldInvalid {
+        span: Span::new(712, 716),
+        reason: "Only Non-Optional field is allowed in the compound ID".to_string(),
+        attrib_name: "id".to_string(),
+        field_name: "name".to_string(),
+        model_name: "Group".to_string(),
+      },
+      Error::ModelAttributeInvalid {
+        span: Span::new(725, 727),
+        reason: "Model can have only one compound ID".to_string(),
+        attrib_name: "id".to_string(),
+        model_name: "Group".to_string(),
+      },
+    ];
+
+    let decls = crate::parser::delcarations()
+      .parse(&model_attribute_invalid_usage_sdml)
+      .into_result()
+      .unwrap();
+    let errs =
+      semantic_update(decls).expect_err("Expecting semantic errors to get surfaced.");
+    eprintln!("{:#?}", errs);
+    assert_eq!(expected_semantic_errs.len(), errs.len());
+    errs.iter().for_each(|err| {
+      assert!(
+        expected_semantic_errs.contains(err),
+        "{} is an unexpected semantic error",
+        err
+      )
+    });
+  }
 }
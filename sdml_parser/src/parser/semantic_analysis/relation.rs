@@ -1,12 +1,14 @@
-use std::collections::HashMap;
+use indexmap::IndexMap;
 
-use crate::types::{RelationEdge, Token};
+use crate::types::{
+  FieldDecl, ModelDecl, NamedArg, ReferentialAction, RelationEdge, Token,
+};
 
 use super::err::Error;
 
 #[derive(Debug, Default)]
 pub(crate) struct RelationMap {
-  relations: HashMap<String, (Option<RelationEdge>, Option<RelationEdge>)>,
+  relations: IndexMap<String, (Option<RelationEdge>, Option<RelationEdge>)>,
 }
 
 impl RelationMap {
@@ -15,8 +17,8 @@ impl RelationMap {
   /// then error is returned.
   pub fn get_valid_relations(
     self,
-  ) -> Result<HashMap<String, (RelationEdge, Option<RelationEdge>)>, Vec<Error>> {
-    let mut valid_relations = HashMap::new();
+  ) -> Result<IndexMap<String, (RelationEdge, Option<RelationEdge>)>, Vec<Error>> {
+    let mut valid_relations = IndexMap::new();
     let mut errs = Vec::new();
     self.relations.into_iter().for_each(|(key, (left, right))| {
       RelationMap::is_relation_valid(left.as_ref(), right.as_ref()).map_or_else(
@@ -109,6 +111,21 @@ impl RelationMap {
         field_name: None,
         model_name: None,
       }),
+      (
+        Some(RelationEdge::ImplicitManyToManyRelation { .. }),
+        Some(RelationEdge::ImplicitManyToManyRelation { .. }),
+      ) => Ok(()),
+      (Some(RelationEdge::ImplicitManyToManyRelation { .. }), _)
+      | (_, Some(RelationEdge::ImplicitManyToManyRelation { .. })) => {
+        // Both sides of the many-to-many relation should either have
+        // the relation scalar fields or not.
+        Err(Error::RelationInvalid {
+          span: right.unwrap().relation_name().span(),
+          relation_name: right.unwrap().relation_name().str().unwrap(),
+          field_name: None,
+          model_name: None,
+        })
+      }
       (Some(RelationEdge::OneSideRelationRight { .. }), _)
       | (
         Some(RelationEdge::ManySideRelation { .. }),
@@ -133,3 +150,44 @@ impl RelationMap {
     }
   }
 }
+
+/// Returns the referential action (`onDelete` / `onUpdate`) of the relation attribute,
+/// if it can be applied to the relation scalar field.
+/// Ex. `SetNull` requires an optional relation scalar field, and `SetDefault` requires
+/// a relation scalar field with a literal `@default(..)` value, as the generated
+/// values (Ex. `uuid()`) can never reference an existing object.
+pub(crate) fn get_referential_action(
+  action_arg: &NamedArg,
+  relation_scalar_field: &FieldDecl,
+  field: &FieldDecl,
+  model: &ModelDecl,
+) -> Result<ReferentialAction, Error> {
+  let action = match &action_arg.arg_value {
+    Token::Ident(action_name, _) => ReferentialAction::from_name(action_name),
+    _ => None,
+  }
+  .ok_or_else(|| Error::RelationInvalidAttributeArg {
+    span: action_arg.arg_value.span(),
+    relation_name: None,
+    arg_name: action_arg.arg_name.ident_name(),
+    field_name: field.name.ident_name(),
+    model_name: model.name.ident_name(),
+  })?;
+  let is_applicable = match action {
+    ReferentialAction::SetNull => relation_scalar_field.field_type.is_optional(),
+    ReferentialAction::SetDefault => relation_scalar_field.default_value().is_some(),
+    ReferentialAction::Cascade
+    | ReferentialAction::Restrict
+    | ReferentialAction::NoAction => true,
+  };
+  if is_applicable {
+    Ok(action)
+  } else {
+    Err(Error::RelationReferentialActionInvalid {
+      span: action_arg.arg_value.span(),
+      action: action.to_string(),
+      field_name: relation_scalar_field.name.ident_name().unwrap(),
+      model_name: model.name.ident_name().unwrap(),
+    })
+  }
+}
@@ -3,11 +3,13 @@
 
 use std::collections::HashMap;
 
+use indexmap::IndexMap;
+
 use crate::{
   parser::semantic_analysis::{attribute::AttributeDetails, err::Error, RelationMap},
   types::{
     Attribute, ConfigDecl, DataModel, DeclarationsGrouped, EnumDecl, FieldDecl,
-    ModelDecl, Type,
+    ModelDecl, Type, TypeDecl,
   },
 };
 
@@ -53,22 +55,29 @@ impl<'a> VisitorContext<'a> {
     &self.mode
   }
 
-  pub fn input_models(&self) -> &'a HashMap<String, ModelDecl> {
+  pub fn input_models(&self) -> &'a IndexMap<String, ModelDecl> {
     match self.mode {
       VisitorMode::Build(declarations) => &declarations.models,
       VisitorMode::Validate(data_model) => &data_model.models,
     }
   }
 
-  pub fn input_enums(&self) -> &'a HashMap<String, EnumDecl> {
+  pub fn input_enums(&self) -> &'a IndexMap<String, EnumDecl> {
     match self.mode {
       VisitorMode::Build(declarations) => &declarations.enums,
       VisitorMode::Validate(data_model) => &data_model.enums,
     }
   }
 
+  pub fn input_types(&self) -> &'a IndexMap<String, TypeDecl> {
+    match self.mode {
+      VisitorMode::Build(declarations) => &declarations.types,
+      VisitorMode::Validate(data_model) => &data_model.types,
+    }
+  }
+
   #[allow(dead_code)]
-  pub fn input_configs(&self) -> &'a HashMap<String, ConfigDecl> {
+  pub fn input_configs(&self) -> &'a IndexMap<String, ConfigDecl> {
     match self.mode {
       VisitorMode::Build(declarations) => &declarations.configs,
       VisitorMode::Validate(data_model) => &data_model.configs,
@@ -110,6 +119,31 @@ impl<'a> VisitorContext<'a> {
     field_to_update.field_type.set_type(actual_type);
   }
 
+  /// Update the field type of the given field of the composite type.
+  /// ### Note:
+  /// Similar to `update_current_field_type`, the update happens in the `updated_data_model`.
+  pub fn update_type_field_type(
+    &mut self,
+    type_decl: &TypeDecl,
+    field: &FieldDecl,
+    actual_type: Type,
+  ) {
+    let field_to_update = self
+      .built_data_model
+      .types
+      .get_mut(&type_decl.name.ident_name().unwrap())
+      .and_then(|type_to_update| {
+        type_to_update.field_by_name_mut(&field.name.ident_name().unwrap())
+      })
+      .expect("Field should present inside the composite type");
+
+    debug_assert!(
+      matches!(field_to_update.field_type.r#type(), Type::Unknown(..)),
+      "Only fields with unknown types should be updated!"
+    );
+    field_to_update.field_type.set_type(actual_type);
+  }
+
   /// Updates the relations in `updated_data_model`.
   pub fn update_relation_map(&mut self, relations: RelationMap) {
     let _ = relations
@@ -232,6 +266,9 @@ pub trait Visitor<'a> {
   fn enter_enum(&mut self, _ctx: &mut VisitorContext<'a>, _enum: &'a EnumDecl) {}
   fn exit_enum(&mut self, _ctx: &mut VisitorContext<'a>, _enum: &'a EnumDecl) {}
 
+  fn enter_type(&mut self, _ctx: &mut VisitorContext<'a>, _type: &'a TypeDecl) {}
+  fn exit_type(&mut self, _ctx: &mut VisitorContext<'a>, _type: &'a TypeDecl) {}
+
   fn enter_model(&mut self, _ctx: &mut VisitorContext<'a>, _model: &'a ModelDecl) {}
   fn exit_model(&mut self, _ctx: &mut VisitorContext<'a>, _model: &'a ModelDecl) {}
 
@@ -319,6 +356,15 @@ where
     self.1.exit_enum(ctx, r#enum);
   }
 
+  fn enter_type(&mut self, ctx: &mut VisitorContext<'a>, r#type: &'a TypeDecl) {
+    self.0.enter_type(ctx, r#type);
+    self.1.enter_type(ctx, r#type);
+  }
+  fn exit_type(&mut self, ctx: &mut VisitorContext<'a>, r#type: &'a TypeDecl) {
+    self.0.exit_type(ctx, r#type);
+    self.1.exit_type(ctx, r#type);
+  }
+
   fn enter_model(&mut self, ctx: &mut VisitorContext<'a>, model: &'a ModelDecl) {
     self.0.enter_model(ctx, model);
     self.1.enter_model(ctx, model);
@@ -366,6 +412,11 @@ pub fn build_data_model<'a, V: Visitor<'a>>(
     v.enter_enum(&mut ctx, r#enum);
     v.exit_enum(&mut ctx, r#enum);
   });
+  // composite types
+  declarations.types.values().for_each(|r#type| {
+    v.enter_type(&mut ctx, r#type);
+    v.exit_type(&mut ctx, r#type);
+  });
 
   // Models
   declarations.models.values().for_each(|model| {
@@ -402,6 +453,11 @@ pub fn validate_data_model<'a, V: Visitor<'a>>(
     v.enter_enum(&mut ctx, r#enum);
     v.exit_enum(&mut ctx, r#enum);
   });
+  // composite types
+  data_model.types.values().for_each(|r#type| {
+    v.enter_type(&mut ctx, r#type);
+    v.exit_type(&mut ctx, r#type);
+  });
 
   // Models
   data_model.models.values().for_each(|model| {
@@ -5,10 +5,12 @@ mod update_unknown_fields;
 mod validate_attribute_args;
 mod validate_field_attribute;
 mod validate_field_attributes;
+mod validate_model_attributes;
 mod validate_model_has_id_field;
 
 pub use update_unknown_fields::UpdateUnknownFields;
 pub use validate_attribute_args::ValidateAttributeArgs;
 pub use validate_field_attribute::ValidateFieldAttribute;
 pub use validate_field_attributes::ValidateFieldAttributes;
+pub use validate_model_attributes::ValidateModelAttributes;
 pub use validate_model_has_id_field::ValidateModelHasIdField;
@@ -3,23 +3,25 @@ use std::collections::HashMap;
 use crate::{
   parser::semantic_analysis::{
     attribute::{
-      ATTRIB_NAMED_ARG_FIELD, ATTRIB_NAMED_ARG_NAME, ATTRIB_NAMED_ARG_REFERENCES,
-      ATTRIB_NAME_RELATION,
+      ATTRIB_NAMED_ARG_FIELD, ATTRIB_NAMED_ARG_NAME, ATTRIB_NAMED_ARG_ON_DELETE,
+      ATTRIB_NAMED_ARG_ON_UPDATE, ATTRIB_NAMED_ARG_REFERENCES, ATTRIB_NAME_RELATION,
     },
     err::Error,
+    relation::get_referential_action,
     visitor::VisitorMode,
     RelationMap, ATTRIB_NAME_ID, ATTRIB_NAME_UNIQUE,
   },
   types::{
-    AttribArg, DeclarationsGrouped, FieldDecl, ModelDecl, NamedArg, RelationEdge, Token,
-    Type,
+    AttribArg, DeclarationsGrouped, FieldDecl, ModelDecl, NamedArg, ReferentialActions,
+    RelationEdge, Token, Type, TypeDecl,
   },
 };
 
 use super::*;
 
 /// Visitor to update the all the model fielde of type Type::Unknown(..).
-/// This visitor also takes care of capturing all the relations in the declarations.
+/// This visitor also takes care of capturing all the relations in the declarations,
+/// and resolving the fields of the composite types.
 #[derive(Debug, Default)]
 pub struct UpdateUnknownFields {
   relation_map: Option<RelationMap>,
@@ -41,6 +43,17 @@ impl<'a> Visitor<'a> for UpdateUnknownFields {
       });
   }
 
+  fn enter_type(&mut self, ctx: &mut VisitorContext<'a>, r#type: &'a TypeDecl) {
+    r#type.fields.iter().for_each(|field| {
+      let _ = Self::get_type_field_actual_type(ctx, r#type, field)
+        .map(|field_type| {
+          field_type
+            .map(|field_type| ctx.update_type_field_type(r#type, field, field_type))
+        })
+        .map_err(|err| ctx.report_error(err));
+    });
+  }
+
   fn enter_declarations(
     &mut self,
     ctx: &mut VisitorContext<'a>,
@@ -92,6 +105,10 @@ impl UpdateUnknownFields {
         Ok(Some(Type::Enum {
           enum_ty_name: type_name_tok.clone(),
         }))
+      } else if ctx.input_types().contains_key(&type_name) {
+        Ok(Some(Type::Composite {
+          composite_ty_name: type_name_tok.clone(),
+        }))
       } else {
         Err(Error::TypeUndefined {
           span: type_name_tok.span(),
@@ -105,6 +122,64 @@ impl UpdateUnknownFields {
     }
   }
 
+  /// Actual type of the field of the composite type if its type is Unknown, which is either
+  /// an enum or an other composite type. If the field type is already known, it returns `None`.
+  /// Note: Relations & attributes aren't allowed, the value is embedded in the objects.
+  fn get_type_field_actual_type(
+    ctx: &VisitorContext<'_>,
+    r#type: &TypeDecl,
+    field: &FieldDecl,
+  ) -> Result<Option<Type>, Error> {
+    let invalid_field_err = |span, reason: &str| Error::TypeFieldInvalid {
+      span,
+      reason: reason.to_string(),
+      field_name: field.name.ident_name().unwrap(),
+      type_name: r#type.name.ident_name().unwrap(),
+    };
+    if let Some(attrib) = field.attributes.first() {
+      return Err(invalid_field_err(
+        attrib.name.span(),
+        "Attributes aren't allowed on the composite type fields",
+      ));
+    }
+    let Type::Unknown(type_name_tok) = field.field_type.r#type() else {
+      return Ok(None);
+    };
+    let type_name = type_name_tok.ident_name().unwrap();
+    if ctx.input_enums().contains_key(&type_name) {
+      Ok(Some(Type::Enum {
+        enum_ty_name: type_name_tok.clone(),
+      }))
+    } else if ctx.input_types().contains_key(&type_name) {
+      if type_name == r#type.name.ident_name().unwrap()
+        && !field.field_type.is_optional()
+        && !field.field_type.is_array()
+      {
+        // Note: Required field of the same type would embed the type endlessly.
+        Err(invalid_field_err(
+          type_name_tok.span(),
+          "Composite type can embed itself only through an optional or a list field",
+        ))
+      } else {
+        Ok(Some(Type::Composite {
+          composite_ty_name: type_name_tok.clone(),
+        }))
+      }
+    } else if ctx.input_models().contains_key(&type_name) {
+      Err(invalid_field_err(
+        type_name_tok.span(),
+        "Relations aren't allowed in the composite types",
+      ))
+    } else {
+      Err(Error::TypeUndefined {
+        span: type_name_tok.span(),
+        type_name,
+        field_name: field.name.ident_name().unwrap(),
+        model_name: r#type.name.ident_name().unwrap(),
+      })
+    }
+  }
+
   pub fn get_relation_edge(
     field: &FieldDecl,
     model: &ModelDecl,
@@ -167,9 +242,20 @@ impl UpdateUnknownFields {
       relation_scalar_field,
       referenced_model_field,
       referenced_model_relation_field,
+      referential_actions,
     } = Self::get_relation_attribute_args(relation_args, field, model, referenced_model)?;
 
-    if relation_scalar_field.is_none() && referenced_model_field.is_none() {
+    if relation_scalar_field.is_none()
+      && referenced_model_field.is_none()
+      && field.field_type.is_array()
+      && referenced_model_relation_field.is_some_and(|fld| fld.field_type.is_array())
+    {
+      // Is this implicit many-to-many relation ?
+      return Ok(RelationEdge::ImplicitManyToManyRelation {
+        relation_name: relation_name.clone(),
+        referenced_model_name: referenced_model.name.clone(),
+      });
+    } else if relation_scalar_field.is_none() && referenced_model_field.is_none() {
       // Is this OneSideRelation ?
       return Ok(RelationEdge::OneSideRelation {
         relation_name: relation_name.clone(),
@@ -240,6 +326,7 @@ impl UpdateUnknownFields {
           scalar_field_name: relation_scalar_field.name.clone(),
           referenced_model_name: referenced_model.name.clone(),
           referenced_field_name: referenced_model_field.unwrap().name.clone(),
+          referential_actions,
         })
       }
       (_scalar_fld_unique @ true, _rel_fld_exists @ true, _rel_fld_array @ false) => {
@@ -248,6 +335,7 @@ impl UpdateUnknownFields {
           scalar_field_name: relation_scalar_field.name.clone(),
           referenced_model_name: referenced_model.name.clone(),
           referenced_field_name: referenced_model_field.unwrap().name.clone(),
+          referential_actions,
         })
       }
       (_scalar_fld_unique @ false, _rel_fld_exists @ true, _rel_fld_array @ true) => {
@@ -256,6 +344,7 @@ impl UpdateUnknownFields {
           scalar_field_name: relation_scalar_field.name.clone(),
           referenced_model_name: referenced_model.name.clone(),
           referenced_field_name: referenced_model_field.unwrap().name.clone(),
+          referential_actions,
         })
       }
       _ => Err(Error::RelationInvalid {
@@ -277,6 +366,15 @@ impl UpdateUnknownFields {
     let mut relation_scalar_field: Option<&'b FieldDecl> = None;
     let mut referenced_model_field: Option<&'b FieldDecl> = None;
     let referenced_model_relation_field: Option<&'b FieldDecl>;
+    let mut referential_actions = ReferentialActions::default();
+
+    // Note: Referential actions are optional, and they are allowed only along
+    // with the relation scalar field.
+    let (action_args, relation_args): (Vec<&'b NamedArg>, Vec<&'b NamedArg>) =
+      relation_args.iter().partition(|arg| {
+        matches!(&arg.arg_name, Token::Ident(ident_name, _)
+          if ident_name == ATTRIB_NAMED_ARG_ON_DELETE || ident_name == ATTRIB_NAMED_ARG_ON_UPDATE)
+      });
 
     // Step 1: Validate relation attribute has correct set of args.
     let mut valid_arg_sets: HashMap<usize, Vec<_>> = HashMap::new();
@@ -291,7 +389,8 @@ impl UpdateUnknownFields {
     );
     // Check for invalid arg sets
     let allowed_arg_set = valid_arg_sets.get(&relation_args.len());
-    if allowed_arg_set.is_none() {
+    if allowed_arg_set.is_none() || (!action_args.is_empty() && relation_args.len() == 1)
+    {
       return Err(Error::RelationInvalidAttributeArg {
         span: field.name.span(),
         relation_name: None,
@@ -319,7 +418,7 @@ impl UpdateUnknownFields {
     }
 
     // Step 2: Get those arg values, and make sure they are of expected type.
-    for arg in relation_args.iter() {
+    for arg in relation_args.iter().copied() {
       match &arg.arg_name {
         Token::Ident(ident_name, _) if ident_name == ATTRIB_NAMED_ARG_NAME => {
           if let Token::String(..) = arg.arg_value {
@@ -358,6 +457,33 @@ impl UpdateUnknownFields {
       }
     }
 
+    // Step 3: Get the referential actions, and make sure they fit the relation scalar field.
+    for arg in action_args {
+      let invalid_arg_err = || Error::RelationInvalidAttributeArg {
+        span: arg.arg_name.span(),
+        relation_name: None,
+        arg_name: arg.arg_name.ident_name(),
+        field_name: field.name.ident_name(),
+        model_name: model.name.ident_name(),
+      };
+      let action = get_referential_action(
+        arg,
+        relation_scalar_field.ok_or_else(invalid_arg_err)?,
+        field,
+        model,
+      )?;
+      let referential_action = match &arg.arg_name {
+        Token::Ident(ident_name, _) if ident_name == ATTRIB_NAMED_ARG_ON_DELETE => {
+          &mut referential_actions.on_delete
+        }
+        _ => &mut referential_actions.on_update,
+      };
+      if referential_action.replace(action).is_some() {
+        // Duplicate referential action.
+        return Err(invalid_arg_err());
+      }
+    }
+
     referenced_model_relation_field = Self::get_referenced_model_relation_field(
       relation_name.expect("relation_name can't be None at this point."),
       field,
@@ -384,6 +510,7 @@ impl UpdateUnknownFields {
         relation_scalar_field,
         referenced_model_field,
         referenced_model_relation_field,
+        referential_actions,
       })
     }
   }
@@ -570,4 +697,5 @@ struct RelationAttributeDetails<'a> {
   pub relation_scalar_field: Option<&'a FieldDecl>,
   pub referenced_model_field: Option<&'a FieldDecl>,
   pub referenced_model_relation_field: Option<&'a FieldDecl>,
+  pub referential_actions: ReferentialActions,
 }
@@ -1,12 +1,22 @@
 use std::collections::HashMap;
 
+use indexmap::IndexMap;
+use regex::Regex;
+
 use crate::{
   parser::semantic_analysis::{
-    attribute::{AttributeDetails, ATTRIB_ARG_VALUE_ENUM},
+    attribute::{
+      AttributeDetails, ATTRIB_ARG_FN_NANOID, ATTRIB_ARG_FN_NOW, ATTRIB_ARG_FN_SEQUENCE,
+      ATTRIB_ARG_FN_UUID, ATTRIB_ARG_VALUE_ENUM, ATTRIB_ARG_VALUE_LITERAL,
+      ATTRIB_NAMED_ARG_MAX, ATTRIB_NAMED_ARG_MIN, ATTRIB_NAME_LENGTH,
+      ATTRIB_NAME_PATTERN, ATTRIB_NAME_RANGE,
+    },
     err::Error,
     visitor::{Visitor, VisitorMode},
   },
-  types::{AttribArg, Attribute, EnumDecl, FieldDecl, ModelDecl, Type},
+  types::{
+    AttribArg, Attribute, EnumDecl, FieldDecl, ModelDecl, PrimitiveType, Token, Type,
+  },
 };
 
 /// Validate the attribute arguments
@@ -35,6 +45,13 @@ impl<'a> Visitor<'a> for ValidateAttributeArgs {
       &ctx.input_enums(),
       ctx.attribute_details_map(),
     )
+    .and_then(|_| {
+      Self::validate_constraint_args(
+        attribute,
+        ctx.current_field.unwrap(),
+        ctx.current_model.unwrap(),
+      )
+    })
     .map_err(|err| {
       ctx.report_error(err);
     });
@@ -46,7 +63,7 @@ impl ValidateAttributeArgs {
     attrib: &Attribute,
     field: &FieldDecl,
     model: &ModelDecl,
-    enums: &HashMap<String, EnumDecl>,
+    enums: &IndexMap<String, EnumDecl>,
     attribute_details_map: &HashMap<&'static str, AttributeDetails>,
   ) -> Result<(), Error> {
     match attribute_details_map.get(attrib.name.ident_name().unwrap().as_str()) {
@@ -72,7 +89,7 @@ impl ValidateAttributeArgs {
           })
         } else if let Some(attrib_arg) = attrib.arg.as_ref() {
           match attrib_arg {
-            AttribArg::Function(fn_name) => {
+            AttribArg::Function(fn_name, fn_args) => {
               if !attrib_detail
                 .allowed_arg_fns
                 .contains(&fn_name.ident_name().unwrap().as_str())
@@ -85,7 +102,7 @@ impl ValidateAttributeArgs {
                   model_name: model.name.ident_name().unwrap(),
                 })
               } else {
-                Ok(())
+                Self::validate_attribute_fn(attrib, fn_name, fn_args, field, model)
               }
             }
             AttribArg::Ident(arg_value) => {
@@ -135,7 +152,23 @@ impl ValidateAttributeArgs {
                   model_name: model.name.ident_name().unwrap(),
                 })
               } else {
-                Ok(())
+                Self::validate_attribute_arg_type(attrib, arg_value, field, model)
+              }
+            }
+            AttribArg::Literal(literal) => {
+              if !attrib_detail
+                .allowed_arg_values
+                .contains(&ATTRIB_ARG_VALUE_LITERAL)
+              {
+                Err(Error::AttributeArgInvalid {
+                  span: literal.span(),
+                  attrib_arg_name: Some(attrib_arg.to_string()),
+                  attrib_name: attrib.name.ident_name().unwrap(),
+                  field_name: field.name.ident_name().unwrap(),
+                  model_name: model.name.ident_name().unwrap(),
+                })
+              } else {
+                Self::validate_attribute_arg_type(attrib, literal, field, model)
               }
             }
             AttribArg::Args(named_args) => {
@@ -168,4 +201,180 @@ impl ValidateAttributeArgs {
       }
     }
   }
+  /// Validate the arguments of the validation attributes, the bounds should fit the field
+  /// and the pattern should be a valid regular expression.
+  fn validate_constraint_args(
+    attrib: &Attribute,
+    field: &FieldDecl,
+    model: &ModelDecl,
+  ) -> Result<(), Error> {
+    let attrib_name = attrib.name.ident_name().unwrap();
+    let arg_invalid = |span, attrib_arg_name| Error::AttributeArgInvalid {
+      span,
+      attrib_arg_name,
+      attrib_name: attrib_name.clone(),
+      field_name: field.name.ident_name().unwrap(),
+      model_name: model.name.ident_name().unwrap(),
+    };
+    match (attrib_name.as_str(), &attrib.arg) {
+      (ATTRIB_NAME_LENGTH | ATTRIB_NAME_RANGE, Some(AttribArg::Args(named_args))) => {
+        let mut bounds = HashMap::new();
+        for named_arg in named_args {
+          let arg_value = &named_arg.arg_value;
+          let bound = match (attrib_name.as_str(), arg_value) {
+            // Length is counted in characters, hence it can't be negative.
+            (ATTRIB_NAME_LENGTH, Token::Int(int, _)) if *int >= 0 => *int as f64,
+            (ATTRIB_NAME_LENGTH, _) => {
+              return Err(arg_invalid(
+                arg_value.span(),
+                named_arg.arg_name.ident_name(),
+              ))
+            }
+            (_, Token::Int(int, _)) => {
+              Self::validate_attribute_arg_type(attrib, arg_value, field, model)?;
+              *int as f64
+            }
+            (_, Token::Float(float, _)) => {
+              Self::validate_attribute_arg_type(attrib, arg_value, field, model)?;
+              *float
+            }
+            _ => {
+              return Err(Self::attribute_arg_type_mismatch(
+                attrib, arg_value, field, model,
+              ))
+            }
+          };
+          bounds.insert(named_arg.arg_name.ident_name().unwrap(), (bound, arg_value));
+        }
+        match (
+          bounds.get(ATTRIB_NAMED_ARG_MIN),
+          bounds.get(ATTRIB_NAMED_ARG_MAX),
+        ) {
+          (Some((min, _)), Some((max, max_value))) if min > max => Err(arg_invalid(
+            max_value.span(),
+            Some(ATTRIB_NAMED_ARG_MAX.to_string()),
+          )),
+          _ => Ok(()),
+        }
+      }
+      // Note: Bounds of the length & range, and the pattern should be given.
+      (ATTRIB_NAME_LENGTH | ATTRIB_NAME_RANGE, _) | (ATTRIB_NAME_PATTERN, None) => {
+        Err(arg_invalid(attrib.name.span(), None))
+      }
+      (ATTRIB_NAME_PATTERN, Some(AttribArg::Literal(Token::String(pattern, span)))) => {
+        Regex::new(pattern)
+          .map(|_| ())
+          .map_err(|_| arg_invalid(*span, Some(attrib.arg.as_ref().unwrap().to_string())))
+      }
+      _ => Ok(()),
+    }
+  }
+
+  /// Validate the arguments of the attribute function, and if the value generated by the
+  /// function fits the type of the field.
+  fn validate_attribute_fn(
+    attrib: &Attribute,
+    fn_name: &Token,
+    fn_args: &[Token],
+    field: &FieldDecl,
+    model: &ModelDecl,
+  ) -> Result<(), Error> {
+    let fn_name_str = fn_name.ident_name().unwrap();
+    let is_valid_arg = match fn_args {
+      [] => true,
+      [Token::Int(int, _)] => match fn_name_str.as_str() {
+        ATTRIB_ARG_FN_UUID => matches!(int, 4 | 7),
+        ATTRIB_ARG_FN_NANOID => (2..=255).contains(int),
+        _ => false,
+      },
+      _ => false,
+    };
+    if !is_valid_arg {
+      return Err(Error::AttributeArgInvalid {
+        span: fn_name.span(),
+        attrib_arg_name: Some(attrib.arg.as_ref().unwrap().to_string()),
+        attrib_name: attrib.name.ident_name().unwrap(),
+        field_name: field.name.ident_name().unwrap(),
+        model_name: model.name.ident_name().unwrap(),
+      });
+    }
+
+    let field_types: &[PrimitiveType] = match fn_name_str.as_str() {
+      ATTRIB_ARG_FN_NOW => &[PrimitiveType::DateTime],
+      ATTRIB_ARG_FN_SEQUENCE => &[
+        PrimitiveType::ShortStr,
+        PrimitiveType::LongStr,
+        PrimitiveType::Int32,
+        PrimitiveType::Int64,
+      ],
+      ATTRIB_ARG_FN_UUID => &[
+        PrimitiveType::ShortStr,
+        PrimitiveType::LongStr,
+        PrimitiveType::Uuid,
+      ],
+      // ID generators of the string IDs.
+      _ => &[PrimitiveType::ShortStr, PrimitiveType::LongStr],
+    };
+    match field.field_type.r#type() {
+      Type::Primitive { r#type, .. } if field_types.contains(r#type) => Ok(()),
+      _ => Err(Self::attribute_arg_type_mismatch(
+        attrib, fn_name, field, model,
+      )),
+    }
+  }
+
+  /// Validate if the literal (or boolean) value of the attribute argument fits the type of the field.
+  fn validate_attribute_arg_type(
+    attrib: &Attribute,
+    arg_value: &Token,
+    field: &FieldDecl,
+    model: &ModelDecl,
+  ) -> Result<(), Error> {
+    let Type::Primitive { r#type, .. } = field.field_type.r#type() else {
+      // Only the enum values fit the enum field.
+      return Err(Self::attribute_arg_type_mismatch(
+        attrib, arg_value, field, model,
+      ));
+    };
+    let fits = match (arg_value, r#type) {
+      (
+        Token::String(..),
+        PrimitiveType::ShortStr
+        | PrimitiveType::LongStr
+        | PrimitiveType::Decimal
+        | PrimitiveType::Date
+        | PrimitiveType::Uuid,
+      ) => true,
+      (Token::Int(int, _), PrimitiveType::Int32) => i32::try_from(*int).is_ok(),
+      (
+        Token::Int(..),
+        PrimitiveType::Int64 | PrimitiveType::Float64 | PrimitiveType::Decimal,
+      ) => true,
+      (Token::Float(..), PrimitiveType::Float64 | PrimitiveType::Decimal) => true,
+      (Token::Ident(..), PrimitiveType::Boolean) => true,
+      _ => false,
+    };
+    if fits {
+      Ok(())
+    } else {
+      Err(Self::attribute_arg_type_mismatch(
+        attrib, arg_value, field, model,
+      ))
+    }
+  }
+
+  fn attribute_arg_type_mismatch(
+    attrib: &Attribute,
+    arg_value: &Token,
+    field: &FieldDecl,
+    model: &ModelDecl,
+  ) -> Error {
+    Error::AttributeArgTypeMismatch {
+      span: arg_value.span(),
+      field_type: field.field_type.r#type().token().ident_name().unwrap(),
+      attrib_name: attrib.name.ident_name().unwrap(),
+      field_name: field.name.ident_name().unwrap(),
+      model_name: model.name.ident_name().unwrap(),
+    }
+  }
 }
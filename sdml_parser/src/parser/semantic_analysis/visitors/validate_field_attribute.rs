@@ -6,7 +6,7 @@ use crate::{
     err::Error,
     visitor::{Visitor, VisitorMode},
   },
-  types::{Attribute, FieldDecl, ModelDecl},
+  types::{Attribute, FieldDecl, ModelDecl, Type},
 };
 
 /// Validates if the attribute can be applied to the
@@ -56,8 +56,20 @@ impl ValidateFieldAttribute {
         field_name: field.name.ident_name().unwrap(),
         model_name: model.name.ident_name().unwrap(),
       }),
+      Some(_) if matches!(field.field_type.r#type(), Type::Composite { .. }) => {
+        Err(Error::AttributeInvalid {
+          span: attrib.name.span(),
+          reason: "Attributes aren't allowed on the composite type field".to_string(),
+          attrib_name: attrib.name.ident_name().unwrap(),
+          field_name: field.name.ident_name().unwrap(),
+          model_name: model.name.ident_name().unwrap(),
+        })
+      }
       Some(attrib_detail) => {
         let is_scalar_short_str_field = field.field_type.is_scalar_short_str();
+        let is_scalar_date_time_field = field.field_type.is_scalar_date_time();
+        let is_str_field = field.field_type.is_str();
+        let is_number_field = field.field_type.is_number();
         let is_scalar_field = field.field_type.is_scalar();
         let is_optional_field = field.field_type.is_optional();
 
@@ -78,6 +90,29 @@ impl ValidateFieldAttribute {
               Ok(())
             }
           }
+          AllowedFieldType::ScalarDateTimeField { can_be_optional }
+            if is_scalar_date_time_field =>
+          {
+            if !can_be_optional && is_optional_field {
+              invalid_attribute_err
+            } else {
+              Ok(())
+            }
+          }
+          AllowedFieldType::StrField { can_be_optional } if is_str_field => {
+            if !can_be_optional && is_optional_field {
+              invalid_attribute_err
+            } else {
+              Ok(())
+            }
+          }
+          AllowedFieldType::NumberField { can_be_optional } if is_number_field => {
+            if !can_be_optional && is_optional_field {
+              invalid_attribute_err
+            } else {
+              Ok(())
+            }
+          }
           AllowedFieldType::ScalarField { can_be_optional } if is_scalar_field => {
             if !can_be_optional && is_optional_field {
               invalid_attribute_err
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use crate::{
+  parser::semantic_analysis::{
+    attribute::{
+      ATTRIB_NAME_MODEL_ID, ATTRIB_NAME_MODEL_INDEX, ATTRIB_NAME_MODEL_UNIQUE,
+    },
+    err::Error,
+    visitor::Visitor,
+  },
+  types::{ModelAttribute, ModelDecl},
+};
+
+/// Name of the GraphQL ID field, which a model with compound ID (`@@id`) gets.
+const FIELD_NAME_ID: &str = "id";
+
+/// Validates the model (block) attributes, `@@id`, `@@unique` & `@@index`.
+pub struct ValidateModelAttributes;
+
+impl<'a> Visitor<'a> for ValidateModelAttributes {
+  fn enter_model(&mut self, ctx: &mut super::VisitorContext<'a>, model: &'a ModelDecl) {
+    let mut compound_names = HashSet::new();
+    let mut has_compound_id = false;
+    model.attributes.iter().for_each(|attrib| {
+      let result = Self::validate_model_attribute(attrib, model).and_then(|_| {
+        Self::validate_compound_key(attrib, model, &mut compound_names, has_compound_id)
+      });
+      if let Err(err) = result {
+        ctx.report_error(err);
+      }
+      has_compound_id |= attrib.name.ident_name().unwrap() == ATTRIB_NAME_MODEL_ID;
+    });
+  }
+}
+
+impl ValidateModelAttributes {
+  /// Validates the attribute and the fields listed in it.
+  fn validate_model_attribute(
+    attrib: &ModelAttribute,
+    model: &ModelDecl,
+  ) -> Result<(), Error> {
+    let attrib_name = attrib.name.ident_name().unwrap();
+    let model_name = model.name.ident_name().unwrap();
+    if ![
+      ATTRIB_NAME_MODEL_ID,
+      ATTRIB_NAME_MODEL_UNIQUE,
+      ATTRIB_NAME_MODEL_INDEX,
+    ]
+    .contains(&attrib_name.as_str())
+    {
+      return Err(Error::ModelAttributeUnknown {
+        span: attrib.name.span(),
+        attrib_name,
+        model_name,
+      });
+    }
+    if attrib.fields.len() < 2 {
+      return Err(Error::ModelAttributeInvalid {
+        span: attrib.name.span(),
+        reason: "At least two fields should be listed, use the field attribute for a single field".to_string(),
+        attrib_name,
+        model_name,
+      });
+    }
+
+    let mut field_names = HashSet::new();
+    attrib.fields.iter().try_for_each(|field_name_tok| {
+      let field_name = field_name_tok.ident_name().unwrap();
+      let field_err = |reason: &str| Error::ModelAttributeFieldInvalid {
+        span: field_name_tok.span(),
+        reason: reason.to_string(),
+        attrib_name: attrib_name.clone(),
+        field_name: field_name.clone(),
+        model_name: model_name.clone(),
+      };
+      let Some(field) = model.field_by_name(&field_name) else {
+        return Err(field_err("Field is not found in the model"));
+      };
+      if !field_names.insert(field_name.clone()) {
+        Err(field_err("Field is listed more than once"))
+      } else if !field.field_type.is_scalar() {
+        Err(field_err("Only Scalar field is allowed"))
+      } else if attrib_name == ATTRIB_NAME_MODEL_ID && field.field_type.is_optional() {
+        Err(field_err(
+          "Only Non-Optional field is allowed in the compound ID",
+        ))
+      } else {
+        Ok(())
+      }
+    })
+  }
+
+  /// Validates the compound key doesn't clash with the fields,
+  /// the other compound keys or the ID of the model.
+  fn validate_compound_key(
+    attrib: &ModelAttribute,
+    model: &ModelDecl,
+    compound_names: &mut HashSet<String>,
+    has_compound_id: bool,
+  ) -> Result<(), Error> {
+    let attrib_name = attrib.name.ident_name().unwrap();
+    let invalid_attrib_err = |reason: String| Error::ModelAttributeInvalid {
+      span: attrib.name.span(),
+      reason,
+      attrib_name: attrib_name.clone(),
+      model_name: model.name.ident_name().unwrap(),
+    };
+    let compound_name = attrib.compound_name();
+    if model.field_by_name(&compound_name).is_some() {
+      return Err(invalid_attrib_err(format!(
+        "Compound name {compound_name} clashes with the field of the same name"
+      )));
+    }
+    if !compound_names.insert(compound_name.clone()) {
+      return Err(invalid_attrib_err(format!(
+        "Compound key {compound_name} is declared more than once"
+      )));
+    }
+    if attrib_name == ATTRIB_NAME_MODEL_ID {
+      if has_compound_id {
+        return Err(invalid_attrib_err(
+          "Model can have only one compound ID".to_string(),
+        ));
+      }
+      if model.fields.iter().any(|field| field.has_id_attrib()) {
+        return Err(invalid_attrib_err(
+          "Model already has a field with @id attribute".to_string(),
+        ));
+      }
+      if model.field_by_name(FIELD_NAME_ID).is_some() {
+        return Err(invalid_attrib_err(format!(
+          "Field {FIELD_NAME_ID} is reserved for the compound ID"
+        )));
+      }
+    }
+    Ok(())
+  }
+}
@@ -20,6 +20,13 @@ impl<'a> Visitor<'a> for ValidateModelHasIdField {
 impl ValidateModelHasIdField {
   fn validate_model_id_field(model: &ModelDecl) -> Result<(), Error> {
     let model_fields = model.get_fields_internal(true); // Note: allow_unknown_field_type is set to `true`. Because this function is called during the semantic_update phase.
+
+    // Note: Fields of the compound ID (`@@id`) are validated by `ValidateModelAttributes`.
+    let id_attrib_fields = model_fields
+      .id
+      .iter()
+      .filter(|(id_fld, _is_auto_gen)| id_fld.has_id_attrib())
+      .collect::<Vec<_>>();
     let has_only_auto_gen_id = model_fields
       .id
       .iter()
@@ -40,8 +47,8 @@ impl ValidateModelHasIdField {
         span: model.name.span(),
         model_name: model.name.ident_name().unwrap(),
       })
-    } else if model_fields.id.len() > 1 {
-      let (second_id_field, _) = model_fields.id[1];
+    } else if id_attrib_fields.len() > 1 {
+      let (second_id_field, _) = id_attrib_fields[1];
       // Is there more than one Id field in a Model ?
       Err(Error::ModelIdFieldDuplicate {
         span: second_id_field.name.span(),
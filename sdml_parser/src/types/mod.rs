@@ -1,8 +1,10 @@
 //! Abstract Synctax Tree (AST) types of Simple Data Modeling Language (SDML).
-use std::{borrow::Borrow, collections::HashMap, ops::Deref, sync::Arc};
+use std::{borrow::Borrow, ops::Deref, sync::Arc};
 
 use crate::parser::semantic_analysis;
 use chumsky::span::SimpleSpan;
+use graphql_value::{ConstValue, Name};
+use indexmap::IndexMap;
 
 pub type Span = SimpleSpan<usize>;
 
@@ -85,6 +87,7 @@ impl PartialEq<Str> for &'_ str {
 #[derive(Debug, Clone)]
 pub enum Token {
   Ident(Str, Span),
+  /// String literal, without the quotes & with its escapes decoded.
   String(Str, Span),
   Int(i64, Span),
   Float(f64, Span),
@@ -121,7 +124,7 @@ impl Token {
   }
   pub fn str(&self) -> Option<String> {
     if let Token::String(str, _) = self {
-      Some(str.trim_matches('"').to_string())
+      Some(str.to_string())
     } else {
       None
     }
@@ -157,25 +160,30 @@ impl Token {
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub(crate) struct DeclarationsGrouped {
-  /// Map of config name to its declarations.
-  pub configs: HashMap<String, ConfigDecl>,
-  /// Map of enum name to its declarations.
-  pub enums: HashMap<String, EnumDecl>,
-  /// Map of model name to its declarations.
-  pub models: HashMap<String, ModelDecl>,
+  /// Map of config name to its declarations, in the source order.
+  pub configs: IndexMap<String, ConfigDecl>,
+  /// Map of enum name to its declarations, in the source order.
+  pub enums: IndexMap<String, EnumDecl>,
+  /// Map of composite type name to its declarations, in the source order.
+  pub types: IndexMap<String, TypeDecl>,
+  /// Map of model name to its declarations, in the source order.
+  pub models: IndexMap<String, ModelDecl>,
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct DataModel {
-  /// Map of config name to its declarations.
-  pub configs: HashMap<String, ConfigDecl>,
-  /// Map of enum name to its declarations.
-  pub enums: HashMap<String, EnumDecl>,
-  /// Map of model name to its declarations.
-  pub models: HashMap<String, ModelDecl>,
-  /// Map of valid relations with fully formed edges.
+  /// Map of config name to its declarations, in the source order.
+  pub configs: IndexMap<String, ConfigDecl>,
+  /// Map of enum name to its declarations, in the source order.
+  pub enums: IndexMap<String, EnumDecl>,
+  /// Map of composite type name to its declarations, in the source order.
+  pub types: IndexMap<String, TypeDecl>,
+  /// Map of model name to its declarations, in the source order.
+  pub models: IndexMap<String, ModelDecl>,
+  /// Map of valid relations with fully formed edges, in the order they are
+  /// first referenced in the source.
   /// Available only after semantic_analysis phase.
-  pub relations: HashMap<String, (RelationEdge, Option<RelationEdge>)>,
+  pub relations: IndexMap<String, (RelationEdge, Option<RelationEdge>)>,
 }
 
 impl From<DeclarationsGrouped> for DataModel {
@@ -183,6 +191,7 @@ impl From<DeclarationsGrouped> for DataModel {
     DataModel {
       configs: value.configs,
       enums: value.enums,
+      types: value.types,
       models: value.models,
       relations: Default::default(),
     }
@@ -191,70 +200,82 @@ impl From<DeclarationsGrouped> for DataModel {
 
 impl DataModel {
   pub fn new(
-    configs: HashMap<String, ConfigDecl>,
-    enums: HashMap<String, EnumDecl>,
-    models: HashMap<String, ModelDecl>,
-    relations: HashMap<String, (RelationEdge, Option<RelationEdge>)>,
+    configs: IndexMap<String, ConfigDecl>,
+    enums: IndexMap<String, EnumDecl>,
+    types: IndexMap<String, TypeDecl>,
+    models: IndexMap<String, ModelDecl>,
+    relations: IndexMap<String, (RelationEdge, Option<RelationEdge>)>,
   ) -> DataModel {
     DataModel {
       configs,
       enums,
+      types,
       models,
       relations,
     }
   }
-  pub fn configs(&self) -> &HashMap<String, ConfigDecl> {
+  /// Configs in the source order.
+  pub fn configs(&self) -> &IndexMap<String, ConfigDecl> {
     &self.configs
   }
-  /// Configs sorted by its name in chronogical order.
+  /// Configs sorted by its name in alphabetical order.
   pub fn configs_sorted(&self) -> Vec<&ConfigDecl> {
-    let mut config_names = self.enums.keys().collect::<Vec<&String>>();
-    config_names.sort();
-    config_names
-      .into_iter()
-      .rfold(Vec::new(), |mut acc, config_name| {
-        acc.push(self.configs.get(config_name).unwrap());
-        acc
-      })
+    sorted_by_name(&self.configs)
   }
-
-  pub fn enums(&self) -> &HashMap<String, EnumDecl> {
+  /// Enums in the source order.
+  pub fn enums(&self) -> &IndexMap<String, EnumDecl> {
     &self.enums
   }
-  /// Enums sorted by its name in chronogical order.
+  /// Enums sorted by its name in alphabetical order.
   pub fn enums_sorted(&self) -> Vec<&EnumDecl> {
-    let mut enum_names = self.enums.keys().collect::<Vec<&String>>();
-    enum_names.sort();
-    enum_names
-      .into_iter()
-      .rfold(Vec::new(), |mut acc, enum_name| {
-        acc.push(self.enums.get(enum_name).unwrap());
-        acc
-      })
+    sorted_by_name(&self.enums)
   }
-  pub fn models(&self) -> &HashMap<String, ModelDecl> {
+  /// Composite types in the source order.
+  pub fn types(&self) -> &IndexMap<String, TypeDecl> {
+    &self.types
+  }
+  /// Composite types sorted by its name in alphabetical order.
+  pub fn types_sorted(&self) -> Vec<&TypeDecl> {
+    sorted_by_name(&self.types)
+  }
+  /// Models in the source order.
+  pub fn models(&self) -> &IndexMap<String, ModelDecl> {
     &self.models
   }
-  /// Models sorted by its name in chronogical order.
+  /// Models sorted by its name in alphabetical order.
   pub fn models_sorted(&self) -> Vec<&ModelDecl> {
-    let mut model_names = self.models.keys().collect::<Vec<&String>>();
-    model_names.sort();
-    model_names
-      .into_iter()
-      .rfold(Vec::new(), |mut acc, model_name| {
-        acc.push(self.models.get(model_name).unwrap());
-        acc
-      })
+    sorted_by_name(&self.models)
   }
-  pub fn relations(&self) -> &HashMap<String, (RelationEdge, Option<RelationEdge>)> {
+  pub fn relations(&self) -> &IndexMap<String, (RelationEdge, Option<RelationEdge>)> {
     &self.relations
   }
+  /// All the declarations, in the source order.
+  pub fn declarations(&self) -> Vec<Declaration> {
+    let mut decls = self
+      .configs
+      .values()
+      .cloned()
+      .map(Declaration::Config)
+      .chain(self.enums.values().cloned().map(Declaration::Enum))
+      .chain(self.types.values().cloned().map(Declaration::Type))
+      .chain(self.models.values().cloned().map(Declaration::Model))
+      .collect::<Vec<_>>();
+    decls.sort_by_key(|decl| decl.name().span().start);
+    decls
+  }
+}
+
+fn sorted_by_name<T>(decls: &IndexMap<String, T>) -> Vec<&T> {
+  let mut decls = decls.iter().collect::<Vec<_>>();
+  decls.sort_by_key(|(name, _)| *name);
+  decls.into_iter().map(|(_, decl)| decl).collect()
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Declaration {
   Config(ConfigDecl),
   Enum(EnumDecl),
+  Type(TypeDecl),
   Model(ModelDecl),
 }
 
@@ -263,6 +284,7 @@ impl Declaration {
     match self {
       Declaration::Config(c) => &c.name,
       Declaration::Enum(e) => &e.name,
+      Declaration::Type(t) => &t.name,
       Declaration::Model(m) => &m.name,
     }
   }
@@ -282,6 +304,7 @@ pub struct ConfigPair {
 
 #[derive(Debug, Clone)]
 pub enum ConfigValue {
+  /// String literal, without the quotes & with its escapes decoded.
   String(Str, Span),
   Int(i64, Span),
   Float(f64, Span),
@@ -323,18 +346,84 @@ impl TryFrom<Token> for ConfigValue {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct EnumDecl {
+  /// Doc comments (`///`) of the enum.
+  pub docs: Option<Str>,
   pub name: Token,
   pub elements: Vec<Token>,
 }
 
+/// Composite type (`type` block), a value object embedded in the models.
+/// Ex. `type Address { street ShortStr city ShortStr }`, stored inline with the parent object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDecl {
+  /// Doc comments (`///`) of the type.
+  pub docs: Option<Str>,
+  pub name: Token,
+  pub fields: Vec<FieldDecl>,
+}
+
+impl TypeDecl {
+  pub fn field_by_name(&self, name: &str) -> Option<&FieldDecl> {
+    self
+      .fields
+      .iter()
+      .find(|field| field.name.ident_name().unwrap() == name)
+  }
+  pub(crate) fn field_by_name_mut(&mut self, name: &str) -> Option<&mut FieldDecl> {
+    self
+      .fields
+      .iter_mut()
+      .find(|field| field.name.ident_name().unwrap() == name)
+  }
+}
+
 /// Represents an entity inside the application domain.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ModelDecl {
+  /// Doc comments (`///`) of the model.
+  pub docs: Option<Str>,
   pub name: Token,
   pub fields: Vec<FieldDecl>,
+  /// Model level attributes, ex. `@@unique([tenantId, email])`.
+  pub attributes: Vec<ModelAttribute>,
 }
 
 impl ModelDecl {
+  /// Compound ID (`@@id`) of the model, if it has one.
+  pub fn compound_id(&self) -> Option<&ModelAttribute> {
+    self
+      .model_attributes(semantic_analysis::ATTRIB_NAME_MODEL_ID)
+      .next()
+  }
+  /// Compound unique constraints (`@@unique`) of the model.
+  pub fn compound_uniques(&self) -> impl Iterator<Item = &ModelAttribute> {
+    self.model_attributes(semantic_analysis::ATTRIB_NAME_MODEL_UNIQUE)
+  }
+  /// Compound indexes (`@@index`) of the model.
+  pub fn compound_indexes(&self) -> impl Iterator<Item = &ModelAttribute> {
+    self.model_attributes(semantic_analysis::ATTRIB_NAME_MODEL_INDEX)
+  }
+  /// Is the field part of the compound ID (`@@id`) ?
+  pub fn is_compound_id_field(&self, field: &FieldDecl) -> bool {
+    self.compound_id().is_some_and(|compound_id| {
+      compound_id
+        .fields
+        .iter()
+        .any(|compound_field| compound_field == &field.name)
+    })
+  }
+
+  #[inline]
+  fn model_attributes<'a>(
+    &'a self,
+    attrib_name: &'a str,
+  ) -> impl Iterator<Item = &'a ModelAttribute> {
+    self
+      .attributes
+      .iter()
+      .filter(move |attrib| attrib.name.ident_name().as_deref() == Some(attrib_name))
+  }
+
   pub fn field_by_name(&self, name: &str) -> Option<&FieldDecl> {
     self
       .fields
@@ -354,7 +443,7 @@ pub struct ModelFields<'a> {
   pub relation: Vec<&'a FieldDecl>,
   /// relation_scalar: Vec<(field, is_unique_or_indexed)>
   relation_scalar: Vec<(&'a FieldDecl, bool)>,
-  /// id: Vec<(field, is_auto_generated)>
+  /// id: Vec<(field, is_auto_generated)>, either the `@id` field or the `@@id` fields.
   pub id: Vec<(&'a FieldDecl, bool)>,
   pub unique: Vec<&'a FieldDecl>,
   /// rest: Vec<(field, is_indexed)>
@@ -465,10 +554,11 @@ impl ModelDecl {
             relation_scalar_field_names.push(fld_name.ident_name().unwrap().to_string())
           });
         }
+        Type::Composite { .. } => result.rest.push((field, false)),
         Type::Primitive { .. } | Type::Enum { .. } => {
           if field.is_auto_gen_id() {
             result.id.push((field, true));
-          } else if field.has_id_attrib() {
+          } else if field.has_id_attrib() || self.is_compound_id_field(field) {
             result.id.push((field, false));
           } else if field.has_unique_attrib() {
             result.unique.push(field);
@@ -517,6 +607,8 @@ impl ModelDecl {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FieldDecl {
+  /// Doc comments (`///`) of the field.
+  pub docs: Option<Str>,
   pub name: Token,
   pub field_type: FieldType,
   pub attributes: Vec<Attribute>,
@@ -524,6 +616,7 @@ pub struct FieldDecl {
 
 impl FieldDecl {
   /// Is this an auto-generated id field ?
+  /// i.e. its default value is generated by one of the ID generator functions, Ex. `uuid()`.
   pub fn is_auto_gen_id(&self) -> bool {
     if self.has_id_attrib() {
       self.default_attribute().map_or(false, |default_attrib| {
@@ -531,12 +624,9 @@ impl FieldDecl {
           .arg
           .as_ref()
           .map_or(false, |attrib_arg| match attrib_arg {
-            AttribArg::Function(fn_name) => {
-              if let Token::Ident(ident_name, _) = fn_name {
-                ident_name == semantic_analysis::ATTRIB_ARG_FN_AUTO
-              } else {
-                false
-              }
+            AttribArg::Function(Token::Ident(ident_name, _), _) => {
+              semantic_analysis::ATTRIB_ARG_FN_ID_GENERATORS
+                .contains(&ident_name.as_str())
             }
             _ => false,
           })
@@ -562,12 +652,98 @@ impl FieldDecl {
       .get_attribute(semantic_analysis::ATTRIB_NAME_INDEXED)
       .is_some()
   }
+  /// Returns true if this field has @updatedAt attribute,
+  /// i.e. its value is maintained by the storage.
+  pub fn has_updated_at_attrib(&self) -> bool {
+    self
+      .get_attribute(semantic_analysis::ATTRIB_NAME_UPDATED_AT)
+      .is_some()
+  }
   pub fn has_default_attrib(&self) -> bool {
     self.default_attribute().is_some()
   }
   pub fn default_attribute(&self) -> Option<&Attribute> {
     self.get_attribute(semantic_analysis::ATTRIB_NAME_DEFAULT)
   }
+  /// Constant default value of the field, i.e. a literal, a boolean or an enum value.
+  /// Note: It is `None` for the generated defaults, ex. `@default(now())`.
+  pub fn default_value(&self) -> Option<ConstValue> {
+    match self.default_attribute()?.arg.as_ref()? {
+      AttribArg::Literal(Token::String(str, _)) => {
+        Some(ConstValue::String(str.to_string()))
+      }
+      AttribArg::Literal(Token::Int(int, _)) => match self.field_type.r#type() {
+        Type::Primitive {
+          r#type: PrimitiveType::Float64,
+          ..
+        } => Some(ConstValue::from(*int as f64)),
+        _ => Some(ConstValue::Number((*int).into())),
+      },
+      AttribArg::Literal(Token::Float(float, _)) => Some(ConstValue::from(*float)),
+      AttribArg::Ident(ident) => {
+        let ident = ident.ident_name()?;
+        match (self.field_type.r#type(), ident.as_str()) {
+          (Type::Enum { .. }, _) => Some(ConstValue::Enum(Name::new(ident))),
+          (_, semantic_analysis::ATTRIB_ARG_VALUE_TRUE) => {
+            Some(ConstValue::Boolean(true))
+          }
+          (_, semantic_analysis::ATTRIB_ARG_VALUE_FALSE) => {
+            Some(ConstValue::Boolean(false))
+          }
+          _ => None,
+        }
+      }
+      AttribArg::Literal(_) | AttribArg::Function(..) | AttribArg::Args(_) => None,
+    }
+  }
+  /// Constraints on the field value, given by the validation attributes.
+  /// Ex. `@length(max: 64)`.
+  pub fn constraints(&self) -> Vec<FieldConstraint> {
+    self
+      .attributes
+      .iter()
+      .filter_map(|attrib| {
+        let bound = |arg_name: &str| match &attrib.arg {
+          Some(AttribArg::Args(args)) => args
+            .iter()
+            .find(|arg| arg.arg_name.ident_name().as_deref() == Some(arg_name))
+            .map(|arg| &arg.arg_value),
+          _ => None,
+        };
+        match attrib.name.ident_name()?.as_str() {
+          semantic_analysis::ATTRIB_NAME_LENGTH => {
+            let bound = |arg_name| match bound(arg_name) {
+              Some(Token::Int(int, _)) => Some(*int as usize),
+              _ => None,
+            };
+            Some(FieldConstraint::Length {
+              min: bound(semantic_analysis::ATTRIB_NAMED_ARG_MIN),
+              max: bound(semantic_analysis::ATTRIB_NAMED_ARG_MAX),
+            })
+          }
+          semantic_analysis::ATTRIB_NAME_RANGE => {
+            let bound = |arg_name| match bound(arg_name) {
+              Some(Token::Int(int, _)) => Some(*int as f64),
+              Some(Token::Float(float, _)) => Some(*float),
+              _ => None,
+            };
+            Some(FieldConstraint::Range {
+              min: bound(semantic_analysis::ATTRIB_NAMED_ARG_MIN),
+              max: bound(semantic_analysis::ATTRIB_NAMED_ARG_MAX),
+            })
+          }
+          semantic_analysis::ATTRIB_NAME_PATTERN => match &attrib.arg {
+            Some(AttribArg::Literal(Token::String(pattern, _))) => {
+              Some(FieldConstraint::Pattern(pattern.to_string()))
+            }
+            _ => None,
+          },
+          semantic_analysis::ATTRIB_NAME_EMAIL => Some(FieldConstraint::Email),
+          _ => None,
+        }
+      })
+      .collect()
+  }
 
   #[inline]
   fn get_attribute(&self, attrib_ident_name: &str) -> Option<&Attribute> {
@@ -638,6 +814,43 @@ impl FieldType {
     }
   }
 
+  /// Is this a scalar (i.e. non-array) date time type ?
+  pub fn is_scalar_date_time(&self) -> bool {
+    if self.is_array() {
+      false
+    } else {
+      matches!(
+        self.r#type,
+        Type::Primitive {
+          r#type: PrimitiveType::DateTime,
+          ..
+        }
+      )
+    }
+  }
+
+  /// Is this a string type, or a list of strings ?
+  pub fn is_str(&self) -> bool {
+    matches!(
+      self.r#type,
+      Type::Primitive {
+        r#type: PrimitiveType::ShortStr | PrimitiveType::LongStr,
+        ..
+      }
+    )
+  }
+
+  /// Is this a number type, or a list of numbers ?
+  pub fn is_number(&self) -> bool {
+    matches!(
+      self.r#type,
+      Type::Primitive {
+        r#type: PrimitiveType::Int32 | PrimitiveType::Int64 | PrimitiveType::Float64,
+        ..
+      }
+    )
+  }
+
   /// Is this typed as a  scalar field (i.e) can it hold only one value ?
   /// **Note**: If this is an array type, this field is able to
   /// hold more than one value. Hence it is not scalar field.
@@ -668,9 +881,13 @@ pub enum Type {
   Enum {
     enum_ty_name: Token,
   },
+  /// If field type is a composite type (`type` block), its value is embedded in the object.
+  Composite {
+    composite_ty_name: Token,
+  },
   /// If field type is other model type, then its a `Relation`.
   Relation(RelationEdge),
-  /// If the field type is Enum or Relation, in the first pass it will be set to Unknown with identifier token.
+  /// If the field type is Enum, Composite or Relation, in the first pass it will be set to Unknown with identifier token.
   /// Then only during scemantic analysis its actual user defined type is determined.
   Unknown(Token),
 }
@@ -680,6 +897,7 @@ impl Type {
     match self {
       Self::Primitive { token, .. } => token,
       Self::Enum { enum_ty_name } => enum_ty_name,
+      Self::Composite { composite_ty_name } => composite_ty_name,
       Self::Relation(relation_edge) => relation_edge.referenced_model_name(),
       Self::Unknown(token) => token,
     }
@@ -704,6 +922,7 @@ pub enum RelationEdge {
     referenced_model_name: Token,
     /// Name of the field (should be either @id or @unique) in the referenced model.
     referenced_field_name: Token,
+    referential_actions: ReferentialActions,
   },
   /// Many-side of the relation, capturing the required information for
   /// a. Many side of 1-to-many relation,
@@ -716,6 +935,7 @@ pub enum RelationEdge {
     referenced_model_name: Token,
     /// Name of the field (should be either @id or @unique) in the referenced model.
     referenced_field_name: Token,
+    referential_actions: ReferentialActions,
   },
   /// Self relation of type 1-to-1
   SelfOneToOneRelation {
@@ -725,6 +945,13 @@ pub enum RelationEdge {
     referenced_model_name: Token,
     /// Name of the referened field (should be either @id or @unique) in the model.
     referenced_field_name: Token,
+    referential_actions: ReferentialActions,
+  },
+  /// Either side of the implicit many-to-many relation, declared without the
+  /// relation scalar fields. The links are kept by the storage in a join keyspace.
+  ImplicitManyToManyRelation {
+    relation_name: Token,
+    referenced_model_name: Token,
   },
 }
 
@@ -735,6 +962,7 @@ impl RelationEdge {
       Self::OneSideRelationRight { relation_name, .. } => relation_name,
       Self::ManySideRelation { relation_name, .. } => relation_name,
       Self::SelfOneToOneRelation { relation_name, .. } => relation_name,
+      Self::ImplicitManyToManyRelation { relation_name, .. } => relation_name,
     }
   }
 
@@ -750,6 +978,7 @@ impl RelationEdge {
       Self::SelfOneToOneRelation {
         scalar_field_name, ..
       } => Some(scalar_field_name),
+      Self::ImplicitManyToManyRelation { .. } => None,
     }
   }
 
@@ -771,6 +1000,10 @@ impl RelationEdge {
         referenced_model_name,
         ..
       } => referenced_model_name,
+      Self::ImplicitManyToManyRelation {
+        referenced_model_name,
+        ..
+      } => referenced_model_name,
     }
   }
 
@@ -789,10 +1022,90 @@ impl RelationEdge {
         referenced_field_name,
         ..
       } => Some(referenced_field_name),
+      Self::ImplicitManyToManyRelation { .. } => None,
+    }
+  }
+
+  /// Referential actions of the relation, set only on the edge having the scalar field.
+  pub fn referential_actions(&self) -> ReferentialActions {
+    match self {
+      Self::OneSideRelation { .. } | Self::ImplicitManyToManyRelation { .. } => {
+        ReferentialActions::default()
+      }
+      Self::OneSideRelationRight {
+        referential_actions,
+        ..
+      }
+      | Self::ManySideRelation {
+        referential_actions,
+        ..
+      }
+      | Self::SelfOneToOneRelation {
+        referential_actions,
+        ..
+      } => *referential_actions,
     }
   }
 }
 
+/// What happens to the referencing objects (i.e. holding the relation scalar field),
+/// when the referenced object is deleted or its referenced field is updated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferentialAction {
+  /// Delete the referencing objects / update their relation scalar field.
+  Cascade,
+  /// Fail, if there are referencing objects.
+  Restrict,
+  /// Set the relation scalar field of the referencing objects to `null`.
+  SetNull,
+  /// Set the relation scalar field of the referencing objects to its default value.
+  SetDefault,
+  /// Leave the referencing objects as they are.
+  NoAction,
+}
+
+impl ReferentialAction {
+  pub const ALL: [ReferentialAction; 5] = [
+    ReferentialAction::Cascade,
+    ReferentialAction::Restrict,
+    ReferentialAction::SetNull,
+    ReferentialAction::SetDefault,
+    ReferentialAction::NoAction,
+  ];
+
+  /// Name of the action in SDML, Ex. `SetNull`.
+  pub fn name(&self) -> &'static str {
+    match self {
+      ReferentialAction::Cascade => "Cascade",
+      ReferentialAction::Restrict => "Restrict",
+      ReferentialAction::SetNull => "SetNull",
+      ReferentialAction::SetDefault => "SetDefault",
+      ReferentialAction::NoAction => "NoAction",
+    }
+  }
+
+  /// Action of the given SDML name.
+  pub fn from_name(name: &str) -> Option<Self> {
+    Self::ALL.into_iter().find(|action| action.name() == name)
+  }
+}
+
+impl std::fmt::Display for ReferentialAction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+/// Referential actions of a relation, `None` if the action isn't given.
+/// Ex. `@relation(name: "UserPosts", field: authorId, references: userId, onDelete: Cascade)`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReferentialActions {
+  /// Action when the referenced object is deleted.
+  pub on_delete: Option<ReferentialAction>,
+  /// Action when the referenced field of the referenced object is updated.
+  pub on_update: Option<ReferentialAction>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PrimitiveType {
   ShortStr,
@@ -802,6 +1115,15 @@ pub enum PrimitiveType {
   Int32,
   Int64,
   Float64,
+  /// Exact decimal number, Ex. money. Its value is a string or a number.
+  Decimal,
+  /// Schemaless JSON value.
+  Json,
+  /// Opaque binary blob.
+  Bytes,
+  /// Calendar date without the time, Ex. `2024-06-30`.
+  Date,
+  Uuid,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -810,11 +1132,56 @@ pub struct Attribute {
   pub arg: Option<AttribArg>,
 }
 
+/// Constraint on the field value, checked whenever the value is created or updated.
+/// Each item is checked, if the field is a list.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldConstraint {
+  /// `@length(min: m, max: n)`, number of characters of the string.
+  Length {
+    min: Option<usize>,
+    max: Option<usize>,
+  },
+  /// `@range(min: m, max: n)`, bounds of the number, inclusive.
+  Range { min: Option<f64>, max: Option<f64> },
+  /// `@pattern("regex")`, the string matches the regular expression.
+  Pattern(String),
+  /// `@email`, the string is an email address.
+  Email,
+}
+
+/// Model level (block) attribute, applied to a list of fields of the model.
+/// Ex. `@@unique([tenantId, email])`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelAttribute {
+  pub name: Token,
+  pub fields: Vec<Token>,
+}
+
+impl ModelAttribute {
+  /// Name of the compound key, i.e. the names of its fields joined by `_`.
+  /// Ex. `tenantId_email` for `@@unique([tenantId, email])`.
+  pub fn compound_name(&self) -> String {
+    self
+      .fields
+      .iter()
+      .filter_map(Token::ident_name)
+      .collect::<Vec<_>>()
+      .join("_")
+  }
+  /// Names of the fields in the compound key.
+  pub fn field_names(&self) -> Vec<String> {
+    self.fields.iter().filter_map(Token::ident_name).collect()
+  }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AttribArg {
   Args(Vec<NamedArg>),
-  Function(Token),
+  /// Function and its arguments. Ex. `@default(nanoid(16))`.
+  Function(Token, Vec<Token>),
   Ident(Token),
+  /// String, integer or float literal. Ex. `@default("en")`.
+  Literal(Token),
 }
 
 impl std::fmt::Display for AttribArg {
@@ -826,18 +1193,40 @@ impl std::fmt::Display for AttribArg {
             "{} {}:{}",
             acc,
             arg.arg_name.ident_name().unwrap(),
-            // Note: arg_value should be either an ident or a string, and nothing else.
-            arg.arg_value.ident_name().or(arg.arg_value.str()).unwrap()
+            // Note: arg_value should be either an ident, a string or a number.
+            match &arg.arg_value {
+              Token::Int(int, _) => int.to_string(),
+              Token::Float(float, _) => format!("{:?}", float),
+              arg_value => arg_value.ident_name().or(arg_value.str()).unwrap(),
+            }
           )
         });
         write!(f, "{}", disp_str)
       }
-      AttribArg::Function(fn_name) => {
-        write!(f, "{}", fn_name.ident_name().unwrap())
+      AttribArg::Function(fn_name, fn_args) => {
+        let fn_args = fn_args
+          .iter()
+          .map(|fn_arg| match fn_arg {
+            Token::Int(int, _) => int.to_string(),
+            _ => panic!("Function argument should be an integer!"),
+          })
+          .collect::<Vec<_>>();
+        write!(
+          f,
+          "{}({})",
+          fn_name.ident_name().unwrap(),
+          fn_args.join(", ")
+        )
       }
       AttribArg::Ident(v) => {
         write!(f, "{}", v.ident_name().unwrap())
       }
+      AttribArg::Literal(literal) => match literal {
+        Token::String(str, _) => write!(f, "{:?}", str.as_str()),
+        Token::Int(int, _) => write!(f, "{}", int),
+        Token::Float(float, _) => write!(f, "{:?}", float),
+        _ => panic!("Literal should be a string or a number!"),
+      },
     }
   }
 }